@@ -0,0 +1,39 @@
+use syn::{Path, Token, parse::ParseStream};
+
+/// User functions used instead of the std traits, such as `#[reflect(hash = my_hash)]`.
+///
+/// Expected signatures (`T` is the type or field type):
+///
+/// - `clone`: `fn(&T) -> T`
+/// - `default`: `fn() -> T`
+/// - `debug`: `fn(&T, &mut Formatter<'_>) -> fmt::Result`
+/// - `hash`: `fn(&T, &mut impl Hasher)`
+/// - `partial_eq`: `fn(&T, &T) -> bool`
+/// - `partial_cmp`: `fn(&T, &T) -> Option<Ordering>`
+#[derive(Clone, Default)]
+pub(crate) struct CustomFunctions {
+    pub clone: Option<Path>,
+    pub default: Option<Path>,
+    pub debug: Option<Path>,
+    pub hash: Option<Path>,
+    pub partial_eq: Option<Path>,
+    pub partial_cmp: Option<Path>,
+}
+
+impl CustomFunctions {
+    /// Try parse `= path` after the keyword, return `None` if there is no `=`.
+    pub fn parse_optional(input: ParseStream) -> syn::Result<Option<Path>> {
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Ok(Some(input.parse::<Path>()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parse `= path` after the keyword, the path is required.
+    pub fn parse_required(input: ParseStream) -> syn::Result<Path> {
+        input.parse::<Token![=]>()?;
+        input.parse::<Path>()
+    }
+}
@@ -1,13 +1,21 @@
-use syn::{Attribute, Meta, Token, MacroDelimiter, MetaList, parse::ParseStream, MetaNameValue};
+use syn::{Attribute, LitStr, Meta, Token, MacroDelimiter, MetaList, parse::ParseStream, MetaNameValue};
 
 use crate::{
     REFLECT_ATTRIBUTE_NAME,
-    derive_data::{CustomAttributes, ReflectDocs}
+    derive_data::{CustomAttributes, CustomFunctions, ReflectDocs}
 };
 
 mod kw{
     syn::custom_keyword!(docs);
     syn::custom_keyword!(ignore);
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(clone);
+    syn::custom_keyword!(debug);
+    syn::custom_keyword!(hash);
+    syn::custom_keyword!(partial_eq);
+    syn::custom_keyword!(partial_cmp);
+    syn::custom_keyword!(rename);
+    syn::custom_keyword!(alias);
 }
 
 
@@ -19,6 +27,14 @@ pub(crate) struct FieldAttributes {
     pub docs: ReflectDocs,
     /// Determines how this field should be ignored if at all.
     pub ignore: bool,
+    /// Custom functions created via `#[reflect(hash = path)]` and so on.
+    pub custom_fns: CustomFunctions,
+    /// Use `#[reflect(default)]` or `#[reflect(default = path)]` to fill the field when it's missing.
+    pub default: bool,
+    /// The serialized name, use `#[reflect(rename = "...")]` to set it.
+    pub rename: Option<LitStr>,
+    /// Other names accepted by deserialization, use `#[reflect(alias = "...")]` to add one.
+    pub aliases: Vec<LitStr>,
 }
 
 impl FieldAttributes {
@@ -75,6 +91,22 @@ impl FieldAttributes {
             self.parse_docs(input)
         } else if lookahead.peek(kw::ignore) {
             self.parse_ignore(input)
+        } else if lookahead.peek(kw::default) {
+            self.parse_default(input)
+        } else if lookahead.peek(kw::clone) {
+            self.parse_clone(input)
+        } else if lookahead.peek(kw::hash) {
+            self.parse_hash(input)
+        } else if lookahead.peek(kw::partial_eq) {
+            self.parse_partial_eq(input)
+        } else if lookahead.peek(kw::partial_cmp) {
+            self.parse_partial_cmp(input)
+        } else if lookahead.peek(kw::debug) {
+            self.parse_debug(input)
+        } else if lookahead.peek(kw::rename) {
+            self.parse_rename(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
         } else {
             Err(lookahead.error())
         }
@@ -97,6 +129,71 @@ impl FieldAttributes {
         self.ignore = true;
         Ok(())
     }
+
+    // #[reflect(default)] or #[reflect(default = path)]
+    fn parse_default(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::default>()?;
+        self.default = true;
+        self.custom_fns.default = CustomFunctions::parse_optional(input)?;
+        Ok(())
+    }
+
+    // #[reflect(rename = "...")]
+    fn parse_rename(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::rename>()?;
+        input.parse::<Token![=]>()?;
+        let lit = input.parse::<LitStr>()?;
+
+        if self.rename.is_some() {
+            return Err(syn::Error::new(lit.span(), "`rename` can only be specified once."));
+        }
+        self.rename = Some(lit);
+
+        Ok(())
+    }
+
+    // #[reflect(alias = "...")]
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+        self.aliases.push(input.parse::<LitStr>()?);
+        Ok(())
+    }
+
+    // #[reflect(clone = path)]
+    fn parse_clone(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::clone>()?;
+        self.custom_fns.clone = Some(CustomFunctions::parse_required(input)?);
+        Ok(())
+    }
+
+    // #[reflect(hash = path)]
+    fn parse_hash(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::hash>()?;
+        self.custom_fns.hash = Some(CustomFunctions::parse_required(input)?);
+        Ok(())
+    }
+
+    // #[reflect(partial_eq = path)]
+    fn parse_partial_eq(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::partial_eq>()?;
+        self.custom_fns.partial_eq = Some(CustomFunctions::parse_required(input)?);
+        Ok(())
+    }
+
+    // #[reflect(partial_cmp = path)]
+    fn parse_partial_cmp(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::partial_cmp>()?;
+        self.custom_fns.partial_cmp = Some(CustomFunctions::parse_required(input)?);
+        Ok(())
+    }
+
+    // #[reflect(debug = path)]
+    fn parse_debug(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::debug>()?;
+        self.custom_fns.debug = Some(CustomFunctions::parse_required(input)?);
+        Ok(())
+    }
 }
 
 
@@ -54,6 +54,7 @@ pub(crate) struct TraitAvailableFlags {
     pub debug: bool,
     pub hash: bool,
     pub partial_eq: bool,
+    pub partial_cmp: bool,
     pub serialize: bool,
     pub deserialize: bool,
 }
@@ -5,6 +5,9 @@ pub(crate) use custom_attributes::CustomAttributes;
 mod reflect_docs;
 pub(crate) use reflect_docs::ReflectDocs;
 
+mod custom_functions;
+pub(crate) use custom_functions::CustomFunctions;
+
 mod flags;
 pub(crate) use flags::{
     TraitImplSwitches, TraitAvailableFlags,
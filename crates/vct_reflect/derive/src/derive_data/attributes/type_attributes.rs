@@ -1,11 +1,11 @@
 use syn::{
-    Attribute, Expr, ExprLit, Lit, MacroDelimiter, Meta, MetaList, MetaNameValue, Path, Token, parse::ParseStream, spanned::Spanned
+    Attribute, Expr, ExprLit, Lit, LitStr, MacroDelimiter, Meta, MetaList, MetaNameValue, Path, Token, Type, parse::{Parse, ParseStream}, spanned::Spanned
 };
 
 use crate::{
     REFLECT_ATTRIBUTE_NAME,
     derive_data::{
-        CustomAttributes, TraitAvailableFlags, ReflectDocs, TraitImplSwitches
+        CustomAttributes, CustomFunctions, TraitAvailableFlags, ReflectDocs, TraitImplSwitches
     }
 };
 
@@ -26,11 +26,22 @@ mod kw {
     syn::custom_keyword!(debug);
     syn::custom_keyword!(hash);
     syn::custom_keyword!(partial_eq);
+    syn::custom_keyword!(partial_cmp);
+    syn::custom_keyword!(partial_ord);
+    syn::custom_keyword!(PartialOrd);
+    syn::custom_keyword!(Internal);
     syn::custom_keyword!(serialize);
     syn::custom_keyword!(deserialize);
     syn::custom_keyword!(serde);
     syn::custom_keyword!(type_path);
+    syn::custom_keyword!(alias);
+    syn::custom_keyword!(type_trait);
     syn::custom_keyword!(docs);
+    syn::custom_keyword!(version);
+    syn::custom_keyword!(deny_unknown_fields);
+    syn::custom_keyword!(tag);
+    syn::custom_keyword!(content);
+    syn::custom_keyword!(untagged);
 }
 
 #[derive(Default, Clone)]
@@ -41,12 +52,30 @@ pub(crate) struct TypeAttributes {
     pub impl_switchs: TraitImplSwitches,
     /// See: [`MethodImplFlags`]
     pub avail_traits: TraitAvailableFlags,
+    /// See: [`CustomFunctions`], the related flag of `avail_traits` is also set.
+    pub custom_fns: CustomFunctions,
+    /// Default is false, use `#[reflect(partial_ord = Internal)]` to compare the fields through reflection.
+    pub internal_partial_cmp: bool,
     /// By default, only types like `struct A;` are `Opaque`, but user can use `#[reflect(opaque)]` to enable it explicitly.
     pub is_opaque: bool,
     /// Default is false, use `#[reflect(auto_register)]` or `#[reflect(auto_register)]` to enable i.
     pub auto_register: bool,
     /// Default is None, So the macro will be auto generated. Use `#[reflect(type_path = "...")]` to set it.
     pub type_path: Option<Path>,
+    /// Default is None, use `#[reflect(alias = B)]` to reflect this wrapper as the foreign type `B`.
+    pub alias: Option<Type>,
+    /// Default is Empty, use `#[reflect(type_trait(A, B))]` to insert extra type traits in `get_type_traits`.
+    pub type_traits: Vec<Path>,
+    /// Default is 0, use `#[reflect(version = 2)]` to set the schema version for serialization.
+    pub version: u32,
+    /// Default is false, use `#[reflect(deny_unknown_fields)]` to reject unknown fields in deserialization.
+    pub deny_unknown_fields: bool,
+    /// Default is None (externally tagged), use `#[reflect(tag = "type")]` for internally tagged enums.
+    pub tag: Option<LitStr>,
+    /// Default is None, use `#[reflect(tag = "t", content = "c")]` for adjacently tagged enums.
+    pub content: Option<LitStr>,
+    /// Default is false, use `#[reflect(untagged)]` for untagged enums.
+    pub untagged: bool,
     /// Default is Empty Docs,  Use `///`, `#[doc = ""]` or `#[reflect(docs = "")]` to set it, Can set multi-lines.
     pub docs: ReflectDocs,
 }
@@ -78,9 +107,21 @@ impl TypeAttributes {
             }
         }
 
+        type_attributes.check_tagging()?;
+
         Ok(type_attributes)
     }
 
+    fn check_tagging(&self) -> syn::Result<()> {
+        if let Some(content) = &self.content && self.tag.is_none() {
+            return Err(syn::Error::new(content.span(), "`content` requires `tag` to be specified."));
+        }
+        if self.untagged && let Some(tag) = &self.tag {
+            return Err(syn::Error::new(tag.span(), "`tag` cannot be used with `untagged`."));
+        }
+        Ok(())
+    }
+
     fn parse_meta_list(&mut self, meta: &MetaList) -> syn::Result<()> {
         meta.parse_args_with(|stream: ParseStream|{
             loop {
@@ -112,6 +153,10 @@ impl TypeAttributes {
             self.parse_hash(input)
         } else if lookahead.peek(kw::partial_eq) {
             self.parse_patrial_eq(input)
+        } else if lookahead.peek(kw::partial_cmp) {
+            self.parse_partial_cmp(input)
+        } else if lookahead.peek(kw::partial_ord) {
+            self.parse_partial_ord(input)
         } else if lookahead.peek(kw::debug) {
             self.parse_debug(input)
         } else if lookahead.peek(kw::serde) {
@@ -126,6 +171,20 @@ impl TypeAttributes {
             self.parse_auto_register(input)
         } else if lookahead.peek(kw::type_path) {
             self.parse_type_path(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
+        } else if lookahead.peek(kw::type_trait) {
+            self.parse_type_trait(input)
+        } else if lookahead.peek(kw::version) {
+            self.parse_version(input)
+        } else if lookahead.peek(kw::deny_unknown_fields) {
+            self.parse_deny_unknown_fields(input)
+        } else if lookahead.peek(kw::tag) {
+            self.parse_tag(input)
+        } else if lookahead.peek(kw::content) {
+            self.parse_content(input)
+        } else if lookahead.peek(kw::untagged) {
+            self.parse_untagged(input)
         } else if lookahead.peek(kw::TypePath) {
             self.parse_trait_type_path(input)
         } else if lookahead.peek(kw::Typed) {
@@ -162,38 +221,73 @@ impl TypeAttributes {
         self.docs.parse_custom_docs(&pair)
     }
 
-    // #[reflect(default)]
+    // #[reflect(default)] or #[reflect(default = path)]
     fn parse_default(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<kw::default>()?;
         self.avail_traits.default = true;
+        self.custom_fns.default = CustomFunctions::parse_optional(input)?;
         Ok(())
     }
 
-    // #[reflect(clone)]
+    // #[reflect(clone)] or #[reflect(clone = path)]
     fn parse_clone(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<kw::clone>()?;
         self.avail_traits.clone = true;
+        self.custom_fns.clone = CustomFunctions::parse_optional(input)?;
         Ok(())
     }
 
-    // #[reflect(hash)]
+    // #[reflect(hash)] or #[reflect(hash = path)]
     fn parse_hash(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<kw::hash>()?;
         self.avail_traits.hash = true;
+        self.custom_fns.hash = CustomFunctions::parse_optional(input)?;
         Ok(())
     }
 
-    // #[reflect(partial_eq)]
+    // #[reflect(partial_eq)] or #[reflect(partial_eq = path)]
     fn parse_patrial_eq(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<kw::partial_eq>()?;
         self.avail_traits.partial_eq = true;
+        self.custom_fns.partial_eq = CustomFunctions::parse_optional(input)?;
+        Ok(())
+    }
+
+    // #[reflect(partial_cmp)] or #[reflect(partial_cmp = path)]
+    fn parse_partial_cmp(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::partial_cmp>()?;
+        self.avail_traits.partial_cmp = true;
+        self.custom_fns.partial_cmp = CustomFunctions::parse_optional(input)?;
+        Ok(())
+    }
+
+    // #[reflect(partial_ord = PartialOrd)] or #[reflect(partial_ord = Internal)]
+    fn parse_partial_ord(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::partial_ord>()?;
+        input.parse::<Token![=]>()?;
+
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::PartialOrd) {
+            input.parse::<kw::PartialOrd>()?;
+            self.avail_traits.partial_cmp = true;
+            self.custom_fns.partial_cmp = None;
+            self.internal_partial_cmp = false;
+        } else if lookahead.peek(kw::Internal) {
+            input.parse::<kw::Internal>()?;
+            self.avail_traits.partial_cmp = false;
+            self.custom_fns.partial_cmp = None;
+            self.internal_partial_cmp = true;
+        } else {
+            return Err(lookahead.error());
+        }
         Ok(())
     }
 
-    // #[reflect(debug)]
+    // #[reflect(debug)] or #[reflect(debug = path)]
     fn parse_debug(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<kw::debug>()?;
         self.avail_traits.debug = true;
+        self.custom_fns.debug = CustomFunctions::parse_optional(input)?;
         Ok(())
     }
 
@@ -255,6 +349,75 @@ impl TypeAttributes {
         Ok(())
     }
 
+    // #[reflect(alias = B)]
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+        let ty = input.parse::<Type>()?;
+
+        if self.alias.is_some() {
+            return Err(syn::Error::new(ty.span(), "`alias` can only be specified once."));
+        }
+        self.alias = Some(ty);
+
+        Ok(())
+    }
+
+    // #[reflect(type_trait(A, B))]
+    fn parse_type_trait(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::type_trait>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let paths = content.parse_terminated(<Path as Parse>::parse, Token![,])?;
+        self.type_traits.extend(paths);
+        Ok(())
+    }
+
+    // #[reflect(version = 2)]
+    fn parse_version(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) = &pair.value {
+            self.version = lit.base10_parse::<u32>()?;
+        } else {
+            return Err(syn::Error::new(pair.value.span(), "Expected a u32 literal value."));
+        }
+
+        Ok(())
+    }
+
+    // #[reflect(deny_unknown_fields)]
+    fn parse_deny_unknown_fields(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::deny_unknown_fields>()?;
+        self.deny_unknown_fields = true;
+        Ok(())
+    }
+
+    // #[reflect(tag = "...")]
+    fn parse_tag(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::tag>()?;
+        input.parse::<Token![=]>()?;
+        self.tag = Some(input.parse::<LitStr>()?);
+        Ok(())
+    }
+
+    // #[reflect(content = "...")]
+    fn parse_content(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::content>()?;
+        input.parse::<Token![=]>()?;
+        self.content = Some(input.parse::<LitStr>()?);
+        Ok(())
+    }
+
+    // #[reflect(untagged)]
+    fn parse_untagged(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::untagged>()?;
+        self.untagged = true;
+        Ok(())
+    }
+
     fn parse_trait_type_path(&mut self, input: ParseStream) -> syn::Result<()> {
         // #[reflect(TypePath = false)]
         let pair = input.parse::<MetaNameValue>()?;
@@ -28,7 +28,11 @@ impl<'a> ReflectDerive<'a> {
     }
 
     pub fn from_input(input: &'a DeriveInput, source: ImplSourceKind) -> syn::Result<Self> {
-        let type_attributes = TypeAttributes::parse_attrs(&input.attrs)?;
+        let mut type_attributes = TypeAttributes::parse_attrs(&input.attrs)?;
+
+        if type_attributes.alias.is_some() {
+            Self::check_alias(input, &mut type_attributes)?;
+        }
 
         // For local types, can use `module_path!()` to get the module path, 
         // but for foreign types, the user needs to explicitly provide it.
@@ -79,6 +83,7 @@ impl<'a> ReflectDerive<'a> {
             },
             syn::Data::Enum(data_enum) => {
                 let variants = Self::collect_enum_variants(&data_enum.variants)?;
+                Self::check_internal_tagging(meta.attrs(), &variants)?;
                 Ok(Self::Enum(ReflectEnum { meta, variants }))
             },
             syn::Data::Union(_) => {
@@ -87,6 +92,65 @@ impl<'a> ReflectDerive<'a> {
         }
     }
 
+    /// The alias wrapper must be a non-generic `#[repr(transparent)]` tuple struct with a single field,
+    /// it is reflected as `Opaque`, and uses the path of the alias as its type path by default.
+    fn check_alias(input: &DeriveInput, type_attributes: &mut TypeAttributes) -> syn::Result<()> {
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                input.generics.span(),
+                "#[reflect(alias = ...)] does not support generic types.",
+            ));
+        }
+
+        let is_transparent = input.attrs.iter().any(|attr| {
+            attr.path().is_ident("repr")
+                && attr.parse_args::<syn::Ident>().is_ok_and(|repr| repr == "transparent")
+        });
+        if !is_transparent {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "#[repr(transparent)] must be specified when using #[reflect(alias = ...)].",
+            ));
+        }
+
+        let is_newtype = matches!(
+            &input.data,
+            syn::Data::Struct(syn::DataStruct { fields: Fields::Unnamed(fields), .. })
+                if fields.unnamed.len() == 1
+        );
+        if !is_newtype {
+            return Err(syn::Error::new(
+                input.span(),
+                "#[reflect(alias = ...)] can only be used on tuple struct with a single field, like `struct A(B);`.",
+            ));
+        }
+
+        if type_attributes.type_path.is_none() {
+            let alias = type_attributes.alias.as_ref().unwrap();
+            match alias {
+                // `crate::`, `self::` and `super::` are relative to the caller, not valid type paths.
+                syn::Type::Path(type_path) if type_path.qself.is_none()
+                    && type_path.path.segments.len() > 1
+                    && !["crate", "self", "super"].iter().any(|kw| type_path.path.segments[0].ident == kw) => {
+                    let mut path = type_path.path.clone();
+                    path.leading_colon = None;
+                    type_attributes.type_path = Some(path);
+                },
+                _ => {
+                    return Err(syn::Error::new(
+                        alias.span(),
+                        "The alias should be an absolute path like `foo::B` (not starting with `crate`, `self` or `super`), \
+                         otherwise #[reflect(type_path = \"...\")] must be specified.",
+                    ));
+                },
+            }
+        }
+
+        type_attributes.is_opaque = true;
+
+        Ok(())
+    }
+
     fn colloct_struct_field(fields: &'a Fields) -> syn::Result<Vec<StructField<'a>>> {
         let mut active_index = 0;
         
@@ -113,6 +177,30 @@ impl<'a> ReflectDerive<'a> {
         Ok(res)
     }
 
+    /// Internally tagged enums write the tag next to the fields, like serde-derive,
+    /// tuple variants must have exactly one field (which is expected to be a struct or a map).
+    fn check_internal_tagging(type_attributes: &TypeAttributes, variants: &[EnumVariant<'a>]) -> syn::Result<()> {
+        let (Some(tag), None) = (&type_attributes.tag, &type_attributes.content) else {
+            return Ok(());
+        };
+
+        for variant in variants {
+            if let EnumVariantFields::Unnamed(_) = variant.fields
+                && variant.active_fields().count() != 1
+            {
+                return Err(syn::Error::new(
+                    variant.data.ident.span(),
+                    format_args!(
+                        "#[reflect(tag = {:?})] cannot be used with tuple variants that do not have exactly one field.",
+                        tag.value(),
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     fn collect_enum_variants(variants: &'a Punctuated<Variant, Comma>) -> syn::Result<Vec<EnumVariant<'a>>> {
         let mut res: Vec<EnumVariant<'a>> = Vec::with_capacity(variants.len());
 
@@ -41,7 +41,13 @@ impl<'a> EnumVariant<'a> {
     }
 
     /// Generates a `TokenStream` for `VariantInfo` construction.
-    pub fn to_info_tokens(&self, vct_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
+    ///
+    /// `deny_unknown_fields` is the enum attribute, it only applies to struct variants.
+    pub fn to_info_tokens(
+        &self,
+        vct_reflect_path: &syn::Path,
+        deny_unknown_fields: bool,
+    ) -> proc_macro2::TokenStream {
         let variant_info_path = crate::path::variant_info_(vct_reflect_path);
 
         let variant_info_kind = match &self.fields {
@@ -73,12 +79,19 @@ impl<'a> EnumVariant<'a> {
         // See [`ReflectDocs::get_expression_with`]
         // If feature is diabled, this function will return a empty TokenStream, so it's safe.
         let with_docs = self.attrs.docs.get_expression_with();
+        let with_deny_unknown_fields = match &self.fields {
+            EnumVariantFields::Named(_) if deny_unknown_fields => {
+                quote!(.with_deny_unknown_fields(true))
+            }
+            _ => crate::utils::empty(),
+        };
 
         quote! {
             #variant_info_path::#variant_info_kind(
                 #info_struct_path::new( #args )
                     #with_custom_attributes
                     #with_docs
+                    #with_deny_unknown_fields
             )
         }
     }
@@ -107,8 +120,9 @@ impl<'a> ReflectEnum<'a> {
 
         let info_struct_path = crate::path::enum_info_(vct_reflect_path);
 
+        let deny_unknown_fields = self.meta.attrs().deny_unknown_fields;
         let variant_infos = self.variants.iter()
-            .map(|variant| variant.to_info_tokens(vct_reflect_path));
+            .map(|variant| variant.to_info_tokens(vct_reflect_path, deny_unknown_fields));
 
         // See [`CustomAttributes::get_expression_with`]
         let with_custom_attributes = self.meta.with_custom_attributes_expression();
@@ -117,14 +131,34 @@ impl<'a> ReflectEnum<'a> {
         let with_docs = self.meta.with_docs_expression();
         // See [`ReflectMeta::with_generics_expression`]
         let with_generics = self.meta.with_generics_expression();
+        // See [`ReflectMeta::with_version_expression`]
+        let with_version = self.meta.with_version_expression();
+        let with_tagging = self.with_tagging_expression();
 
         quote! {
             #type_info_path::Enum(
-                #info_struct_path::::new::<Self>(&[ #(#variant_infos),* ])
+                #info_struct_path::new::<Self>(&[ #(#variant_infos),* ])
                     #with_custom_attributes
                     #with_generics
                     #with_docs
+                    #with_version
+                    #with_tagging
             )
         }
     }
+
+    /// Returns `.with_tagging(...)` if the enum is not externally tagged.
+    fn with_tagging_expression(&self) -> proc_macro2::TokenStream {
+        let enum_tagging_ = crate::path::enum_tagging_(self.meta.vct_reflect_path());
+        let attrs = self.meta.attrs();
+
+        match (&attrs.tag, &attrs.content) {
+            _ if attrs.untagged => quote!(.with_tagging(#enum_tagging_::Untagged)),
+            (Some(tag), Some(content)) => {
+                quote!(.with_tagging(#enum_tagging_::Adjacent { tag: #tag, content: #content }))
+            }
+            (Some(tag), None) => quote!(.with_tagging(#enum_tagging_::Internal { tag: #tag })),
+            (None, _) => crate::utils::empty(),
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use proc_macro2::TokenStream;
 use syn::{Path, Token, punctuated::Punctuated};
 use quote::quote;
 use crate::derive_data::{
@@ -34,15 +35,23 @@ impl<'a> ReflectMeta<'a> {
         &self.attrs
     }
 
-    pub fn with_docs_expression(&self) -> proc_macro2::TokenStream {
+    pub fn with_docs_expression(&self) -> TokenStream {
         self.attrs.docs.get_expression_with()
     }
 
-    pub fn with_custom_attributes_expression(&self) -> proc_macro2::TokenStream {
+    pub fn with_custom_attributes_expression(&self) -> TokenStream {
         self.attrs.custom_attributes.get_expression_with(&self.vct_reflect_path)
     }
 
-    pub fn with_generics_expression(&self) -> proc_macro2::TokenStream{
+    /// Returns `.with_version(N)` if the version is not `0`, otherwise an empty TokenStream.
+    pub fn with_version_expression(&self) -> TokenStream {
+        match self.attrs.version {
+            0 => TokenStream::new(),
+            version => quote!(.with_version(#version)),
+        }
+    }
+
+    pub fn with_generics_expression(&self) -> TokenStream{
         let vct_reflect_path = &self.vct_reflect_path;
         let generics_ = crate::path::generics_(vct_reflect_path);
         let generic_info_ = crate::path::generic_info_(vct_reflect_path);
@@ -99,8 +108,62 @@ impl<'a> ReflectMeta<'a> {
         }
     }
 
+    /// `Clone::clone(value)`, or the custom function of `#[reflect(clone = path)]`.
+    pub fn clone_expr(&self, value: TokenStream) -> TokenStream {
+        use crate::path::fp::CloneFP;
+        match &self.attrs.custom_fns.clone {
+            Some(func) => quote!(#func(#value)),
+            None => quote!(<Self as #CloneFP>::clone(#value)),
+        }
+    }
+
+    /// `Default::default()`, or the custom function of `#[reflect(default = path)]`.
+    pub fn default_expr(&self) -> TokenStream {
+        use crate::path::fp::DefaultFP;
+        match &self.attrs.custom_fns.default {
+            Some(func) => quote!(#func()),
+            None => quote!(<Self as #DefaultFP>::default()),
+        }
+    }
+
+    /// `PartialEq::eq(x, y)`, or the custom function of `#[reflect(partial_eq = path)]`.
+    pub fn partial_eq_expr(&self, x: TokenStream, y: TokenStream) -> TokenStream {
+        use crate::path::fp::PartialEqFP;
+        match &self.attrs.custom_fns.partial_eq {
+            Some(func) => quote!(#func(#x, #y)),
+            None => quote!(<Self as #PartialEqFP>::eq(#x, #y)),
+        }
+    }
+
+    /// `PartialOrd::partial_cmp(x, y)`, or the custom function of `#[reflect(partial_cmp = path)]`.
+    pub fn partial_cmp_expr(&self, x: TokenStream, y: TokenStream) -> TokenStream {
+        use crate::path::fp::PartialOrdFP;
+        match &self.attrs.custom_fns.partial_cmp {
+            Some(func) => quote!(#func(#x, #y)),
+            None => quote!(<Self as #PartialOrdFP>::partial_cmp(#x, #y)),
+        }
+    }
+
+    /// `Hash::hash(value, hasher)`, or the custom function of `#[reflect(hash = path)]`.
+    pub fn hash_expr(&self, value: TokenStream, hasher: TokenStream) -> TokenStream {
+        use crate::path::fp::HashFP;
+        match &self.attrs.custom_fns.hash {
+            Some(func) => quote!(#func(#value, #hasher)),
+            None => quote!(<Self as #HashFP>::hash(#value, #hasher)),
+        }
+    }
+
+    /// `Debug::fmt(value, f)`, or the custom function of `#[reflect(debug = path)]`.
+    pub fn debug_expr(&self, value: TokenStream, f: TokenStream) -> TokenStream {
+        use crate::path::fp::DebugFP;
+        match &self.attrs.custom_fns.debug {
+            Some(func) => quote!(#func(#value, #f)),
+            None => quote!(<Self as #DebugFP>::fmt(#value, #f)),
+        }
+    }
+
     /// For Opaque Type
-    pub fn to_info_tokens(&self) -> proc_macro2::TokenStream {
+    pub fn to_info_tokens(&self) -> TokenStream {
         let vct_reflect_path = &self.vct_reflect_path;
 
         let opaque_info_ = crate::path::opaque_info_(vct_reflect_path);
@@ -44,11 +44,51 @@ impl StructField<'_> {
         // See [`ReflectDocs::get_expression_with`]
         // If feature is diabled, this function will return a empty TokenStream, so it's safe.
         let with_docs = self.attrs.docs.get_expression_with();
+        // Serialization options only exist on named fields.
+        let with_serde = if self.data.ident.is_some() {
+            self.serde_expression(vct_reflect_path)
+        } else {
+            crate::utils::empty()
+        };
 
         quote! {
             #field_info::new::<#ty>(#name)
                 #with_custom_attributes
                 #with_docs
+                #with_serde
+        }
+    }
+
+    /// Generates `.with_rename(...)`, `.with_aliases(...)` and `.with_default(...)` for `NamedField`.
+    fn serde_expression(&self, vct_reflect_path: &syn::Path) -> proc_macro2::TokenStream {
+        use crate::path::fp::DefaultFP;
+
+        let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+        let ty = &self.data.ty;
+
+        let with_rename = self.attrs.rename.as_ref().map(|rename| quote!(.with_rename(#rename)));
+
+        let aliases = &self.attrs.aliases;
+        let with_aliases = if aliases.is_empty() {
+            crate::utils::empty()
+        } else {
+            quote!(.with_aliases(&[ #(#aliases),* ]))
+        };
+
+        let with_default = if self.attrs.default {
+            let default_value = match &self.attrs.custom_fns.default {
+                Some(func) => quote!(#func()),
+                None => quote!(<#ty as #DefaultFP>::default()),
+            };
+            quote!(.with_default(|| #alloc_utils_::Box::new(#default_value)))
+        } else {
+            crate::utils::empty()
+        };
+
+        quote! {
+            #with_rename
+            #with_aliases
+            #with_default
         }
     }
 
@@ -138,6 +178,14 @@ impl<'a> ReflectStruct<'a> {
         let with_docs = self.meta.with_docs_expression();
         // See [`ReflectMeta::with_generics_expression`]
         let with_generics = self.meta.with_generics_expression();
+        // See [`ReflectMeta::with_version_expression`]
+        let with_version = self.meta.with_version_expression();
+        // Only named structs have `with_deny_unknown_fields`.
+        let with_deny_unknown_fields = if !is_tuple && self.meta.attrs().deny_unknown_fields {
+            quote!(.with_deny_unknown_fields(true))
+        } else {
+            crate::utils::empty()
+        };
 
         quote! {
             #type_info_path::#type_info_kind(
@@ -145,6 +193,8 @@ impl<'a> ReflectStruct<'a> {
                     #with_generics
                     #with_custom_attributes
                     #with_docs
+                    #with_version
+                    #with_deny_unknown_fields
             )
         }
     }
@@ -0,0 +1,37 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use crate::derive_data::ReflectMeta;
+
+/// Impl `ReflectAlias` for `#[reflect(alias = B)]`, and assert the layout of the two types.
+pub(crate) fn impl_alias(meta: &ReflectMeta) -> TokenStream {
+    let alias = meta.attrs().alias.as_ref().expect("`impl_alias` requires `#[reflect(alias = ...)]`");
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_alias_ = crate::path::reflect_alias_(vct_reflect_path);
+
+    let real_ident = meta.type_path_parser().real_ident();
+
+    quote! {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#real_ident>() == ::core::mem::size_of::<#alias>()
+                && ::core::mem::align_of::<#real_ident>() == ::core::mem::align_of::<#alias>(),
+            "The alias type must have the same layout as the reflected type.",
+        );
+
+        // Safety: `#[repr(transparent)]` is checked by the macro, and the layout is asserted.
+        #[expect(unsafe_code, reason = "`ReflectAlias` requires the same layout.")]
+        unsafe impl #reflect_alias_ for #real_ident {
+            type Alias = #alias;
+
+            #[inline]
+            fn from_alias(alias: Self::Alias) -> Self {
+                Self(alias)
+            }
+
+            #[inline]
+            fn into_alias(self) -> Self::Alias {
+                self.0
+            }
+        }
+    }
+}
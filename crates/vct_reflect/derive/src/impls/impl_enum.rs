@@ -1,19 +1,25 @@
 use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
 
-use crate::{derive_data::ReflectEnum, impls::{impl_trait_type_path, impl_trait_typed}};
+use crate::{derive_data::{EnumVariant, EnumVariantFields, ReflectEnum, ReflectMeta, StructField}, impls::{impl_trait_get_type_traits, impl_trait_reflect, impl_trait_type_path, impl_trait_typed}};
 
 
 
 pub(crate) fn impl_enum(info: &ReflectEnum) -> TokenStream {
     let meta = info.meta();
 
+    // Field level comparison functions are only supported on structs.
+    if let Err(err) = check_field_custom_fns(info) {
+        return err.into_compile_error();
+    }
+
     // trait: TypePath
     let type_path_trait_tokens = if meta.attrs().impl_switchs.impl_type_path {
         impl_trait_type_path(meta)
     } else {
         crate::utils::empty()
     };
-    
+
     // trait: Typed
     let typed_trait_tokens = if meta.attrs().impl_switchs.impl_typed {
         impl_trait_typed(meta, info.to_info_tokens())
@@ -21,5 +27,685 @@ pub(crate) fn impl_enum(info: &ReflectEnum) -> TokenStream {
         crate::utils::empty()
     };
 
-    todo!()
+    // trait: Enum
+    let enum_trait_tokens = if meta.attrs().impl_switchs.impl_enum {
+        impl_trait_enum(info)
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: Reflect
+    let reflect_trait_tokens = if meta.attrs().impl_switchs.impl_reflect {
+        let try_apply_tokens = get_enum_try_apply_impl(info);
+        let to_dynamic_tokens = get_enum_to_dynamic_impl(meta);
+        let reflect_clone_tokens = get_enum_clone_impl(info);
+        let reflect_partial_eq_tokens = get_enum_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_enum_partial_cmp_impl(meta);
+        let reflect_hash_tokens = get_enum_hash_impl(meta);
+        let reflect_debug_tokens = get_enum_debug_impl(meta);
+
+        impl_trait_reflect(
+            meta,
+            quote!(Enum),
+            try_apply_tokens,
+            to_dynamic_tokens,
+            reflect_clone_tokens,
+            reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
+            reflect_hash_tokens,
+            reflect_debug_tokens,
+        )
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: GetTypeTraits
+    let get_type_traits_tokens = if meta.attrs().impl_switchs.impl_get_type_traits {
+        impl_trait_get_type_traits(meta, crate::utils::empty())
+    } else {
+        crate::utils::empty()
+    };
+
+    // trait: FromReflect
+    let from_reflect_tokens = if meta.attrs().impl_switchs.impl_from_reflect {
+        impl_enum_from_reflect(info)
+    } else {
+        crate::utils::empty()
+    };
+
+    quote! {
+        #type_path_trait_tokens
+
+        #typed_trait_tokens
+
+        #enum_trait_tokens
+
+        #reflect_trait_tokens
+
+        #get_type_traits_tokens
+
+        #from_reflect_tokens
+    }
+}
+
+fn check_field_custom_fns(info: &ReflectEnum) -> syn::Result<()> {
+    for field in info.active_fields() {
+        let custom_fns = &field.attrs.custom_fns;
+        let unsupported = custom_fns.partial_eq.as_ref()
+            .or(custom_fns.partial_cmp.as_ref())
+            .or(custom_fns.hash.as_ref())
+            .or(custom_fns.debug.as_ref());
+        if let Some(path) = unsupported {
+            return Err(syn::Error::new_spanned(
+                path,
+                "field level `partial_eq`, `partial_cmp`, `hash` and `debug` are not supported on enum variants, \
+                use the type level attribute instead.",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The binding of a field in match patterns, such as `__field_0`.
+fn field_binding(field: &StructField) -> syn::Ident {
+    format_ident!("__field_{}", field.declaration_index)
+}
+
+/// Generates a pattern binding the given fields, such as `Self::A { x: __field_0, .. }`.
+///
+/// The braced form also works for tuple and unit variants.
+fn variant_pattern<'a>(
+    variant: &EnumVariant,
+    fields: impl Iterator<Item = &'a StructField<'a>>,
+) -> TokenStream {
+    let ident = &variant.data.ident;
+    let bindings = fields.map(|field| {
+        let member = field.to_member();
+        let binding = field_binding(field);
+        quote!(#member: #binding)
+    });
+    quote!(Self::#ident { #(#bindings,)* .. })
+}
+
+fn field_name(field: &StructField) -> String {
+    field.data.ident.as_ref()
+        .map(ToString::to_string)
+        .expect("Struct variant should not have unnamed fields.")
+}
+
+fn impl_trait_enum(info: &ReflectEnum) -> TokenStream {
+    use crate::path::fp::OptionFP;
+    let meta = info.meta();
+    let option_ = OptionFP.to_token_stream();
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let enum_ = crate::path::enum_(vct_reflect_path);
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let variant_field_iter_ = crate::path::variant_field_iter_(vct_reflect_path);
+    let variant_kind_ = crate::path::variant_kind_(vct_reflect_path);
+
+    let mut field_arms = Vec::new();
+    let mut field_at_arms = Vec::new();
+    let mut index_of_arms = Vec::new();
+    let mut name_at_arms = Vec::new();
+    let mut field_len_arms = Vec::new();
+    let mut variant_name_arms = Vec::new();
+    let mut variant_index_arms = Vec::new();
+    let mut variant_kind_arms = Vec::new();
+
+    for (variant_index, variant) in info.variants().iter().enumerate() {
+        let variant_name = variant.data.ident.to_string();
+        let empty_pattern = variant_pattern(variant, core::iter::empty());
+        let pattern = variant_pattern(variant, variant.active_fields());
+
+        let indices = variant.active_fields().map(|field| {
+            field.reflection_index.expect("Active fields should have reflection index.")
+        }).collect::<Vec<_>>();
+        let bindings = variant.active_fields().map(field_binding).collect::<Vec<_>>();
+        let field_count = indices.len();
+
+        let variant_kind = match &variant.fields {
+            EnumVariantFields::Named(_) => quote!(Struct),
+            EnumVariantFields::Unnamed(_) => quote!(Tuple),
+            EnumVariantFields::Unit => quote!(Unit),
+        };
+
+        if let EnumVariantFields::Named(_) = &variant.fields {
+            let names = variant.active_fields().map(field_name).collect::<Vec<_>>();
+            field_arms.push(quote! {
+                #pattern => match name {
+                    #(#names => #option_::Some(#bindings),)*
+                    _ => #OptionFP::None,
+                },
+            });
+            index_of_arms.push(quote! {
+                #empty_pattern => match name {
+                    #(#names => #option_::Some(#indices),)*
+                    _ => #OptionFP::None,
+                },
+            });
+            name_at_arms.push(quote! {
+                #empty_pattern => match index {
+                    #(#indices => #option_::Some(#names),)*
+                    _ => #OptionFP::None,
+                },
+            });
+        }
+
+        if field_count > 0 {
+            field_at_arms.push(quote! {
+                #pattern => match index {
+                    #(#indices => #option_::Some(#bindings),)*
+                    _ => #OptionFP::None,
+                },
+            });
+        }
+
+        field_len_arms.push(quote!(#empty_pattern => #field_count,));
+        variant_name_arms.push(quote!(#empty_pattern => #variant_name,));
+        variant_index_arms.push(quote!(#empty_pattern => #variant_index,));
+        variant_kind_arms.push(quote!(#empty_pattern => #variant_kind_::#variant_kind,));
+    }
+
+    let parser = meta.type_path_parser();
+    let real_ident = parser.real_ident();
+    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+
+    quote! {
+        impl #impl_generics #enum_ for #real_ident #ty_generics #where_clause {
+            fn field(&self, name: &str) -> #OptionFP<&dyn #reflect_> {
+                match self {
+                    #(#field_arms)*
+                    _ => #OptionFP::None,
+                }
+            }
+
+            fn field_at(&self, index: usize) -> #OptionFP<&dyn #reflect_> {
+                match self {
+                    #(#field_at_arms)*
+                    _ => #OptionFP::None,
+                }
+            }
+
+            fn field_mut(&mut self, name: &str) -> #OptionFP<&mut dyn #reflect_> {
+                match self {
+                    #(#field_arms)*
+                    _ => #OptionFP::None,
+                }
+            }
+
+            fn field_at_mut(&mut self, index: usize) -> #OptionFP<&mut dyn #reflect_> {
+                match self {
+                    #(#field_at_arms)*
+                    _ => #OptionFP::None,
+                }
+            }
+
+            fn index_of(&self, name: &str) -> #OptionFP<usize> {
+                match self {
+                    #(#index_of_arms)*
+                    _ => #OptionFP::None,
+                }
+            }
+
+            fn name_at(&self, index: usize) -> #OptionFP<&str> {
+                match self {
+                    #(#name_at_arms)*
+                    _ => #OptionFP::None,
+                }
+            }
+
+            #[inline]
+            fn iter_fields(&self) -> #variant_field_iter_ {
+                #variant_field_iter_::new(self)
+            }
+
+            #[inline]
+            fn field_len(&self) -> usize {
+                match self {
+                    #(#field_len_arms)*
+                }
+            }
+
+            #[inline]
+            fn variant_name(&self) -> &str {
+                match self {
+                    #(#variant_name_arms)*
+                }
+            }
+
+            #[inline]
+            fn variant_index(&self) -> usize {
+                match self {
+                    #(#variant_index_arms)*
+                }
+            }
+
+            #[inline]
+            fn variant_kind(&self) -> #variant_kind_ {
+                match self {
+                    #(#variant_kind_arms)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates the expression constructing a variant from `#ref_enum` (a `&dyn Enum`).
+///
+/// `get_value` maps `(field_ty, field_value, missing_value)` to the value of an active field,
+/// `field_value` is an `Option<&dyn Reflect>`. Ignored fields use the default value.
+fn construct_variant(
+    variant: &EnumVariant,
+    ref_enum: &syn::Ident,
+    vct_reflect_path: &syn::Path,
+    get_value: impl Fn(&StructField, TokenStream) -> TokenStream,
+) -> TokenStream {
+    use crate::path::fp::DefaultFP;
+
+    let enum_ = crate::path::enum_(vct_reflect_path);
+    let ident = &variant.data.ident;
+
+    let field_values = variant.fields().iter().map(|field| {
+        let member = field.to_member();
+        let field_ty = &field.data.ty;
+
+        if field.attrs.ignore {
+            let default_value = match &field.attrs.custom_fns.default {
+                Some(func) => quote!(#func()),
+                None => quote!(<#field_ty as #DefaultFP>::default()),
+            };
+            return quote!(#member: #default_value,);
+        }
+
+        let field_value = match &variant.fields {
+            EnumVariantFields::Named(_) => {
+                let name = field_name(field);
+                quote!(#enum_::field(#ref_enum, #name))
+            }
+            _ => {
+                let index = field.reflection_index.expect("Active fields should have reflection index.");
+                quote!(#enum_::field_at(#ref_enum, #index))
+            }
+        };
+        let value = get_value(field, field_value);
+        quote!(#member: #value,)
+    });
+
+    quote!(Self::#ident { #(#field_values)* })
+}
+
+fn get_enum_try_apply_impl(info: &ReflectEnum) -> TokenStream {
+    use crate::path::fp::{ResultFP, OptionFP};
+
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let reflect_ref = crate::path::reflect_ref_(vct_reflect_path);
+    let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+    let enum_ = crate::path::enum_(vct_reflect_path);
+    let reflect_kind_ = crate::path::reflect_kind_(vct_reflect_path);
+    let variant_kind_ = crate::path::variant_kind_(vct_reflect_path);
+    let apply_error_ = crate::path::apply_error_(vct_reflect_path);
+    let type_path_ = crate::path::type_path_(vct_reflect_path);
+    let dynamic_type_path_ = crate::path::dynamic_type_path_(vct_reflect_path);
+
+    let clone_tokens = if meta.attrs().avail_traits.clone {
+        let clone_value = meta.clone_expr(quote!(value));
+        quote! {
+            if let #OptionFP::Some(value) = <dyn #reflect_>::downcast_ref::<Self>(value) {
+                *self = #clone_value;
+                return #ResultFP::Ok(());
+            }
+        }
+    } else {
+        crate::utils::empty()
+    };
+
+    let ref_enum = format_ident!("__ref_enum");
+
+    // Switching to another variant, every active field must be present.
+    let variant_arms = info.variants().iter().map(|variant| {
+        let variant_name = variant.data.ident.to_string();
+        let construct = construct_variant(variant, &ref_enum, vct_reflect_path, |field, field_value| {
+            let field_ty = &field.data.ty;
+            let field_name = match &variant.fields {
+                EnumVariantFields::Named(_) => field_name(field),
+                _ => field.reflection_index.expect("Active fields should have reflection index.").to_string(),
+            };
+            quote! {
+                match #field_value {
+                    #OptionFP::Some(value) => match <#field_ty as #from_reflect_>::from_reflect(value) {
+                        #OptionFP::Some(value) => value,
+                        #OptionFP::None => return #ResultFP::Err(#apply_error_::MismatchedTypes {
+                            from_type: #alloc_utils_::Cow::Owned(#alloc_utils_::ToOwned::to_owned(#dynamic_type_path_::reflect_type_path(value))),
+                            to_type: #alloc_utils_::Cow::Borrowed(<#field_ty as #type_path_>::type_path()),
+                        }),
+                    },
+                    #OptionFP::None => return #ResultFP::Err(#apply_error_::MissingEnumField {
+                        variant_name: #alloc_utils_::Cow::Borrowed(#variant_name),
+                        field_name: #alloc_utils_::Cow::Borrowed(#field_name),
+                    }),
+                }
+            }
+        });
+        quote!(#variant_name => *self = #construct,)
+    });
+
+    quote! {
+        fn try_apply(&mut self, value: &dyn #reflect_) -> #ResultFP<(), #apply_error_> {
+
+            #clone_tokens
+
+            let #reflect_ref::Enum(#ref_enum) = #reflect_::reflect_ref(value) else {
+                return #ResultFP::Err(
+                    #apply_error_::MismatchedKinds {
+                        from_kind: #reflect_::reflect_kind(value),
+                        to_kind: #reflect_kind_::Enum,
+                    }
+                );
+            };
+
+            if #enum_::variant_name(self) == #enum_::variant_name(#ref_enum) {
+                // Same variant, apply the fields.
+                match #enum_::variant_kind(#ref_enum) {
+                    #variant_kind_::Struct => {
+                        for field in #enum_::iter_fields(#ref_enum) {
+                            let name = field.name().unwrap();
+                            if let #OptionFP::Some(v) = #enum_::field_mut(self, name) {
+                                #reflect_::try_apply(v, field.value())?;
+                            }
+                        }
+                    }
+                    #variant_kind_::Tuple => {
+                        for (index, field) in ::core::iter::Iterator::enumerate(#enum_::iter_fields(#ref_enum)) {
+                            if let #OptionFP::Some(v) = #enum_::field_at_mut(self, index) {
+                                #reflect_::try_apply(v, field.value())?;
+                            }
+                        }
+                    }
+                    #variant_kind_::Unit => {}
+                }
+            } else {
+                match #enum_::variant_name(#ref_enum) {
+                    #(#variant_arms)*
+                    name => {
+                        return #ResultFP::Err(#apply_error_::UnknownVariant {
+                            enum_name: #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path()),
+                            variant_name: #alloc_utils_::Cow::Owned(#alloc_utils_::ToOwned::to_owned(name)),
+                        });
+                    }
+                }
+            }
+            #ResultFP::Ok(())
+        }
+    }
+}
+
+fn get_enum_to_dynamic_impl(meta: &ReflectMeta) -> TokenStream {
+    let vct_reflect_path = meta.vct_reflect_path();
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let enum_ = crate::path::enum_(vct_reflect_path);
+
+    quote! {
+        #[inline]
+        fn to_dynamic(&self) -> #alloc_utils_::Box<dyn #reflect_> {
+            #alloc_utils_::Box::new( #enum_::to_dynamic_enum(self) )
+        }
+    }
+}
+
+fn get_enum_clone_impl(info: &ReflectEnum) -> TokenStream {
+    use crate::path::fp::{ResultFP, OptionFP};
+
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
+    let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+    let macro_exports_ = crate::path::macro_exports_(vct_reflect_path);
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let reflect_clone_error_ = crate::path::reflect_clone_error_(vct_reflect_path);
+    let type_path_ = crate::path::type_path_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.clone {
+        let clone_self = meta.clone_expr(quote!(self));
+        return quote! {
+            #[inline]
+            fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
+                #ResultFP::Ok(#alloc_utils_::Box::new(#clone_self) as #alloc_utils_::Box<dyn #reflect_>)
+            }
+        };
+    }
+
+    let variant_arms = info.variants().iter().map(|variant| {
+        let ident = &variant.data.ident;
+        let variant_name = ident.to_string();
+
+        // Ignored fields can still be cloned by `#[reflect(clone = path)]`.
+        let not_cloneable = variant.fields().iter()
+            .find(|field| field.attrs.ignore && field.attrs.custom_fns.clone.is_none());
+        if let Some(field) = not_cloneable {
+            let pattern = variant_pattern(variant, core::iter::empty());
+            let field_id = field.field_id(vct_reflect_path);
+            return quote! {
+                #pattern => #ResultFP::Err(#reflect_clone_error_::FieldNotCloneable {
+                    type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path()),
+                    field: #field_id,
+                    variant: #OptionFP::Some(#alloc_utils_::Cow::Borrowed(#variant_name)),
+                }),
+            };
+        }
+
+        let pattern = variant_pattern(variant, variant.fields().iter());
+        let field_values = variant.fields().iter().map(|field| {
+            let field_ty = &field.data.ty;
+            let member = field.to_member();
+            let binding = field_binding(field);
+
+            let value = match &field.attrs.custom_fns.clone {
+                Some(func) => quote!(#func(#binding)),
+                None => {
+                    let field_id = field.field_id(vct_reflect_path);
+                    quote!(#macro_exports_::reflect_clone_field::<#field_ty>(#binding, #field_id)?)
+                },
+            };
+
+            quote!(#member: #value,)
+        });
+
+        quote! {
+            #pattern => #ResultFP::Ok(#alloc_utils_::Box::new(
+                Self::#ident { #(#field_values)* }
+            ) as #alloc_utils_::Box<dyn #reflect_>),
+        }
+    });
+
+    quote! {
+        fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
+            match self {
+                #(#variant_arms)*
+            }
+        }
+    }
+}
+
+fn get_enum_partial_eq_impl(meta: &ReflectMeta) -> TokenStream  {
+    use crate::path::fp::OptionFP;
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.partial_eq {
+        let eq = meta.partial_eq_expr(quote!(self), quote!(value));
+        quote! {
+            #[inline]
+            fn reflect_partial_eq(&self, other: &dyn #reflect_) -> #OptionFP<bool> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #OptionFP::Some( #eq );
+                }
+                #OptionFP::None
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
+fn get_enum_partial_cmp_impl(meta: &ReflectMeta) -> TokenStream  {
+    use crate::path::fp::{OptionFP, OrderingFP};
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.partial_cmp {
+        let cmp = meta.partial_cmp_expr(quote!(self), quote!(value));
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #cmp;
+                }
+                #OptionFP::None
+            }
+        }
+    } else if meta.attrs().internal_partial_cmp {
+        let enum_partial_cmp_ = crate::path::enum_partial_cmp_(vct_reflect_path);
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                #enum_partial_cmp_(self, other)
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
+fn get_enum_hash_impl(meta: &ReflectMeta) -> TokenStream {
+    use crate::path::fp::{OptionFP, HasherFP};
+
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_hasher = crate::path::reflect_hasher_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.hash {
+        let hash = meta.hash_expr(quote!(self), quote!(&mut hasher));
+        quote! {
+            #[inline]
+            fn reflect_hash(&self) -> #OptionFP<u64> {
+                let mut hasher = #reflect_hasher();
+                #hash;
+                #OptionFP::Some(#HasherFP::finish(&hasher))
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
+fn get_enum_debug_impl(meta: &ReflectMeta) -> TokenStream {
+    if meta.attrs().avail_traits.debug {
+        let debug = meta.debug_expr(quote!(self), quote!(f));
+        quote! {
+            #[inline]
+            fn reflect_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #debug
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
+fn impl_enum_from_reflect(info: &ReflectEnum) -> TokenStream {
+    use crate::path::fp::{OptionFP, ResultFP, DefaultFP};
+
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
+    let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+    let from_reflect_error_ = crate::path::from_reflect_error_(vct_reflect_path);
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let reflect_ref_ = crate::path::reflect_ref_(vct_reflect_path);
+    let enum_ = crate::path::enum_(vct_reflect_path);
+
+    let clone_tokens = if meta.attrs().avail_traits.clone {
+        let clone_value = meta.clone_expr(quote!(value));
+        quote! {
+            if let #OptionFP::Some(value) = <dyn #reflect_>::downcast_ref::<Self>(value) {
+                return #ResultFP::Ok(#clone_value);
+            }
+        }
+    } else {
+        crate::utils::empty()
+    };
+
+    let ref_enum = format_ident!("__ref_enum");
+
+    let variant_arms = info.variants().iter().map(|variant| {
+        let variant_name = variant.data.ident.to_string();
+        let construct = construct_variant(variant, &ref_enum, vct_reflect_path, |field, field_value| {
+            let field_ty = &field.data.ty;
+
+            // The field is added to the path of errors.
+            let with_field = match &variant.fields {
+                EnumVariantFields::Named(_) => {
+                    let name = field_name(field);
+                    quote!(with_field(#name))
+                }
+                _ => {
+                    let index = field.reflection_index.expect("Active fields should have reflection index.");
+                    quote!(with_tuple_index(#index))
+                }
+            };
+
+            let missing_value = if field.attrs.default {
+                match &field.attrs.custom_fns.default {
+                    Some(func) => quote!(#func()),
+                    None => quote!(<#field_ty as #DefaultFP>::default()),
+                }
+            } else {
+                quote! {
+                    return #ResultFP::Err(
+                        #from_reflect_error_::missing_field::<#field_ty>().#with_field
+                    )
+                }
+            };
+
+            quote! {
+                match #field_value {
+                    #OptionFP::Some(value) => <#field_ty as #from_reflect_>::try_from_reflect(value)
+                        .map_err(|err| err.#with_field)?,
+                    #OptionFP::None => #missing_value,
+                }
+            }
+        });
+        quote!(#variant_name => #ResultFP::Ok(#construct),)
+    });
+
+    let parser = meta.type_path_parser();
+    let real_ident = parser.real_ident();
+    let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+
+    quote! {
+        impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
+            #[inline]
+            fn from_reflect(value: &dyn #reflect_) -> #OptionFP<Self> {
+                <Self as #from_reflect_>::try_from_reflect(value).ok()
+            }
+
+            fn try_from_reflect(value: &dyn #reflect_) -> #ResultFP<Self, #from_reflect_error_> {
+
+                #clone_tokens
+
+                if let #reflect_ref_::Enum(#ref_enum) = #reflect_::reflect_ref(value) {
+                    match #enum_::variant_name(#ref_enum) {
+                        #(#variant_arms)*
+                        _ => #ResultFP::Err(#from_reflect_error_::mismatched::<Self>(value)),
+                    }
+                } else {
+                    #ResultFP::Err(#from_reflect_error_::mismatched::<Self>(value))
+                }
+            }
+        }
+    }
 }
@@ -23,6 +23,7 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
         let to_dynamic_tokens = get_opaque_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_opaque_clone_impl(meta);
         let reflect_partial_eq_tokens = get_opaque_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_opaque_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_opaque_hash_impl(meta);
         let reflect_debug_tokens = get_opaque_debug_impl(meta);
 
@@ -33,6 +34,7 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -68,7 +70,7 @@ pub(crate) fn impl_opaque(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_opaque_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::{ResultFP, OptionFP, CloneFP};
+    use crate::path::fp::{ResultFP, OptionFP};
 
     let vct_reflect_path = meta.vct_reflect_path();
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
@@ -76,11 +78,12 @@ fn get_opaque_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let apply_error_ = crate::path::apply_error_(vct_reflect_path);
     let type_path_ = crate::path::type_path_(vct_reflect_path);
     let dynamic_type_path_ = crate::path::dynamic_type_path_(vct_reflect_path);
+    let clone_value = meta.clone_expr(quote!(value));
 
     quote! {
         fn try_apply(&mut self, value: &dyn #reflect_) -> #ResultFP<(), #apply_error_> {
             if let #OptionFP::Some(value) = <dyn #reflect_>::downcast_ref::<Self>(value) {
-                *self = #CloneFP::clone(value);
+                *self = #clone_value;
                 return #ResultFP::Ok(());
             }
 
@@ -95,22 +98,21 @@ fn get_opaque_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_opaque_to_dynamic_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::CloneFP;
-
     let vct_reflect_path = meta.vct_reflect_path();
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
     let reflect_ = crate::path::reflect_(vct_reflect_path);
+    let clone_self = meta.clone_expr(quote!(self));
 
     quote! {
         #[inline]
         fn to_dynamic(&self) -> #alloc_utils_::Box<dyn #reflect_> {
-            #alloc_utils_::Box::new(<Self as #CloneFP>::clone(self))
+            #alloc_utils_::Box::new(#clone_self)
         }
     }
 }
 
 fn get_opaque_clone_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::{ResultFP, CloneFP};
+    use crate::path::fp::ResultFP;
 
     debug_assert!(meta.attrs().avail_traits.clone);
 
@@ -118,26 +120,49 @@ fn get_opaque_clone_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
     let reflect_ = crate::path::reflect_(vct_reflect_path);
     let reflect_clone_error_ = crate::path::reflect_clone_error_(vct_reflect_path);
+    let clone_self = meta.clone_expr(quote!(self));
 
     quote! {
         #[inline]
         fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
-            #ResultFP::Ok(#alloc_utils_::Box::new(<Self as #CloneFP>::clone(self)) as #alloc_utils_::Box<dyn #reflect_>)
+            #ResultFP::Ok(#alloc_utils_::Box::new(#clone_self) as #alloc_utils_::Box<dyn #reflect_>)
         }
     }
 }
 
 fn get_opaque_partial_eq_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
-    use crate::path::fp::{OptionFP, PartialEqFP};
+    use crate::path::fp::OptionFP;
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_ = crate::path::reflect_(vct_reflect_path);
 
     if meta.attrs().avail_traits.partial_eq {
+        let eq = meta.partial_eq_expr(quote!(self), quote!(value));
         quote! {
             #[inline]
             fn reflect_partial_eq(&self, other: &dyn #reflect_) -> #OptionFP<bool> {
                 if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
-                    return #OptionFP::Some( #PartialEqFP::eq(self, value) );
+                    return #OptionFP::Some( #eq );
+                }
+                #OptionFP::None
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
+fn get_opaque_partial_cmp_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
+    use crate::path::fp::{OptionFP, OrderingFP};
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.partial_cmp {
+        let cmp = meta.partial_cmp_expr(quote!(self), quote!(value));
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #cmp;
                 }
                 #OptionFP::None
             }
@@ -148,16 +173,17 @@ fn get_opaque_partial_eq_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
 }
 
 fn get_opaque_hash_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::{OptionFP, HashFP, HasherFP};
+    use crate::path::fp::{OptionFP, HasherFP};
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_hasher = crate::path::reflect_hasher_(vct_reflect_path);
 
     if meta.attrs().avail_traits.hash {
+        let hash = meta.hash_expr(quote!(self), quote!(&mut hasher));
         quote! {
             #[inline]
             fn reflect_hash(&self) -> #OptionFP<u64> {
                 let mut hasher = #reflect_hasher();
-                <Self as #HashFP>::hash(self, &mut hasher);
+                #hash;
                 #OptionFP::Some(#HasherFP::finish(&hasher))
             }
         }
@@ -167,13 +193,12 @@ fn get_opaque_hash_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_opaque_debug_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::DebugFP;
-
     if meta.attrs().avail_traits.debug {
+        let debug = meta.debug_expr(quote!(self), quote!(f));
         quote! {
             #[inline]
             fn reflect_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                <Self as #DebugFP>::fmt(self, f)
+                #debug
             }
         }
     } else {
@@ -182,7 +207,7 @@ fn get_opaque_debug_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn impl_opaque_from_reflect(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::{OptionFP, CloneFP};
+    use crate::path::fp::OptionFP;
 
     assert!(meta.attrs().avail_traits.clone);
 
@@ -193,13 +218,13 @@ fn impl_opaque_from_reflect(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
     let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
+    let clone_value = meta.clone_expr(quote!(value));
 
     quote! {
         impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
             fn from_reflect(value: &dyn #reflect_) -> #OptionFP<Self> {
-                #OptionFP::Some(#CloneFP::clone(
-                    <dyn #reflect_>::downcast_ref::<Self>(value)?
-                ))
+                let value = <dyn #reflect_>::downcast_ref::<Self>(value)?;
+                #OptionFP::Some(#clone_value)
             }
         }
     }
@@ -34,9 +34,10 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
         let try_apply_tokens = get_struct_try_apply_impl(meta);
         let to_dynamic_tokens = get_struct_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_struct_clone_impl(info);
-        let reflect_partial_eq_tokens = get_struct_partial_eq_impl(meta);
-        let reflect_hash_tokens = get_struct_hash_impl(meta);
-        let reflect_debug_tokens = get_struct_debug_impl(meta);
+        let reflect_partial_eq_tokens = get_struct_partial_eq_impl(info);
+        let reflect_partial_cmp_tokens = get_struct_partial_cmp_impl(info);
+        let reflect_hash_tokens = get_struct_hash_impl(info);
+        let reflect_debug_tokens = get_struct_debug_impl(info);
 
         impl_trait_reflect(
             meta, 
@@ -45,6 +46,7 @@ pub(crate) fn impl_struct(info: &ReflectStruct) -> TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -170,7 +172,7 @@ pub fn impl_trait_struct(info: &ReflectStruct) -> TokenStream {
 }
 
 pub fn get_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
-    use crate::path::fp::{ResultFP, OptionFP, CloneFP};
+    use crate::path::fp::{ResultFP, OptionFP};
 
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_ = crate::path::reflect_(vct_reflect_path);
@@ -180,10 +182,11 @@ pub fn get_struct_try_apply_impl(meta: &ReflectMeta) -> TokenStream {
     let apply_error_ = crate::path::apply_error_(vct_reflect_path);
 
     if meta.attrs().avail_traits.clone {
+        let clone_value = meta.clone_expr(quote!(value));
         quote! {
             fn try_apply(&mut self, value: &dyn #reflect_) -> #ResultFP<(), #apply_error_> {
                 if let #OptionFP::Some(value) = <dyn #reflect_>::downcast_ref::<Self>(value) {
-                    *self = #CloneFP::clone(value);
+                    *self = #clone_value;
                     return #ResultFP::Ok(());
                 }
                 if let #reflect_ref::Struct(struct_value) = #reflect_::reflect_ref(value) {
@@ -244,7 +247,7 @@ fn get_struct_to_dynamic_impl(meta: &ReflectMeta) -> TokenStream {
 }
 
 fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
-    use crate::path::fp::{ResultFP, CloneFP, OptionFP};
+    use crate::path::fp::{ResultFP, OptionFP};
 
     let meta = info.meta();
     let vct_reflect_path = meta.vct_reflect_path();
@@ -256,21 +259,23 @@ fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
 
 
     if meta.attrs().avail_traits.clone {
+        let clone_self = meta.clone_expr(quote!(self));
         quote! {
             #[inline]
             fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
-                #ResultFP::Ok(#alloc_utils_::Box::new(<Self as #CloneFP>::clone(self)) as #alloc_utils_::Box<dyn #reflect_>)
+                #ResultFP::Ok(#alloc_utils_::Box::new(#clone_self) as #alloc_utils_::Box<dyn #reflect_>)
             }
         }
     } else {
         for field in info.fields().iter() {
-            if field.attrs.ignore {
+            // Ignored fields can still be cloned by `#[reflect(clone = path)]`.
+            if field.attrs.ignore && field.attrs.custom_fns.clone.is_none() {
                 let field_id = field.field_id(vct_reflect_path);
                 return quote! {
                     #[inline]
                     fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
                         #ResultFP::Err(#reflect_clone_error_::FieldNotCloneable {
-                            type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path())
+                            type_path:  #alloc_utils_::Cow::Borrowed(<Self as #type_path_>::type_path()),
                             field: #field_id,
                             variant: #OptionFP::None,
                         })
@@ -286,8 +291,16 @@ fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
             let member = field.to_member();
             let accessor = info.access_for_field(field, false);
 
+            let value = match &field.attrs.custom_fns.clone {
+                Some(func) => quote!(#func(#accessor)),
+                None => {
+                    let field_id = field.field_id(vct_reflect_path);
+                    quote!(#macro_exports_::reflect_clone_field::<#field_ty>(#accessor, #field_id)?)
+                },
+            };
+
             tokens.extend(quote! {
-                #member: #macro_exports_::reflect_clone_field::<#field_ty>(#accessor)?,
+                #member: #value,
             });
         }
 
@@ -304,38 +317,212 @@ fn get_struct_clone_impl(info: &ReflectStruct) -> TokenStream {
     }
 }
 
-fn get_struct_partial_eq_impl(meta: &ReflectMeta) -> TokenStream  {
-    use crate::path::fp::{OptionFP, PartialEqFP};
+fn get_struct_partial_eq_impl(info: &ReflectStruct) -> TokenStream  {
+    use crate::path::fp::OptionFP;
+
+    let meta = info.meta();
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_ = crate::path::reflect_(vct_reflect_path);
 
     if meta.attrs().avail_traits.partial_eq {
+        let eq = meta.partial_eq_expr(quote!(self), quote!(value));
         quote! {
             #[inline]
             fn reflect_partial_eq(&self, other: &dyn #reflect_) -> #OptionFP<bool> {
                 if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
-                    return #OptionFP::Some( #PartialEqFP::eq(self, value) );
+                    return #OptionFP::Some( #eq );
+                }
+                #OptionFP::None
+            }
+        }
+    } else if info.active_fields().any(|field| field.attrs.custom_fns.partial_eq.is_some()) {
+        // Compare field by field, other structs (such as `DynamicStruct`) are compared by field name,
+        // the custom function is used if the field of `other` can be downcast to the field type.
+        let struct_ = crate::path::struct_(vct_reflect_path);
+        let reflect_ref_ = crate::path::reflect_ref_(vct_reflect_path);
+
+        let field_eqs = info.active_fields().map(|field| {
+            let member = field.to_member();
+            match &field.attrs.custom_fns.partial_eq {
+                Some(func) => quote! {
+                    if !#func(&self.#member, &value.#member) {
+                        return #OptionFP::Some(false);
+                    }
+                },
+                None => quote! {
+                    let result = #reflect_::reflect_partial_eq(&self.#member, &value.#member);
+                    if result != #OptionFP::Some(true) {
+                        return result;
+                    }
+                },
+            }
+        });
+
+        let dynamic_field_eqs = info.active_fields().map(|field| {
+            let member = field.to_member();
+            let name = member.to_token_stream().to_string();
+            let ty = &field.data.ty;
+            let result = match &field.attrs.custom_fns.partial_eq {
+                Some(func) => quote! {
+                    match field.downcast_ref::<#ty>() {
+                        #OptionFP::Some(field) => #OptionFP::Some(#func(&self.#member, field)),
+                        #OptionFP::None => #reflect_::reflect_partial_eq(&self.#member, field),
+                    }
+                },
+                None => quote!(#reflect_::reflect_partial_eq(&self.#member, field)),
+            };
+            quote! {
+                let #OptionFP::Some(field) = #struct_::field(value, #name) else {
+                    return #OptionFP::Some(false);
+                };
+                let result = #result;
+                if result != #OptionFP::Some(true) {
+                    return result;
+                }
+            }
+        });
+
+        quote! {
+            fn reflect_partial_eq(&self, other: &dyn #reflect_) -> #OptionFP<bool> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    #(#field_eqs)*
+                    return #OptionFP::Some(true);
+                }
+                let #reflect_ref_::Struct(value) = #reflect_::reflect_ref(other) else {
+                    return #OptionFP::Some(false);
+                };
+                if #struct_::field_len(value) != #struct_::field_len(self) {
+                    return #OptionFP::Some(false);
+                }
+                #(#dynamic_field_eqs)*
+                #OptionFP::Some(true)
+            }
+        }
+    } else {
+        crate::utils::empty()
+    }
+}
+
+fn get_struct_partial_cmp_impl(info: &ReflectStruct) -> TokenStream  {
+    use crate::path::fp::{OptionFP, OrderingFP};
+
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.partial_cmp {
+        let cmp = meta.partial_cmp_expr(quote!(self), quote!(value));
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #cmp;
                 }
                 #OptionFP::None
             }
         }
+    } else if meta.attrs().internal_partial_cmp
+        || info.active_fields().any(|field| field.attrs.custom_fns.partial_cmp.is_some())
+    {
+        // Lexicographic comparison in the declaration order, like `#[derive(PartialOrd)]`,
+        // other structs (such as `DynamicStruct`) are compared by field name,
+        // the custom function is used if the field of `other` can be downcast to the field type.
+        let struct_ = crate::path::struct_(vct_reflect_path);
+        let reflect_ref_ = crate::path::reflect_ref_(vct_reflect_path);
+
+        let field_cmps = info.active_fields().map(|field| {
+            let member = field.to_member();
+            let cmp = match &field.attrs.custom_fns.partial_cmp {
+                Some(func) => quote!(#func(&self.#member, &value.#member)),
+                None => quote!(#reflect_::reflect_partial_cmp(&self.#member, &value.#member)),
+            };
+            quote! {
+                match #cmp {
+                    #OptionFP::Some(#OrderingFP::Equal) => {},
+                    ordering => return ordering,
+                }
+            }
+        });
+
+        let dynamic_field_cmps = info.active_fields().map(|field| {
+            let member = field.to_member();
+            let name = member.to_token_stream().to_string();
+            let ty = &field.data.ty;
+            let cmp = match &field.attrs.custom_fns.partial_cmp {
+                Some(func) => quote! {
+                    match field.downcast_ref::<#ty>() {
+                        #OptionFP::Some(field) => #func(&self.#member, field),
+                        #OptionFP::None => #reflect_::reflect_partial_cmp(&self.#member, field),
+                    }
+                },
+                None => quote!(#reflect_::reflect_partial_cmp(&self.#member, field)),
+            };
+            quote! {
+                let field = #struct_::field(value, #name)?;
+                match #cmp {
+                    #OptionFP::Some(#OrderingFP::Equal) => {},
+                    ordering => return ordering,
+                }
+            }
+        });
+
+        quote! {
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    #(#field_cmps)*
+                    return #OptionFP::Some(#OrderingFP::Equal);
+                }
+                let #reflect_ref_::Struct(value) = #reflect_::reflect_ref(other) else {
+                    return #OptionFP::None;
+                };
+                if #struct_::field_len(value) != #struct_::field_len(self) {
+                    return #OptionFP::None;
+                }
+                #(#dynamic_field_cmps)*
+                #OptionFP::Some(#OrderingFP::Equal)
+            }
+        }
     } else {
         crate::utils::empty()
     }
 }
 
-fn get_struct_hash_impl(meta: &ReflectMeta) -> TokenStream {
-    use crate::path::fp::{OptionFP, HashFP, HasherFP};
+fn get_struct_hash_impl(info: &ReflectStruct) -> TokenStream {
+    use crate::path::fp::{OptionFP, HashFP, HasherFP, TypeIdFP};
 
+    let meta = info.meta();
     let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
     let reflect_hasher = crate::path::reflect_hasher_(vct_reflect_path);
 
     if meta.attrs().avail_traits.hash {
+        let hash = meta.hash_expr(quote!(self), quote!(&mut hasher));
         quote! {
             #[inline]
             fn reflect_hash(&self) -> #OptionFP<u64> {
                 let mut hasher = #reflect_hasher();
-                <Self as #HashFP>::hash(self, &mut hasher);
+                #hash;
+                #OptionFP::Some(#HasherFP::finish(&hasher))
+            }
+        }
+    } else if info.active_fields().any(|field| field.attrs.custom_fns.hash.is_some()) {
+        let field_hashes = info.active_fields().map(|field| {
+            let member = field.to_member();
+            match &field.attrs.custom_fns.hash {
+                Some(func) => quote! {
+                    #func(&self.#member, &mut hasher);
+                },
+                None => quote! {
+                    #HasherFP::write_u64(&mut hasher, #reflect_::reflect_hash(&self.#member)?);
+                },
+            }
+        });
+
+        quote! {
+            fn reflect_hash(&self) -> #OptionFP<u64> {
+                let mut hasher = #reflect_hasher();
+                <#TypeIdFP as #HashFP>::hash(&#TypeIdFP::of::<Self>(), &mut hasher);
+                #(#field_hashes)*
                 #OptionFP::Some(#HasherFP::finish(&hasher))
             }
         }
@@ -344,19 +531,49 @@ fn get_struct_hash_impl(meta: &ReflectMeta) -> TokenStream {
     }
 }
 
-fn get_struct_debug_impl(meta: &ReflectMeta) -> TokenStream {
-    use crate::path::fp::DebugFP;
+fn get_struct_debug_impl(info: &ReflectStruct) -> TokenStream {
+    let meta = info.meta();
+    let vct_reflect_path = meta.vct_reflect_path();
 
     if meta.attrs().avail_traits.debug {
+        let debug = meta.debug_expr(quote!(self), quote!(f));
         quote! {
             #[inline]
             fn reflect_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                <Self as #DebugFP>::fmt(self, f)
+                #debug
+            }
+        }
+    } else if info.active_fields().any(|field| field.attrs.custom_fns.debug.is_some()) {
+        let reflect_ = crate::path::reflect_(vct_reflect_path);
+        let type_path_ = crate::path::type_path_(vct_reflect_path);
+        let macro_exports_ = crate::path::macro_exports_(vct_reflect_path);
+
+        let field_debugs = info.active_fields().map(|field| {
+            let member = field.to_member();
+            let name = field
+                .data
+                .ident
+                .as_ref()
+                .map(ToString::to_string)
+                .expect("Struct should not have unnamed fields.");
+            match &field.attrs.custom_fns.debug {
+                Some(func) => quote! {
+                    .field(#name, &#macro_exports_::DebugFn(|f: &mut ::core::fmt::Formatter<'_>| #func(&self.#member, f)))
+                },
+                None => quote! {
+                    .field(#name, &#reflect_::as_reflect(&self.#member))
+                },
+            }
+        });
+
+        quote! {
+            fn reflect_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(<Self as #type_path_>::type_path())
+                    #(#field_debugs)*
+                    .finish()
             }
         }
     } else {
         crate::utils::empty()
     }
 }
-
-
@@ -1,27 +1,33 @@
 use crate::derive_data::ReflectStruct;
-use quote::{ToTokens, quote};
+use quote::quote;
 use syn::Ident;
 use proc_macro2::Span;
 
 
 pub(crate) fn impl_struct_from_reflect(info: &ReflectStruct, is_tuple: bool) -> proc_macro2::TokenStream {
-    use crate::path::fp::{OptionFP, CloneFP};
+    use crate::path::fp::{OptionFP, ResultFP, DefaultFP};
 
     let meta = info.meta();
     let vct_reflect_path = meta.vct_reflect_path();
     let from_reflect_ = crate::path::from_reflect_(vct_reflect_path);
+    let from_reflect_error_ = crate::path::from_reflect_error_(vct_reflect_path);
     let reflect_ = crate::path::reflect_(vct_reflect_path);
     let reflect_ref_ = crate::path::reflect_ref_(vct_reflect_path);
-    // let option_ = OptionFP.to_token_stream();
+    let struct_ = if is_tuple {
+        crate::path::tuple_struct_(vct_reflect_path)
+    } else {
+        crate::path::struct_(vct_reflect_path)
+    };
 
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
     let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
 
     let clone_tokens = if meta.attrs().avail_traits.clone {
+        let clone_value = meta.clone_expr(quote!(value));
         quote! {
-            if let Some(val) = <dyn #reflect_>::downcast_ref::<Self>(value) {
-                return #OptionFP::Some(#CloneFP::clone(val));
+            if let #OptionFP::Some(value) = <dyn #reflect_>::downcast_ref::<Self>(value) {
+                return #ResultFP::Ok(#clone_value);
             }
         }
     } else {
@@ -35,36 +41,68 @@ pub(crate) fn impl_struct_from_reflect(info: &ReflectStruct, is_tuple: bool) ->
         Ident::new("Struct", Span::call_site())
     };
 
-    // TODO
-    let constructor= quote! ( #OptionFP::None );
+    let field_values = info.fields().iter().map(|field| {
+        let member = field.to_member();
+        let field_ty = &field.data.ty;
+
+        // Used for ignored fields, and for missing fields with `#[reflect(default)]`.
+        let default_value = match &field.attrs.custom_fns.default {
+            Some(func) => quote!(#func()),
+            None => quote!(<#field_ty as #DefaultFP>::default()),
+        };
+
+        if field.attrs.ignore {
+            return quote!(#member: #default_value,);
+        }
+
+        // The field is added to the path of errors.
+        let (field_value, with_field) = if is_tuple {
+            let index = field.reflection_index.expect("Active fields should have reflection index.");
+            (quote!(#struct_::field(#ref_struct, #index)), quote!(with_tuple_index(#index)))
+        } else {
+            let name = field.data.ident.as_ref().map(ToString::to_string)
+                .expect("Struct should not have unnamed fields.");
+            (quote!(#struct_::field(#ref_struct, #name)), quote!(with_field(#name)))
+        };
+
+        let missing_value = if field.attrs.default {
+            default_value
+        } else {
+            quote! {
+                return #ResultFP::Err(
+                    #from_reflect_error_::missing_field::<#field_ty>().#with_field
+                )
+            }
+        };
+
+        quote! {
+            #member: match #field_value {
+                #OptionFP::Some(value) => <#field_ty as #from_reflect_>::try_from_reflect(value)
+                    .map_err(|err| err.#with_field)?,
+                #OptionFP::None => #missing_value,
+            },
+        }
+    });
 
     quote! {
         impl #impl_generics #from_reflect_ for #real_ident #ty_generics #where_clause  {
+            #[inline]
             fn from_reflect(value: &dyn #reflect_) -> #OptionFP<Self> {
+                <Self as #from_reflect_>::try_from_reflect(value).ok()
+            }
 
-                #clone_tokens
+            fn try_from_reflect(value: &dyn #reflect_) -> #ResultFP<Self, #from_reflect_error_> {
 
-                if <dyn #reflect_>::is::<Self>(value) {
-                    if let Ok(cloned) = #reflect_::reflect_clone(value)
-                        && Ok(val) = <dyn #reflect_>::take::<Self>(cloned)
-                    {
-                        return #OptionFP::Some(*val);
-                    }
-                }
+                #clone_tokens
 
-                if let #reflect_ref_::#ref_struct_type(#ref_struct) = #reflect_::reflect_ref(reflect) {
-                    #constructor
+                if let #reflect_ref_::#ref_struct_type(#ref_struct) = #reflect_::reflect_ref(value) {
+                    #ResultFP::Ok(Self {
+                        #(#field_values)*
+                    })
                 } else {
-                    #OptionFP::None
+                    #ResultFP::Err(#from_reflect_error_::mismatched::<Self>(value))
                 }
             }
         }
     }
 }
-
-
-
-
-
-
-
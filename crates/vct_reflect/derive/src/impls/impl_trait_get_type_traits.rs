@@ -17,8 +17,20 @@ pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_t
     
     let insert_default = if meta.attrs().avail_traits.default {
         let type_trait_default_ = crate::path::type_trait_default_(vct_reflect_path);
-        quote! {
-            #type_traits_::insert::<#type_trait_default_>(&mut type_traits, #from_type_::<Self>::from_type());
+        if meta.attrs().custom_fns.default.is_some() {
+            let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
+            let reflect_ = crate::path::reflect_(vct_reflect_path);
+            let default_expr = meta.default_expr();
+            quote! {
+                #type_traits_::insert::<#type_trait_default_>(
+                    &mut type_traits,
+                    #type_trait_default_::new(|| #alloc_utils_::Box::new(#default_expr) as #alloc_utils_::Box<dyn #reflect_>),
+                );
+            }
+        } else {
+            quote! {
+                #type_traits_::insert::<#type_trait_default_>(&mut type_traits, #from_type_::<Self>::from_type());
+            }
         }
     } else {
         crate::utils::empty()
@@ -42,6 +54,12 @@ pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_t
         crate::utils::empty()
     };
 
+    let insert_type_traits = meta.attrs().type_traits.iter().map(|type_trait| {
+        quote! {
+            #type_traits_::insert::<#type_trait>(&mut type_traits, #from_type_::<Self>::from_type());
+        }
+    });
+
     let parser = meta.type_path_parser();
     let real_ident = parser.real_ident();
     let (impl_generics, ty_generics, where_clause) = parser.generics().split_for_impl();
@@ -55,6 +73,7 @@ pub(crate) fn impl_trait_get_type_traits<'a>(meta: &ReflectMeta, register_deps_t
                 #insert_default
                 #insert_serialize
                 #insert_deserialize
+                #(#insert_type_traits)*
                 type_traits
             }
 
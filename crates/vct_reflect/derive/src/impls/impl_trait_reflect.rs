@@ -10,6 +10,7 @@ pub(crate) fn impl_trait_reflect(
     to_dynamic_tokens: TokenStream,
     reflect_clone_tokens: TokenStream,
     reflect_partial_eq_tokens: TokenStream,
+    reflect_partial_cmp_tokens: TokenStream,
     reflect_hash_tokens: TokenStream,
     reflect_debug_tokens: TokenStream,
 ) -> TokenStream {
@@ -79,6 +80,8 @@ pub(crate) fn impl_trait_reflect(
 
             #reflect_partial_eq_tokens
 
+            #reflect_partial_cmp_tokens
+
             #reflect_hash_tokens
 
             #reflect_debug_tokens
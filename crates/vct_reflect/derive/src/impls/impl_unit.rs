@@ -23,6 +23,7 @@ pub(crate) fn impl_unit(meta: &ReflectMeta) -> proc_macro2::TokenStream {
         let to_dynamic_tokens = get_unit_to_dynamic_impl(meta);
         let reflect_clone_tokens = get_unit_clone_impl(meta);
         let reflect_partial_eq_tokens = get_unit_partial_eq_impl(meta);
+        let reflect_partial_cmp_tokens = get_unit_partial_cmp_impl(meta);
         let reflect_hash_tokens = get_unit_hash_impl(meta);
         let reflect_debug_tokens = get_unit_debug_impl(meta);
 
@@ -33,6 +34,7 @@ pub(crate) fn impl_unit(meta: &ReflectMeta) -> proc_macro2::TokenStream {
             to_dynamic_tokens,
             reflect_clone_tokens,
             reflect_partial_eq_tokens,
+            reflect_partial_cmp_tokens,
             reflect_hash_tokens,
             reflect_debug_tokens,
         )
@@ -68,7 +70,7 @@ pub(crate) fn impl_unit(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_unit_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::{ResultFP, OptionFP, CloneFP};
+    use crate::path::fp::{ResultFP, OptionFP};
 
     let vct_reflect_path = meta.vct_reflect_path();
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
@@ -78,10 +80,11 @@ fn get_unit_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let dynamic_type_path_ = crate::path::dynamic_type_path_(vct_reflect_path);
 
     if meta.attrs().avail_traits.clone {
+        let clone_value = meta.clone_expr(quote!(value));
         quote! {
             fn try_apply(&mut self, value: &dyn #reflect_) -> #ResultFP<(), #apply_error_> {
                 if let #OptionFP::Some(value) = <dyn #reflect_>::downcast_ref::<Self>(value) {
-                    *self = #CloneFP::clone(value);
+                    *self = #clone_value;
                     return #ResultFP::Ok(());
                 }
                 #ResultFP::Err(
@@ -112,17 +115,16 @@ fn get_unit_try_apply_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_unit_to_dynamic_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::CloneFP;
-
     let vct_reflect_path = meta.vct_reflect_path();
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
     let reflect_ = crate::path::reflect_(vct_reflect_path);
 
     if meta.attrs().avail_traits.clone {
+        let clone_self = meta.clone_expr(quote!(self));
         quote! {
             #[inline]
             fn to_dynamic(&self) -> #alloc_utils_::Box<dyn #reflect_> {
-                #alloc_utils_::Box::new(<Self as #CloneFP>::clone(self))
+                #alloc_utils_::Box::new(#clone_self)
             }
         }
     } else {
@@ -136,7 +138,7 @@ fn get_unit_to_dynamic_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_unit_clone_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::{ResultFP, CloneFP};
+    use crate::path::fp::ResultFP;
 
     let vct_reflect_path = meta.vct_reflect_path();
     let alloc_utils_ = crate::path::alloc_utils_(vct_reflect_path);
@@ -145,10 +147,11 @@ fn get_unit_clone_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
 
     if meta.attrs().avail_traits.clone {
+        let clone_self = meta.clone_expr(quote!(self));
         quote! {
             #[inline]
             fn reflect_clone(&self) -> #ResultFP<#alloc_utils_::Box<dyn #reflect_>, #reflect_clone_error_> {
-                #ResultFP::Ok(#alloc_utils_::Box::new(<Self as #CloneFP>::clone(self)) as #alloc_utils_::Box<dyn #reflect_>)
+                #ResultFP::Ok(#alloc_utils_::Box::new(#clone_self) as #alloc_utils_::Box<dyn #reflect_>)
             }
         }
     } else {
@@ -162,17 +165,18 @@ fn get_unit_clone_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_unit_partial_eq_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
-    use crate::path::fp::{OptionFP, PartialEqFP};
+    use crate::path::fp::OptionFP;
     let vct_reflect_path = meta.vct_reflect_path();
     let reflect_ = crate::path::reflect_(vct_reflect_path);
 
 
     if meta.attrs().avail_traits.partial_eq {
+        let eq = meta.partial_eq_expr(quote!(self), quote!(value));
         quote! {
             #[inline]
             fn reflect_partial_eq(&self, other: &dyn #reflect_) -> #OptionFP<bool> {
                 if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
-                    return #OptionFP::Some( #PartialEqFP::eq(self, value) );
+                    return #OptionFP::Some( #eq );
                 }
                 #OptionFP::None
             }
@@ -182,7 +186,37 @@ fn get_unit_partial_eq_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
             #[inline]
             fn reflect_partial_eq(&self, other: &dyn #reflect_) -> #OptionFP<bool> {
                 if other.is::<Self>() {
-                    #OptionFP::Some( true );
+                    #OptionFP::Some( true )
+                } else {
+                    #OptionFP::None
+                }
+            }
+        }
+    }
+}
+
+fn get_unit_partial_cmp_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream  {
+    use crate::path::fp::{OptionFP, OrderingFP};
+    let vct_reflect_path = meta.vct_reflect_path();
+    let reflect_ = crate::path::reflect_(vct_reflect_path);
+
+    if meta.attrs().avail_traits.partial_cmp {
+        let cmp = meta.partial_cmp_expr(quote!(self), quote!(value));
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                if let #OptionFP::Some(value) = other.downcast_ref::<Self>() {
+                    return #cmp;
+                }
+                #OptionFP::None
+            }
+        }
+    } else {
+        quote! {
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn #reflect_) -> #OptionFP<#OrderingFP> {
+                if other.is::<Self>() {
+                    #OptionFP::Some( #OrderingFP::Equal )
                 } else {
                     #OptionFP::None
                 }
@@ -198,11 +232,12 @@ fn get_unit_hash_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let reflect_hasher = crate::path::reflect_hasher_(vct_reflect_path);
 
     if meta.attrs().avail_traits.hash {
+        let hash = meta.hash_expr(quote!(self), quote!(&mut hasher));
         quote! {
             #[inline]
             fn reflect_hash(&self) -> #OptionFP<u64> {
                 let mut hasher = #reflect_hasher();
-                <Self as #HashFP>::hash(self, &mut hasher);
+                #hash;
                 #OptionFP::Some(#HasherFP::finish(&hasher))
             }
         }
@@ -219,15 +254,14 @@ fn get_unit_hash_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 }
 
 fn get_unit_debug_impl(meta: &ReflectMeta) -> proc_macro2::TokenStream {
-    use crate::path::fp::DebugFP;
-
     let type_path_ = crate::path::type_path_(meta.vct_reflect_path());
 
     if meta.attrs().avail_traits.debug {
+        let debug = meta.debug_expr(quote!(self), quote!(f));
         quote! {
             #[inline]
             fn reflect_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                <Self as #DebugFP>::fmt(self, f)
+                #debug
             }
         }
     } else {
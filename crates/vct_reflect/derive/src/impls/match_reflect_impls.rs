@@ -11,6 +11,11 @@ pub(crate) fn match_reflect_impls(ast: DeriveInput, source: ImplSourceKind) -> T
 
     let real_ident = reflect_derive.meta().type_path_parser().real_ident();
 
+    let alias_impls = match reflect_derive.meta().attrs().alias {
+        Some(_) => crate::impls::impl_alias(reflect_derive.meta()),
+        None => crate::utils::empty(),
+    };
+
     let assert_ident = quote! {
         mod __reflect_assert_ident {
             use super::*;
@@ -31,6 +36,8 @@ pub(crate) fn match_reflect_impls(ast: DeriveInput, source: ImplSourceKind) -> T
             #assert_ident
 
             #reflect_impls
+
+            #alias_impls
         };
     };
 
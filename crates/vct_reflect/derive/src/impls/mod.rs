@@ -28,5 +28,8 @@ pub(crate) use impl_opaque::impl_opaque;
 mod impl_unit;
 pub(crate) use impl_unit::impl_unit;
 
+mod impl_alias;
+pub(crate) use impl_alias::impl_alias;
+
 mod match_reflect_impls;
 pub(crate) use match_reflect_impls::match_reflect_impls;
@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use syn::{DeriveInput, parse_macro_input};
+use syn::{DeriveInput, ItemTrait, parse_macro_input, spanned::Spanned};
 
 pub(crate) static REFLECT_ATTRIBUTE_NAME: &str = "reflect";
 
@@ -8,6 +8,7 @@ mod path;
 mod utils;
 mod derive_data;
 mod impls;
+mod reflect_trait;
 
 
 /// Generate all reflection related traits' impl
@@ -81,6 +82,14 @@ mod impls;
 ///     - By default, `reflect_debug` will write `Reflect(#type_path)` for `Opaque`, or `reflect_debug` internal fields for other.
 ///     - If it's `Debug`, the type is required to impl `Debug` trait, then will call it directly(usually more efficient).
 ///     - `reflect_debug` does not have `Internal` impl because it is default.
+/// - `#[reflect(partial_ord = PartialOrd/Internal)]` : Control the impl of `Reflect::reflect_partial_cmp`
+///     - By default, `reflect_partial_cmp` will return `None` for every type.
+///     - If it's `PartialOrd`, the type is required to impl `PartialOrd` trait, then will call it directly.
+///       `#[reflect(partial_cmp)]` is a shorthand of it.
+///     - If it's `Internal`, `reflect_partial_cmp` will return `None` for `Opaque`,
+///       or compare internal fields in order for other type, like `#[derive(PartialOrd)]`
+///       (enums compare the variant index first, see `vct_reflect::ops::enum_partial_cmp`).
+/// - `#[reflect(default)]` : Register `TypeTraitDefault`, the type is required to impl `Default` trait.
 /// 
 /// For example:
 /// 
@@ -93,8 +102,47 @@ mod impls;
 /// If the auto-impl of the certain trait is turned off, the corresponding control options will not work and there will be no negative effects.
 /// For exmple, `#[reflect(type_path = "x")]` will not work if `#[reflect(TypePath = false)]`, but it won't cause compile-errors either.
 /// 
-/// Using custom functions to impl `reflect_xxx` (like `#[reflect(debug = my_debug_func)]`) is interesting, 
-/// but it doesn't seem that important and is not currently provided.
+/// ## Custom Functions
+/// 
+/// `clone`, `default`, `hash`, `partial_eq`, `partial_cmp` and `debug` can also be given a path to a function,
+/// which will be used instead of the trait, so the type does not need to impl it:
+/// 
+/// - `#[reflect(clone = f)]` : `fn(&T) -> T`
+/// - `#[reflect(default = f)]` : `fn() -> T`
+/// - `#[reflect(hash = f)]` : `fn(&T, &mut impl Hasher)`
+/// - `#[reflect(partial_eq = f)]` : `fn(&T, &T) -> bool`
+/// - `#[reflect(partial_cmp = f)]` : `fn(&T, &T) -> Option<Ordering>`
+/// - `#[reflect(debug = f)]` : `fn(&T, &mut Formatter<'_>) -> fmt::Result`
+/// 
+/// They can be used on fields too, then `T` is the field type.
+/// If the type-level option is not set, the related `reflect_xxx` of the struct will be implemented field by field,
+/// the custom function is used for this field, and the reflection of other fields is used for the rest.
+/// 
+/// For example:
+/// 
+/// ```ignore
+/// fn hash_ignoring_case(name: &String, hasher: &mut impl Hasher) {
+///     name.to_lowercase().hash(hasher);
+/// }
+/// 
+/// #[derive(Reflect)]
+/// struct A {
+///     #[reflect(hash = hash_ignoring_case, partial_eq = eq_ignoring_case)]
+///     name: String,
+///     #[reflect(default = default_count)]
+///     count: u32,
+/// }
+/// ```
+/// 
+/// - Other structs (such as `DynamicStruct`) are compared by field name, the custom function of a field is used
+///   if the field of the other value can be downcast to the field type, otherwise the field is compared through reflection.
+/// - `hash` only applies to `Self`, the hash of a `DynamicStruct` made from it does not use the custom functions.
+/// - `partial_cmp` compares the fields in order, like `#[derive(PartialOrd)]`, see `vct_reflect::ops::struct_partial_cmp`.
+/// - The field-level `default` is used by `FromReflect` and deserialization when the field is missing (or ignored),
+///   `#[reflect(default)]` without a path uses `Default::default`. Either form makes the field optional
+///   in reflected deserialization, see the `rename`/`alias`/`default` options below.
+/// - The field-level `clone` is also used for ignored fields, so `reflect_clone` can still succeed.
+/// - Enum variant fields only support the field-level `clone` and `default`.
 /// 
 /// ## Custom Attributes
 /// 
@@ -133,6 +181,13 @@ mod impls;
 ///     - Skip this field only during reflected serialization and deserialization.
 ///     - The fields using this macro must impl `Default` trait (for deserialization).
 ///     - Not compatible with `#[reflect(ignore)]`, because it's completely ignored during reflection.
+/// - `#[reflect(rename = "...")]` : The name used in reflected serialization and deserialization.
+/// - `#[reflect(alias = "...")]` : Another name accepted by reflected deserialization, can be used multiple times.
+/// - `#[reflect(default)]` : The value used when the field is missing in reflected deserialization,
+///   otherwise a missing field is an error.
+///     - It's the same option as the field-level custom function `#[reflect(default = f)]`,
+///       so a field with a custom default is always optional in reflected deserialization,
+///       and `FromReflect` fills in the same value.
 /// 
 /// For Example:
 /// 
@@ -143,10 +198,15 @@ mod impls;
 ///     _marker: PhantomData,
 ///     #[reflect(skip_serde)]
 ///     note: &'static str,
+///     #[reflect(rename = "value", alias = "content", default)]
 ///     content: u64,
 /// }
 /// ```
 /// 
+/// Unknown fields are ignored by reflected deserialization,
+/// use the type-level `#[reflect(deny_unknown_fields)]` to reject them
+/// (named structs, and the struct variants of enums).
+/// 
 /// ### Alias Control
 /// 
 /// Rust requires that when implementing a trait, either the type or the trait must be defined in the current crate.
@@ -162,12 +222,21 @@ mod impls;
 ///     content: u64,
 /// }
 /// 
-/// #[derive(Reflect)]
-/// #[reflect(alias = B)]
+/// #[derive(Reflect, Clone)]
+/// #[reflect(alias = foo::B, clone)]
 /// #[repr(transparent)] // ensures the same memory layout
-/// struct A(B);
+/// struct A(foo::B);
 /// ```
-/// 
+///
+/// - The wrapper must be a non-generic tuple struct with a single field, and `#[repr(transparent)]` is required.
+/// - The macro cannot see the definition of the foreign type, so only its path is reflected:
+///   the wrapper is reflected as `Opaque` and its `TypeInfo` is an `OpaqueInfo`,
+///   the fields of the foreign type are not visible, and the `Opaque` rules (such as `#[reflect(clone)]`) apply.
+/// - The path of the alias is used as the type path, use `#[reflect(type_path = "...")]` if it is not a full path,
+///   or if it is relative (starts with `crate`, `self` or `super`).
+/// - `ReflectAlias` is implemented, it converts between the two types (including `FromReflect` and `set`),
+///   and a compile-time assertion checks that the size and alignment are the same.
+///
 /// 
 /// ### Docs Control
 /// 
@@ -196,6 +265,65 @@ mod impls;
 /// 
 /// Note: use `#[reflect(docs = "")]` to close the docs of a certain type.
 /// 
+/// ### Type Traits
+/// 
+/// `FromPtr`, `FromReflect` and the enabled `Default`/`Serialize`/`Deserialize` type traits are always inserted in `get_type_traits`.
+/// 
+/// Others can be listed with `#[reflect(type_trait(...))]`, they must impl `FromType<Self>`.
+/// It can be used multiple times, and is equivalent to calling `TypeRegistry::register_type_trait` for each of them after registration.
+/// 
+/// For Example:
+/// 
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(type_trait(ReflectComponent, ReflectInspector))]
+/// struct A {
+///     /* ... */
+/// }
+/// ```
+/// 
+/// ### Enum Tagging
+/// 
+/// By default, enums are externally tagged in reflected serialization: `{"Circle": {"radius": 2}}`.
+/// Other representations are the same as serde-derive:
+/// 
+/// - `#[reflect(tag = "type")]` : Internally tagged, `{"type": "Circle", "radius": 2}`.
+///     - Tuple variants must have only one field (otherwise it's a compile error), and the field must be a struct.
+/// - `#[reflect(tag = "t", content = "c")]` : Adjacently tagged, `{"t": "Circle", "c": {"radius": 2}}`.
+/// - `#[reflect(untagged)]` : Untagged, `{"radius": 2}`, the first variant that matches the data is used.
+/// 
+/// They require a self-describing format (such as JSON) for deserialization, and are ignored by the compact format.
+/// 
+/// For Example:
+/// 
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(tag = "type")]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Rect { w: f32, h: f32 },
+/// }
+/// ```
+/// 
+/// ### Version Control
+/// 
+/// Structs, tuple structs and enums can set a schema version with `#[reflect(version = N)]`, the default is `0`.
+/// 
+/// `ReflectSerializer` writes the version alongside the type path (omitted when it's `0`),
+/// nested values of versioned types are written as `{ "version": N, "value": ... }`.
+/// When older data is deserialized, the registered `TypeTraitMigrate` can transform the
+/// dynamic value into the current shape before `FromReflect` runs.
+/// 
+/// For Example:
+/// 
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(version = 2)]
+/// struct A {
+///     health: u32,
+/// }
+/// ```
+/// 
 /// ### Auto Register
 /// 
 /// Should enable `auto_register` feature.
@@ -255,6 +383,51 @@ pub fn impl_full_reflect(input: TokenStream) -> TokenStream {
     impls::match_reflect_impls(ast, ImplSourceKind::ImplForeignType)
 }
 
+/// Generate a type trait to cast `dyn Reflect` into the trait object of a user trait.
+/// 
+/// For a trait `MyTrait`, `TypeTraitMyTrait` will be generated (with the visibility of the trait):
+/// 
+/// - `get(&dyn Reflect) -> Option<&dyn MyTrait>`
+/// - `get_mut(&mut dyn Reflect) -> Option<&mut dyn MyTrait>`
+/// - `get_boxed(Box<dyn Reflect>) -> Result<Box<dyn MyTrait>, Box<dyn Reflect>>`
+/// 
+/// `FromType<T>` is implemented for every `T: MyTrait + Reflect + Typed`,
+/// so it can be registered with `#[reflect(type_trait(TypeTraitMyTrait))]` or `TypeRegistry::register_type_trait`.
+/// 
+/// The trait must be dyn compatible and can not be generic.
+/// 
+/// For example:
+/// 
+/// ```ignore
+/// #[reflect_trait]
+/// trait Damageable {
+///     fn damage(&mut self, amount: u32);
+/// }
+/// 
+/// #[derive(Reflect)]
+/// #[reflect(type_trait(TypeTraitDamageable))]
+/// struct Player { hp: u32 }
+/// 
+/// impl Damageable for Player { /* ... */ }
+/// 
+/// let type_trait = registry.get_type_trait::<TypeTraitDamageable>(value.type_id()).unwrap();
+/// type_trait.get_mut(value).unwrap().damage(10);
+/// ```
+#[proc_macro_attribute]
+pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return syn::Error::new(
+            proc_macro2::TokenStream::from(args).span(),
+            "`#[reflect_trait]` does not take arguments.",
+        ).into_compile_error().into();
+    }
+    let item_trait = parse_macro_input!(input as ItemTrait);
+    match reflect_trait::impl_reflect_trait(item_trait) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
 /// How the macro was invoked.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum ImplSourceKind {
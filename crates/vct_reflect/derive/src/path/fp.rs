@@ -6,8 +6,8 @@ pub(crate) struct AnyFP;
 /// Full Path (FP) for [`Clone`]
 pub(crate) struct CloneFP;
 /// Full Path (FP) for [`Default`]
-// pub(crate) struct DefaultFP;
-// /// Full Path (FP) for [`Option`]
+pub(crate) struct DefaultFP;
+/// Full Path (FP) for [`Option`]
 pub(crate) struct OptionFP;
 /// Full Path (FP) for [`Result`]
 pub(crate) struct ResultFP;
@@ -17,6 +17,10 @@ pub(crate) struct ResultFP;
 // pub(crate) struct SyncFP;
 // /// Full Path (FP) for [`PartialEq`]
 pub(crate) struct PartialEqFP;
+/// Full Path (FP) for [`PartialOrd`]
+pub(crate) struct PartialOrdFP;
+/// Full Path (FP) for [`Ordering`](core::cmp::Ordering)
+pub(crate) struct OrderingFP;
 /// Full Path (FP) for [`Hash`](core::hash::Hash)
 pub(crate) struct HashFP;
 /// Full Path (FP) for [`Hasher`](core::hash::Hasher)
@@ -38,11 +42,11 @@ impl ToTokens for CloneFP {
     }
 }
 
-// impl ToTokens for DefaultFP {
-//     fn to_tokens(&self, tokens: &mut TokenStream) {
-//         quote!(::core::default::Default).to_tokens(tokens);
-//     }
-// }
+impl ToTokens for DefaultFP {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        quote!(::core::default::Default).to_tokens(tokens);
+    }
+}
 
 impl ToTokens for OptionFP {
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -74,6 +78,18 @@ impl ToTokens for PartialEqFP {
     }
 }
 
+impl ToTokens for PartialOrdFP {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        quote!(::core::cmp::PartialOrd).to_tokens(tokens);
+    }
+}
+
+impl ToTokens for OrderingFP {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        quote!(::core::cmp::Ordering).to_tokens(tokens);
+    }
+}
+
 impl ToTokens for HashFP {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         quote!(::core::hash::Hash).to_tokens(tokens);
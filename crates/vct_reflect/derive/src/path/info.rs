@@ -183,6 +183,12 @@ pub(crate) fn enum_info_(vct_reflect_path: &syn::Path) -> TokenStream {
     }
 }
 
+pub(crate) fn enum_tagging_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::info::EnumTagging
+    }
+}
+
 #[inline(always)]
 pub(crate) fn reflect_kind_(vct_reflect_path: &syn::Path) -> TokenStream {
     quote! {
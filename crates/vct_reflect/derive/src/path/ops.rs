@@ -343,3 +343,10 @@ pub(crate) fn enum_partial_eq_(vct_reflect_path: &syn::Path) -> TokenStream {
         #vct_reflect_path::ops::enum_partial_eq
     }
 }
+
+#[inline]
+pub(crate) fn enum_partial_cmp_(vct_reflect_path: &syn::Path) -> TokenStream {
+    quote! {
+        #vct_reflect_path::ops::enum_partial_cmp
+    }
+}
@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ItemTrait, spanned::Spanned};
+
+/// Generate `TypeTrait{Trait}` for the trait, see [`crate::reflect_trait`].
+pub(crate) fn impl_reflect_trait(item_trait: ItemTrait) -> syn::Result<TokenStream> {
+    if !item_trait.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item_trait.generics.span(),
+            "`#[reflect_trait]` does not support generic traits.",
+        ));
+    }
+
+    let vct_reflect_path = crate::path::vct_reflect();
+    let reflect_ = crate::path::reflect_(&vct_reflect_path);
+    let typed_ = crate::path::typed_(&vct_reflect_path);
+    let from_type_ = crate::path::from_type_(&vct_reflect_path);
+    let alloc_utils_ = crate::path::alloc_utils_(&vct_reflect_path);
+    use crate::path::fp::{OptionFP, ResultFP};
+
+    let vis = &item_trait.vis;
+    let trait_ident = &item_trait.ident;
+    let type_trait_ident = format_ident!("TypeTrait{}", trait_ident);
+
+    let struct_doc = format!(
+        " A type trait used to cast `dyn Reflect` into `dyn {trait_ident}`, generated by `#[reflect_trait]`."
+    );
+    let get_doc = format!(" Downcasts the value to `&dyn {trait_ident}`, returns `None` if the type does not match.");
+    let get_mut_doc = format!(" Downcasts the value to `&mut dyn {trait_ident}`, returns `None` if the type does not match.");
+    let get_boxed_doc = format!(" Downcasts the value to `Box<dyn {trait_ident}>`, returns `Err` if the type does not match.");
+
+    Ok(quote! {
+        #item_trait
+
+        #[doc = #struct_doc]
+        #[derive(Clone)]
+        #vis struct #type_trait_ident {
+            get_func: fn(&dyn #reflect_) -> #OptionFP<&dyn #trait_ident>,
+            get_mut_func: fn(&mut dyn #reflect_) -> #OptionFP<&mut dyn #trait_ident>,
+            get_boxed_func: fn(#alloc_utils_::Box<dyn #reflect_>) -> #ResultFP<#alloc_utils_::Box<dyn #trait_ident>, #alloc_utils_::Box<dyn #reflect_>>,
+        }
+
+        impl #type_trait_ident {
+            #[doc = #get_doc]
+            #[inline]
+            pub fn get<'a>(&self, reflect: &'a dyn #reflect_) -> #OptionFP<&'a dyn #trait_ident> {
+                (self.get_func)(reflect)
+            }
+
+            #[doc = #get_mut_doc]
+            #[inline]
+            pub fn get_mut<'a>(&self, reflect: &'a mut dyn #reflect_) -> #OptionFP<&'a mut dyn #trait_ident> {
+                (self.get_mut_func)(reflect)
+            }
+
+            #[doc = #get_boxed_doc]
+            #[inline]
+            pub fn get_boxed(
+                &self,
+                reflect: #alloc_utils_::Box<dyn #reflect_>,
+            ) -> #ResultFP<#alloc_utils_::Box<dyn #trait_ident>, #alloc_utils_::Box<dyn #reflect_>> {
+                (self.get_boxed_func)(reflect)
+            }
+        }
+
+        impl<T: #trait_ident + #reflect_ + #typed_> #from_type_<T> for #type_trait_ident {
+            fn from_type() -> Self {
+                Self {
+                    get_func: |reflect| {
+                        <dyn #reflect_>::downcast_ref::<T>(reflect).map(|value| value as &dyn #trait_ident)
+                    },
+                    get_mut_func: |reflect| {
+                        <dyn #reflect_>::downcast_mut::<T>(reflect).map(|value| value as &mut dyn #trait_ident)
+                    },
+                    get_boxed_func: |reflect| {
+                        <dyn #reflect_>::downcast::<T>(reflect).map(|value| value as #alloc_utils_::Box<dyn #trait_ident>)
+                    },
+                }
+            }
+        }
+    })
+}
@@ -2,6 +2,8 @@
 //!
 //! Users should not use any content here.
 
+use core::fmt;
+
 use crate::{Reflect, info::{FieldId, TypePath}, ops::ReflectCloneError};
 
 pub mod alloc_utils {
@@ -44,6 +46,17 @@ pub fn reflect_clone_field<T: Reflect + TypePath>(source: &T, id: FieldId) -> Re
     }
 }
 
+/// Implements [`Debug`](fmt::Debug) with a closure,
+/// used for fields with `#[reflect(debug = path)]`.
+pub struct DebugFn<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(pub F);
+
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> fmt::Debug for DebugFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
 // pub trait RegisterForReflection {
 //     fn __register(_registry: &mut TypeRegistry) {}
 // }
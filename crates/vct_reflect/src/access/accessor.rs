@@ -1,16 +1,17 @@
-use alloc::borrow::Cow;
-use core::fmt;
+use alloc::{borrow::Cow, boxed::Box, format, string::String};
+use core::fmt::{self, Write};
 
 use crate::{
     Reflect,
-    info::{ReflectKind, VariantKind},
+    info::{MapInfo, ReflectKind, SetInfo, TypeInfo, VariantKind},
     ops::{ReflectMut, ReflectRef},
+    serde::text,
 };
 
 /// A **singular** element access within a path.
 ///
-/// Supported for [`Struct`], [`TupleStruct`], [`Tuple`], [`Array`], [`List`], [`Enum`].
-/// [`Map`], [`Set`], and `Opaque` are not supported.
+/// Supported for [`Struct`], [`TupleStruct`], [`Tuple`], [`Array`], [`List`], [`Enum`],
+/// [`Map`] and [`Set`] (shared access only). `Opaque` is not supported.
 ///
 /// [`Struct`]: crate::ops::Struct
 /// [`TupleStruct`]: crate::ops::TupleStruct
@@ -20,7 +21,7 @@ use crate::{
 /// [`Map`]: crate::ops::Map
 /// [`Set`]: crate::ops::Set
 /// [`Enum`]: crate::ops::Enum
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug)]
 pub enum Accessor<'a> {
     /// A name-based field access on a struct or enum struct.
     ///
@@ -40,6 +41,37 @@ pub enum Accessor<'a> {
     ///
     /// Example: the `5` of `"#5"` (default impl)
     FieldIndex(usize),
+    /// A key-based access on a map, or a value lookup on a set, with a reflected key.
+    ///
+    /// The key is compared with [`Reflect::reflect_partial_eq`] and [`Reflect::reflect_hash`],
+    /// so it should be a hashable type, such as primitives and [`String`].
+    /// It's borrowed by [`walk`](crate::visit::walk), so cloning the accessor (or
+    /// [`into_owned`](Accessor::into_owned)) clones the key, see [`ToOwned`] of `dyn Reflect`.
+    MapKey(Cow<'a, dyn Reflect>),
+    /// A key-based access on a map, or a value lookup on a set, with the key written
+    /// in the [text format](crate::serde::text).
+    ///
+    /// The literal is parsed as the key type of the accessed map (or the value type of the set),
+    /// so the map must represent a type.
+    ///
+    /// Example: the `"jump"` of `["jump"]`, the `42u32` of `[42u32]`
+    /// or the `jump` of `{jump}` (default impl)
+    KeyLiteral(Cow<'a, str>),
+    /// Asserts the active variant of an enum, and accesses the enum itself.
+    ///
+    /// Example: the `Running` of `::Running` (default impl)
+    Variant(Cow<'a, str>),
+    /// Unwraps an `Option`-like enum: `Some(value)` accesses `value`,
+    /// `None` fails with [`AccessErrorKind::NoneValue`], and other values are accessed as is.
+    ///
+    /// The next accessor in the path becomes optional, so when it fails on a wrong variant
+    /// or a missing field, optional path access (e.g. [`ReflectPathAccess::access_opt`])
+    /// returns `None` instead of an error.
+    ///
+    /// Example: the `?` of `.state?::Running.speed` (default impl)
+    ///
+    /// [`ReflectPathAccess::access_opt`]: crate::access::ReflectPathAccess::access_opt
+    Optional,
 }
 
 /// The kind of [`AccessError`], along with some kind-specific information.
@@ -54,12 +86,39 @@ pub enum AccessErrorKind {
         expected: VariantKind,
         actual: VariantKind,
     },
+    /// The active variant is not the one required by [`Accessor::Variant`].
+    VariantMismatch {
+        actual: String,
+    },
+    /// An [`Accessor::Optional`] was applied to a `None` value.
+    NoneValue,
+    /// The literal of an [`Accessor::KeyLiteral`] can't be parsed as the key type.
+    InvalidKey {
+        reason: String,
+    },
+}
+
+impl AccessErrorKind {
+    /// Returns `true` if the error depends on the current variant or content of the value,
+    /// rather than on its type.
+    ///
+    /// Such errors end an optional chain with `None`, see [`Accessor::Optional`].
+    #[inline]
+    pub fn is_variant_dependent(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingField(_)
+                | Self::IncompatibleVariantKinds { .. }
+                | Self::VariantMismatch { .. }
+                | Self::NoneValue
+        )
+    }
 }
 
 /// An error originating from an [`Access`] of an element within a type.
 ///
 /// Use the `Display` impl of this type to get information on the error.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccessError<'a> {
     kind: AccessErrorKind,
     accessor: Accessor<'a>,
@@ -73,6 +132,64 @@ impl fmt::Display for Accessor<'_> {
             Accessor::FieldIndex(index) => write!(f, "#{index}"),
             Accessor::TupleIndex(index) => write!(f, ".{index}"),
             Accessor::ListIndex(index) => write!(f, "[{index}]"),
+            Accessor::MapKey(key) => write!(f, "[{}]", KeyDisplay(key.as_ref())),
+            Accessor::KeyLiteral(literal) => write!(f, "[{literal}]"),
+            Accessor::Variant(name) => write!(f, "::{name}"),
+            Accessor::Optional => f.write_char('?'),
+        }
+    }
+}
+
+/// Displays a map key as the literal accepted by the default path parser.
+struct KeyDisplay<'a>(&'a dyn Reflect);
+
+impl fmt::Display for KeyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        macro_rules! suffixed {
+            ($($ty:ident),* $(,)?) => {
+                $(
+                    if let Some(value) = self.0.downcast_ref::<$ty>() {
+                        return write!(f, "{value}{}", stringify!($ty));
+                    }
+                )*
+            };
+        }
+
+        suffixed!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+        fmt::Debug::fmt(self.0, f)
+    }
+}
+
+impl Clone for Accessor<'_> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::FieldName(value) => Self::FieldName(value.clone()),
+            Self::FieldIndex(value) => Self::FieldIndex(*value),
+            Self::TupleIndex(value) => Self::TupleIndex(*value),
+            Self::ListIndex(value) => Self::ListIndex(*value),
+            Self::MapKey(key) => Self::MapKey(key.clone()),
+            Self::KeyLiteral(literal) => Self::KeyLiteral(literal.clone()),
+            Self::Variant(name) => Self::Variant(name.clone()),
+            Self::Optional => Self::Optional,
+        }
+    }
+}
+
+/// Reflected keys of [`Accessor::MapKey`] are compared with [`Reflect::reflect_partial_eq`],
+/// so accessors are only [`PartialEq`].
+impl PartialEq for Accessor<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::FieldName(a), Self::FieldName(b)) | (Self::Variant(a), Self::Variant(b)) => {
+                a == b
+            }
+            (Self::Optional, Self::Optional) => true,
+            (Self::FieldIndex(a), Self::FieldIndex(b))
+            | (Self::TupleIndex(a), Self::TupleIndex(b))
+            | (Self::ListIndex(a), Self::ListIndex(b)) => a == b,
+            (Self::MapKey(a), Self::MapKey(b)) => a.reflect_partial_eq(b.as_ref()) == Some(true),
+            (Self::KeyLiteral(a), Self::KeyLiteral(b)) => a == b,
+            _ => false,
         }
     }
 }
@@ -98,6 +215,31 @@ macro_rules! match_variant {
     };
 }
 
+#[inline]
+fn map_key_info(info: Option<&'static TypeInfo>) -> Option<&'static TypeInfo> {
+    info.and_then(|info| info.as_map().ok()).map(MapInfo::key_info)
+}
+
+#[inline]
+fn set_value_info(info: Option<&'static TypeInfo>) -> Option<&'static TypeInfo> {
+    info.and_then(|info| info.as_set().ok()).map(SetInfo::value_info)
+}
+
+/// Parses the literal of an [`Accessor::KeyLiteral`] as the given key type.
+fn parse_key(
+    literal: &str,
+    key_info: Option<&'static TypeInfo>,
+) -> Result<Box<dyn Reflect>, AccessErrorKind> {
+    let Some(key_info) = key_info else {
+        return Err(AccessErrorKind::InvalidKey {
+            reason: "the accessed value does not represent a typed map or set".into(),
+        });
+    };
+    text::from_str_with_info(literal, key_info).map_err(|err| AccessErrorKind::InvalidKey {
+        reason: format!("expected `{}`, {err}", key_info.type_path()),
+    })
+}
+
 impl<'a> Accessor<'a> {
     /// Converts this into an "owned" value.
     #[inline]
@@ -107,23 +249,101 @@ impl<'a> Accessor<'a> {
             Self::FieldIndex(value) => Accessor::FieldIndex(value),
             Self::TupleIndex(value) => Accessor::TupleIndex(value),
             Self::ListIndex(value) => Accessor::ListIndex(value),
+            Self::MapKey(key) => Accessor::MapKey(Cow::Owned(key.into_owned())),
+            Self::KeyLiteral(literal) => Accessor::KeyLiteral(Cow::Owned(literal.into_owned())),
+            Self::Variant(name) => Accessor::Variant(Cow::Owned(name.into_owned())),
+            Self::Optional => Accessor::Optional,
         }
     }
 
-    /// Returns a reference to this [`Access`]'s inner value as a [`&dyn Display`](fmt::Display).
-    fn display_value(&self) -> &dyn fmt::Display {
+    /// Borrows the contents of this accessor, without cloning them.
+    pub(crate) fn reborrow(&self) -> Accessor<'_> {
         match self {
-            Self::FieldName(value) => value,
-            Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => value,
+            Self::FieldName(value) => Accessor::FieldName(Cow::Borrowed(value)),
+            Self::FieldIndex(value) => Accessor::FieldIndex(*value),
+            Self::TupleIndex(value) => Accessor::TupleIndex(*value),
+            Self::ListIndex(value) => Accessor::ListIndex(*value),
+            Self::MapKey(key) => Accessor::MapKey(Cow::Borrowed(key.as_ref())),
+            Self::KeyLiteral(literal) => Accessor::KeyLiteral(Cow::Borrowed(literal)),
+            Self::Variant(name) => Accessor::Variant(Cow::Borrowed(name)),
+            Self::Optional => Accessor::Optional,
         }
     }
 
+    /// Creates a [`Accessor::MapKey`] from a reflected key.
+    #[inline]
+    pub fn map_key(key: impl Reflect) -> Self {
+        Self::MapKey(Cow::Owned(Box::new(key) as Box<dyn Reflect>))
+    }
+
+    /// Creates a [`Accessor::MapKey`] of a [`String`] key.
+    #[inline]
+    pub fn string_key(key: impl Into<String>) -> Self {
+        Self::MapKey(Cow::Owned(Box::new(key.into()) as Box<dyn Reflect>))
+    }
+
+    /// Returns a reference to this [`Access`]'s inner value as a [`&dyn Display`](fmt::Display).
+    fn display_value(&self) -> impl fmt::Display + '_ {
+        fmt::from_fn(move |f| match self {
+            Self::FieldName(value) | Self::Variant(value) => fmt::Display::fmt(value, f),
+            Self::Optional => f.write_char('?'),
+            Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => {
+                fmt::Display::fmt(value, f)
+            }
+            Self::MapKey(key) => fmt::Display::fmt(&KeyDisplay(key.as_ref()), f),
+            Self::KeyLiteral(literal) => fmt::Display::fmt(literal, f),
+        })
+    }
+
     fn kind(&self) -> &'static str {
         match self {
             Self::FieldName(_) => "FieldName",
             Self::FieldIndex(_) => "FieldIndex",
             Self::TupleIndex(_) => "TupleIndex",
             Self::ListIndex(_) => "ListIndex",
+            Self::MapKey(_) => "MapKey",
+            Self::KeyLiteral(_) => "KeyLiteral",
+            Self::Variant(_) => "Variant",
+            Self::Optional => "Optional",
+        }
+    }
+
+    #[inline]
+    fn error(&self, kind: AccessErrorKind, offset: Option<usize>) -> AccessError<'a> {
+        AccessError {
+            kind,
+            accessor: self.clone(),
+            offset,
+        }
+    }
+
+    /// Checks the active variant for [`Accessor::Variant`].
+    fn check_variant(
+        &self,
+        base: &dyn Reflect,
+        name: &str,
+        offset: Option<usize>,
+    ) -> Result<(), AccessError<'a>> {
+        let kind = match base.reflect_ref() {
+            ReflectRef::Enum(enum_ref) if enum_ref.variant_name() == name => return Ok(()),
+            ReflectRef::Enum(enum_ref) => AccessErrorKind::VariantMismatch {
+                actual: enum_ref.variant_name().into(),
+            },
+            actual => invalid_kind!(ReflectKind::Enum, actual.kind()),
+        };
+        Err(self.error(kind, offset))
+    }
+
+    /// Returns `true` if [`Accessor::Optional`] should unwrap a `Some(value)`,
+    /// or `false` if the value should be accessed as is.
+    fn is_some(&self, base: &dyn Reflect, offset: Option<usize>) -> Result<bool, AccessError<'a>> {
+        let ReflectRef::Enum(enum_ref) = base.reflect_ref() else {
+            return Ok(false);
+        };
+        match (enum_ref.variant_name(), enum_ref.variant_kind()) {
+            ("None", VariantKind::Unit) => Err(self.error(AccessErrorKind::NoneValue, offset)),
+            ("Some", VariantKind::Tuple) => Ok(enum_ref.field_len() == 1),
+            _ => Ok(false),
         }
     }
 
@@ -135,6 +355,17 @@ impl<'a> Accessor<'a> {
     ) -> Result<&'r dyn Reflect, AccessError<'a>> {
         use ReflectRef::*;
 
+        match self {
+            Self::Variant(name) => return self.check_variant(base, name, offset).map(|()| base),
+            Self::Optional => {
+                return match (self.is_some(base, offset)?, base.reflect_ref()) {
+                    (true, Enum(enum_ref)) => Ok(enum_ref.field_at(0).expect("field should exist")),
+                    _ => Ok(base),
+                };
+            }
+            _ => {}
+        }
+
         let res: Result<Option<&'r dyn Reflect>, AccessErrorKind> = match (self, base.reflect_ref())
         {
             (Self::FieldName(field), Struct(struct_ref)) => Ok(struct_ref.field(field.as_ref())),
@@ -156,6 +387,20 @@ impl<'a> Accessor<'a> {
             (&Self::ListIndex(index), List(list)) => Ok(list.get(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get(index)),
             (Self::ListIndex(_), actual) => Err(invalid_kind!(ReflectKind::List, actual.kind())),
+            (Self::MapKey(key), Map(map)) => Ok(map.get(key.as_ref())),
+            (Self::MapKey(key), Set(set)) => Ok(set.get(key.as_ref())),
+            (Self::KeyLiteral(literal), Map(map)) => {
+                let key_info = map_key_info(map.represented_type_info());
+                parse_key(literal, key_info).map(|key| map.get(key.as_ref()))
+            }
+            (Self::KeyLiteral(literal), Set(set)) => {
+                let value_info = set_value_info(set.represented_type_info());
+                parse_key(literal, value_info).map(|key| set.get(key.as_ref()))
+            }
+            (Self::MapKey(_) | Self::KeyLiteral(_), actual) => {
+                Err(invalid_kind!(ReflectKind::Map, actual.kind()))
+            }
+            (Self::Variant(_) | Self::Optional, _) => unreachable!("handled above"),
         };
 
         res.and_then(|opt| opt.ok_or(AccessErrorKind::MissingField(base.reflect_kind())))
@@ -174,6 +419,20 @@ impl<'a> Accessor<'a> {
     ) -> Result<&'r mut dyn Reflect, AccessError<'a>> {
         use ReflectMut::*;
 
+        match self {
+            Self::Variant(name) => return self.check_variant(base, name, offset).map(|()| base),
+            Self::Optional => {
+                if !self.is_some(base, offset)? {
+                    return Ok(base);
+                }
+                return match base.reflect_mut() {
+                    Enum(enum_mut) => Ok(enum_mut.field_at_mut(0).expect("field should exist")),
+                    _ => unreachable!("only enums can be unwrapped"),
+                };
+            }
+            _ => {}
+        }
+
         let base_kind = base.reflect_kind();
 
         let res: Result<Option<&'r mut dyn Reflect>, AccessErrorKind> = match (
@@ -201,6 +460,16 @@ impl<'a> Accessor<'a> {
             (&Self::ListIndex(index), List(list)) => Ok(list.get_mut(index)),
             (&Self::ListIndex(index), Array(list)) => Ok(list.get_mut(index)),
             (Self::ListIndex(_), actual) => Err(invalid_kind!(ReflectKind::List, actual.kind())),
+            // Set values cannot be mutated in place, as it may change their hash.
+            (Self::MapKey(key), Map(map)) => Ok(map.get_mut(key.as_ref())),
+            (Self::KeyLiteral(literal), Map(map)) => {
+                let key_info = map_key_info(map.represented_type_info());
+                parse_key(literal, key_info).map(|key| map.get_mut(key.as_ref()))
+            }
+            (Self::MapKey(_) | Self::KeyLiteral(_), actual) => {
+                Err(invalid_kind!(ReflectKind::Map, actual.kind()))
+            }
+            (Self::Variant(_) | Self::Optional, _) => unreachable!("handled above"),
         };
 
         res.and_then(|opt| opt.ok_or(AccessErrorKind::MissingField(base_kind)))
@@ -264,6 +533,14 @@ impl<'a> fmt::Display for AccessError<'a> {
                     "The {type_accessed} accessed doesn't have index `{}`",
                     accessor.display_value()
                 ),
+                Accessor::MapKey(_) | Accessor::KeyLiteral(_) => write!(
+                    f,
+                    "The {type_accessed} accessed doesn't have key `{}`",
+                    accessor.display_value()
+                ),
+                Accessor::Variant(_) | Accessor::Optional => {
+                    write!(f, "The {type_accessed} accessed is missing")
+                }
             },
             AccessErrorKind::IncompatibleKinds { expected, actual } => write!(
                 f,
@@ -275,6 +552,17 @@ impl<'a> fmt::Display for AccessError<'a> {
                 "Expected variant {} accessor to access a {expected} variant, found a {actual} variant instead.",
                 accessor.kind()
             ),
+            AccessErrorKind::VariantMismatch { actual } => write!(
+                f,
+                "Expected variant `{}`, found variant `{actual}` instead.",
+                accessor.display_value()
+            ),
+            AccessErrorKind::NoneValue => f.write_str("Found a `None` value."),
+            AccessErrorKind::InvalidKey { reason } => write!(
+                f,
+                "Failed to parse key `{}`: {reason}",
+                accessor.display_value()
+            ),
         }
     }
 }
@@ -284,7 +572,7 @@ impl core::error::Error for AccessError<'_> {}
 /// An [`Access`] combined with an `offset` for more helpful error reporting.
 ///
 /// `offset` is only used to display error messages, unrelated to access.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OffsetAccessor<'a> {
     pub accessor: Accessor<'a>,
     /// only used to display error messages
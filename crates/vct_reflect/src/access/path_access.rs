@@ -1,13 +1,13 @@
 use crate::{
     Reflect,
-    access::{AccessError, AccessPath, OffsetAccessor, ParseError},
+    access::{AccessError, AccessErrorKind, AccessPath, Accessor, OffsetAccessor, ParseError},
     ops::{Array, Enum, List, Struct, Tuple, TupleStruct},
 };
 use alloc::vec::Vec;
-use core::fmt;
+use core::{cell::Cell, fmt};
 
 /// An error returned from a failed path access.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 pub enum PathAccessError<'a> {
     /// A path string that could not be parsed.
     /// See [`ParseError`] for details.
@@ -48,6 +48,38 @@ impl<'a> From<AccessError<'a>> for PathAccessError<'a> {
     }
 }
 
+/// Returns `true` if the failed access ends an optional chain with `None`.
+///
+/// `optional` is whether the previous accessor is an [`Accessor::Optional`].
+#[inline]
+fn ends_chain(optional: bool, err: &AccessError) -> bool {
+    match err.kind() {
+        AccessErrorKind::NoneValue => true,
+        kind => optional && kind.is_variant_dependent(),
+    }
+}
+
+/// Tracks whether the last accessor parsed from `path` follows an [`Accessor::Optional`].
+///
+/// Path access stops at the first failed accessor, so after a failure
+/// `optional` tells whether the failure ends an optional chain.
+struct OptionalChain<P> {
+    path: P,
+    optional: Cell<bool>,
+}
+
+impl<'a, P: AccessPath<'a>> AccessPath<'a> for &OptionalChain<P> {
+    fn parse_to_accessor(
+        &self,
+    ) -> impl Iterator<Item = Result<OffsetAccessor<'a>, ParseError<'a>>> {
+        let mut previous = false;
+        self.path.parse_to_accessor().inspect(move |res| {
+            self.optional.set(previous);
+            previous = matches!(res, Ok(accessor) if accessor.accessor == Accessor::Optional);
+        })
+    }
+}
+
 /// Reusable path accessor, a thin wrapper over [`Vec<OffsetAccessor>`].
 ///
 /// [`OffsetAccessor`] and [`Accessor`] only allow access to a single level,
@@ -57,7 +89,7 @@ impl<'a> From<AccessError<'a>> for PathAccessError<'a> {
 /// However, for non-static strings, it requires copying for storage.
 ///
 /// [`ReflectPathAccess`]: crate::access::ReflectPathAccess
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PathAccessor(Vec<OffsetAccessor<'static>>);
 
 impl From<Vec<OffsetAccessor<'static>>> for PathAccessor {
@@ -146,6 +178,48 @@ impl PathAccessor {
         Ok(it)
     }
 
+    /// Returns a reference to the value specified by `path`,
+    /// or `None` if an optional chain is short-circuited, see [`Accessor::Optional`].
+    ///
+    /// The accessor itself will not change and can be reused.
+    pub fn access_opt<'r>(
+        &self,
+        base: &'r dyn Reflect,
+    ) -> Result<Option<&'r dyn Reflect>, PathAccessError<'static>> {
+        let mut it = base;
+        let mut optional = false;
+        for accessor in &self.0 {
+            it = match accessor.access(it) {
+                Ok(val) => val,
+                Err(err) if ends_chain(optional, &err) => return Ok(None),
+                Err(err) => return Err(PathAccessError::AccessError(err)),
+            };
+            optional = matches!(accessor.accessor, Accessor::Optional);
+        }
+        Ok(Some(it))
+    }
+
+    /// Returns a mutable reference to the value specified by `path`,
+    /// or `None` if an optional chain is short-circuited, see [`Accessor::Optional`].
+    ///
+    /// The accessor itself will not change and can be reused.
+    pub fn access_mut_opt<'r>(
+        &self,
+        base: &'r mut dyn Reflect,
+    ) -> Result<Option<&'r mut dyn Reflect>, PathAccessError<'static>> {
+        let mut it = base;
+        let mut optional = false;
+        for accessor in &self.0 {
+            it = match accessor.access_mut(it) {
+                Ok(val) => val,
+                Err(err) if ends_chain(optional, &err) => return Ok(None),
+                Err(err) => return Err(PathAccessError::AccessError(err)),
+            };
+            optional = matches!(accessor.accessor, Accessor::Optional);
+        }
+        Ok(Some(it))
+    }
+
     /// Returns a typed reference to the value specified by `path`.
     ///
     /// The accessor itself will not change and can be reused.
@@ -202,6 +276,54 @@ pub trait ReflectPathAccess {
         path: impl AccessPath<'b>,
     ) -> Result<&'a mut dyn Reflect, PathAccessError<'b>>;
 
+    /// Returns a reference to the value specified by `path`,
+    /// or `None` if an optional chain is short-circuited, see [`Accessor::Optional`].
+    ///
+    /// The default impl relies on [`access`](ReflectPathAccess::access)
+    /// stopping at the first failed accessor.
+    ///
+    /// See [`ReflectPathAccess`]
+    fn access_opt<'a, 'b>(
+        &'a self,
+        path: impl AccessPath<'b>,
+    ) -> Result<Option<&'a dyn Reflect>, PathAccessError<'b>> {
+        let path = OptionalChain {
+            path,
+            optional: Cell::new(false),
+        };
+        match self.access(&path) {
+            Ok(value) => Ok(Some(value)),
+            Err(PathAccessError::AccessError(err)) if ends_chain(path.optional.get(), &err) => {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns a mutable reference to the value specified by `path`,
+    /// or `None` if an optional chain is short-circuited, see [`Accessor::Optional`].
+    ///
+    /// The default impl relies on [`access_mut`](ReflectPathAccess::access_mut)
+    /// stopping at the first failed accessor.
+    ///
+    /// See [`ReflectPathAccess`]
+    fn access_mut_opt<'a, 'b>(
+        &'a mut self,
+        path: impl AccessPath<'b>,
+    ) -> Result<Option<&'a mut dyn Reflect>, PathAccessError<'b>> {
+        let path = OptionalChain {
+            path,
+            optional: Cell::new(false),
+        };
+        match self.access_mut(&path) {
+            Ok(value) => Ok(Some(value)),
+            Err(PathAccessError::AccessError(err)) if ends_chain(path.optional.get(), &err) => {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Returns a typed reference to the value specified by `path`.
     ///
     /// See [`ReflectPathAccess`]
@@ -246,6 +368,44 @@ impl ReflectPathAccess for dyn Reflect {
         Ok(it)
     }
 
+    #[inline(never)]
+    fn access_opt<'a, 'b>(
+        &'a self,
+        path: impl AccessPath<'b>,
+    ) -> Result<Option<&'a dyn Reflect>, PathAccessError<'b>> {
+        let mut it: &dyn Reflect = self;
+        let mut optional = false;
+        for res in path.parse_to_accessor() {
+            let accessor = res?;
+            it = match accessor.access(it) {
+                Ok(val) => val,
+                Err(err) if ends_chain(optional, &err) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            optional = matches!(accessor.accessor, Accessor::Optional);
+        }
+        Ok(Some(it))
+    }
+
+    #[inline(never)]
+    fn access_mut_opt<'a, 'b>(
+        &'a mut self,
+        path: impl AccessPath<'b>,
+    ) -> Result<Option<&'a mut dyn Reflect>, PathAccessError<'b>> {
+        let mut it: &mut dyn Reflect = self;
+        let mut optional = false;
+        for res in path.parse_to_accessor() {
+            let accessor = res?;
+            it = match accessor.access_mut(it) {
+                Ok(val) => val,
+                Err(err) if ends_chain(optional, &err) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            optional = matches!(accessor.accessor, Accessor::Optional);
+        }
+        Ok(Some(it))
+    }
+
     #[inline(never)]
     fn access_as<'a, 'b, T: Reflect>(
         &'a self,
@@ -295,6 +455,24 @@ macro_rules! impl_reflect_path_access {
             <dyn Reflect as ReflectPathAccess>::access_mut(self, path)
         }
 
+        #[inline(always)]
+        fn access_opt<'a, 'b>(
+            &'a self,
+            path: impl AccessPath<'b>,
+        ) -> Result<Option<&'a dyn Reflect>, PathAccessError<'b>> {
+            // Significantly reduce compilation time
+            <dyn Reflect as ReflectPathAccess>::access_opt(self, path)
+        }
+
+        #[inline(always)]
+        fn access_mut_opt<'a, 'b>(
+            &'a mut self,
+            path: impl AccessPath<'b>,
+        ) -> Result<Option<&'a mut dyn Reflect>, PathAccessError<'b>> {
+            // Significantly reduce compilation time
+            <dyn Reflect as ReflectPathAccess>::access_mut_opt(self, path)
+        }
+
         #[inline(always)]
         fn access_as<'a, 'b, T: Reflect>(
             &'a self,
@@ -333,3 +511,125 @@ impl_reflect_path_access!(dyn Tuple);
 impl_reflect_path_access!(dyn List);
 impl_reflect_path_access!(dyn Array);
 impl_reflect_path_access!(dyn Enum);
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use vct_reflect_derive::Reflect;
+
+    use super::*;
+
+    #[derive(Reflect)]
+    enum State {
+        Idle,
+        Running { speed: u32 },
+    }
+
+    #[derive(Reflect)]
+    struct Player {
+        state: State,
+        target: Option<State>,
+    }
+
+    /// Only implements the required methods, to test the default impls.
+    struct Inspector(Player);
+
+    impl ReflectPathAccess for Inspector {
+        fn access<'a, 'b>(
+            &'a self,
+            path: impl AccessPath<'b>,
+        ) -> Result<&'a dyn Reflect, PathAccessError<'b>> {
+            self.0.access(path)
+        }
+
+        fn access_mut<'a, 'b>(
+            &'a mut self,
+            path: impl AccessPath<'b>,
+        ) -> Result<&'a mut dyn Reflect, PathAccessError<'b>> {
+            self.0.access_mut(path)
+        }
+
+        fn access_as<'a, 'b, T: Reflect>(
+            &'a self,
+            path: impl AccessPath<'b>,
+        ) -> Result<&'a T, PathAccessError<'b>> {
+            self.0.access_as(path)
+        }
+
+        fn access_mut_as<'a, 'b, T: Reflect>(
+            &'a mut self,
+            path: impl AccessPath<'b>,
+        ) -> Result<&'a mut T, PathAccessError<'b>> {
+            self.0.access_mut_as(path)
+        }
+    }
+
+    fn player() -> Player {
+        Player {
+            state: State::Running { speed: 3 },
+            target: None,
+        }
+    }
+
+    fn error_kind(err: PathAccessError) -> AccessErrorKind {
+        match err {
+            PathAccessError::AccessError(err) => err.kind().clone(),
+            err => panic!("expected an access error, got {err}"),
+        }
+    }
+
+    #[test]
+    fn variant_access() {
+        let mut player = player();
+        assert_eq!(player.access_as::<u32>(".state::Running.speed"), Ok(&3));
+
+        *player.access_mut_as::<u32>(".state::Running.speed").unwrap() = 5;
+        assert!(matches!(player.state, State::Running { speed: 5 }));
+
+        let err = player.access(".state::Idle").unwrap_err();
+        let actual = "Running".into();
+        assert_eq!(error_kind(err), AccessErrorKind::VariantMismatch { actual });
+
+        let err = player.access(".target?").unwrap_err();
+        assert_eq!(error_kind(err), AccessErrorKind::NoneValue);
+    }
+
+    #[test]
+    fn optional_chain() {
+        let mut player = player();
+        assert!(player.access_opt(".target?::Running.speed").unwrap().is_none());
+        assert!(player.access_opt(".state?::Idle").unwrap().is_none());
+        // Only the accessor after `?` is optional.
+        let err = player.access_opt(".state::Idle").unwrap_err();
+        assert!(matches!(error_kind(err), AccessErrorKind::VariantMismatch { .. }));
+        // Errors that don't depend on the variant are not short-circuited.
+        let err = player.access_opt(".state?[0]").unwrap_err();
+        assert!(matches!(error_kind(err), AccessErrorKind::IncompatibleKinds { .. }));
+
+        player.target = Some(State::Running { speed: 1 });
+        let speed = player.access_mut_opt(".target?::Running.speed").unwrap();
+        *speed.unwrap().downcast_mut::<u32>().unwrap() = 2;
+        assert!(matches!(player.target, Some(State::Running { speed: 2 })));
+
+        let accessor = PathAccessor::parse_static(".target?::Idle").unwrap();
+        assert!(accessor.access_opt(&player).unwrap().is_none());
+        assert_eq!(accessor.to_string(), ".target?::Idle");
+    }
+
+    #[test]
+    fn default_optional_access() {
+        let mut inspector = Inspector(player());
+        assert!(inspector.access_opt(".target?::Running.speed").unwrap().is_none());
+        assert!(inspector.access_mut_opt(".state?::Idle").unwrap().is_none());
+
+        let speed = inspector.access_opt(".state?::Running.speed").unwrap();
+        assert_eq!(speed.unwrap().downcast_ref::<u32>(), Some(&3));
+
+        let err = inspector.access_opt(".state::Idle").unwrap_err();
+        assert!(matches!(error_kind(err), AccessErrorKind::VariantMismatch { .. }));
+        let err = inspector.access_mut_opt(".state.missing").unwrap_err();
+        assert!(matches!(error_kind(err), AccessErrorKind::MissingField(_)));
+        let err = inspector.access_mut_opt(".state?[0]").unwrap_err();
+        assert!(matches!(error_kind(err), AccessErrorKind::IncompatibleKinds { .. }));
+    }
+}
@@ -29,13 +29,23 @@ impl<'a> Ident<'a> {
         }
     }
 
+    /// Parses an identifier inside `[]`:
+    /// - An unsuffixed positive integer becomes [`Accessor::ListIndex`].
+    /// - Anything else (e.g. `42u32`, `true` or `Jump`) becomes [`Accessor::KeyLiteral`],
+    ///   which is parsed as the key type when accessing.
     #[inline(always)]
-    fn list_index(self) -> Result<Accessor<'a>, InnerError<'a>> {
+    fn list_index(self) -> Accessor<'a> {
         match self.0.parse() {
-            Ok(index) => Ok(Accessor::ListIndex(index)),
-            Err(_) => Err(InnerError::InvalidIndex(self)),
+            Ok(index) => Accessor::ListIndex(index),
+            Err(_) => Accessor::KeyLiteral(self.0.into()),
         }
     }
+
+    /// Parses an identifier inside `{}` as a string key.
+    #[inline(always)]
+    fn string_key(self) -> Accessor<'a> {
+        Accessor::KeyLiteral(format!("{:?}", self.0).into())
+    }
 }
 
 // NOTE: We use repr(u8) so that the `match byte` in `Token::symbol_from_byte`
@@ -47,11 +57,15 @@ enum Token<'a> {
     Pound = b'#',
     OpenBracket = b'[',
     CloseBracket = b']',
+    OpenBrace = b'{',
+    CloseBrace = b'}',
+    Colon = b':',
+    Question = b'?',
     Ident(Ident<'a>),
 }
 
 impl Token<'_> {
-    const SYMBOLS: &'static [u8] = b".#[]";
+    const SYMBOLS: &'static [u8] = b".#[]{}:?";
 
     #[inline]
     fn symbol_from_byte(byte: u8) -> Option<Self> {
@@ -60,6 +74,10 @@ impl Token<'_> {
             b'#' => Some(Self::Pound),
             b'[' => Some(Self::OpenBracket),
             b']' => Some(Self::CloseBracket),
+            b'{' => Some(Self::OpenBrace),
+            b'}' => Some(Self::CloseBrace),
+            b':' => Some(Self::Colon),
+            b'?' => Some(Self::Question),
             _ => None,
         }
     }
@@ -72,6 +90,10 @@ impl fmt::Display for Token<'_> {
             Token::Pound => f.write_char('#'),
             Token::OpenBracket => f.write_char('['),
             Token::CloseBracket => f.write_char(']'),
+            Token::OpenBrace => f.write_char('{'),
+            Token::CloseBrace => f.write_char('}'),
+            Token::Colon => f.write_char(':'),
+            Token::Question => f.write_char('?'),
             Token::Ident(ident) => f.write_str(ident.0),
         }
     }
@@ -83,9 +105,11 @@ enum InnerError<'a> {
     IsNotIdent(Token<'a>),
     UnexpectedIdent(Ident<'a>),
     InvalidIndex(Ident<'a>),
+    UnclosedString,
     Unclosed,
     BadClose(Token<'a>),
     CloseBeforeOpen,
+    SingleColon(Option<Token<'a>>),
 }
 
 impl<'a> InnerError<'a> {
@@ -97,18 +121,30 @@ impl<'a> InnerError<'a> {
                 format!("expected an identifier, got '{token}' instead").into()
             }
             InnerError::UnexpectedIdent(ident) => {
-                format!("expected a keyword ('#.[]'), got '{}' instead", ident.0).into()
+                format!("expected a keyword ('#.[]{{}}:?'), got '{}' instead", ident.0).into()
             }
             InnerError::InvalidIndex(ident) => {
                 format!("failed to parse index as integer: {}", ident.0).into()
             }
+            InnerError::UnclosedString => {
+                "a quoted key wasn't closed, reached end of path string".into()
+            }
             InnerError::Unclosed => {
-                "a '[' wasn't closed, reached end of path string before finding a ']'".into()
+                "a '[' or '{' wasn't closed, reached end of path string before finding the close"
+                    .into()
             }
             InnerError::BadClose(token) => {
-                format!("a '[' wasn't closed properly, got '{token}' instead").into()
+                format!("a '[' or '{{' wasn't closed properly, got '{token}' instead").into()
+            }
+            InnerError::CloseBeforeOpen => {
+                "a ']' or '}' was found before an opening '[' or '{'".into()
+            }
+            InnerError::SingleColon(Some(token)) => {
+                format!("expected '::' before a variant name, got ':{token}' instead").into()
+            }
+            InnerError::SingleColon(None) => {
+                "expected '::' before a variant name, reached end of path string".into()
             }
-            InnerError::CloseBeforeOpen => "a ']' was found before an opening '['".into(),
         }
     }
 }
@@ -157,20 +193,61 @@ impl<'a> PathParser<'a> {
         }
     }
 
+    /// Parses a quoted key if the remaining path starts with `"` or `'`.
+    ///
+    /// The literal is kept as is, escapes are skipped so it can contain any character.
+    fn next_quoted_key(&mut self) -> Option<Result<Accessor<'a>, InnerError<'a>>> {
+        let to_parse = self.remaining.trim_ascii_start();
+        let quote = *to_parse.first().filter(|byte| matches!(byte, b'"' | b'\''))?;
+
+        let mut bytes = to_parse.iter().enumerate().skip(1);
+        while let Some((index, &byte)) = bytes.next() {
+            if byte == quote {
+                let (literal, remaining) = to_parse.split_at(index + 1);
+                self.remaining = remaining;
+                // The input is only split at ASCII bytes, so the literal stays valid UTF-8.
+                let literal = core::str::from_utf8(literal).expect("path should be valid UTF-8");
+                return Some(Ok(Accessor::KeyLiteral(literal.into())));
+            }
+            if byte == b'\\' {
+                bytes.next();
+            }
+        }
+
+        self.remaining = &[];
+        Some(Err(InnerError::UnclosedString))
+    }
+
     #[inline(always)]
     fn following_accessor(&mut self, token: Token<'a>) -> Result<Accessor<'a>, InnerError<'a>> {
         match token {
             Token::Dot => Ok(self.next_ident()?.field()),
             Token::Pound => self.next_ident()?.field_index(),
             Token::OpenBracket => {
-                let index_ident = self.next_ident()?.list_index()?;
+                let accessor = match self.next_quoted_key() {
+                    Some(key) => key?,
+                    None => self.next_ident()?.list_index(),
+                };
                 match self.next_token() {
-                    Some(Token::CloseBracket) => Ok(index_ident),
+                    Some(Token::CloseBracket) => Ok(accessor),
                     Some(other) => Err(InnerError::BadClose(other)),
                     None => Err(InnerError::Unclosed),
                 }
             }
-            Token::CloseBracket => Err(InnerError::CloseBeforeOpen),
+            Token::OpenBrace => {
+                let accessor = self.next_ident()?.string_key();
+                match self.next_token() {
+                    Some(Token::CloseBrace) => Ok(accessor),
+                    Some(other) => Err(InnerError::BadClose(other)),
+                    None => Err(InnerError::Unclosed),
+                }
+            }
+            Token::Colon => match self.next_token() {
+                Some(Token::Colon) => Ok(Accessor::Variant(self.next_ident()?.0.into())),
+                other => Err(InnerError::SingleColon(other)),
+            },
+            Token::Question => Ok(Accessor::Optional),
+            Token::CloseBracket | Token::CloseBrace => Err(InnerError::CloseBeforeOpen),
             Token::Ident(ident) => Err(InnerError::UnexpectedIdent(ident)),
         }
     }
@@ -220,3 +297,79 @@ impl<'a> AccessPath<'a> for &'a str {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        borrow::Cow,
+        collections::{BTreeMap, BTreeSet},
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use vct_reflect_derive::Reflect;
+
+    use crate::access::{AccessErrorKind, AccessPath, Accessor, PathAccessError, ReflectPathAccess};
+
+    #[derive(Reflect)]
+    struct Settings {
+        bindings: BTreeMap<String, u32>,
+        slots: BTreeMap<u32, String>,
+        flags: BTreeSet<char>,
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            bindings: BTreeMap::from([("jump".into(), 32), ("a.b]".into(), 7)]),
+            slots: BTreeMap::from([(1, "sword".into())]),
+            flags: BTreeSet::from(['x']),
+        }
+    }
+
+    fn parse(path: &str) -> Vec<Accessor<'_>> {
+        path.parse_to_accessor()
+            .map(|res| res.unwrap().accessor)
+            .collect()
+    }
+
+    #[test]
+    fn parse_map_keys() {
+        assert_eq!(
+            parse(r#".bindings["jump"][3]{run}[42u32]['x']"#),
+            [
+                Accessor::FieldName(Cow::Borrowed("bindings")),
+                Accessor::KeyLiteral(Cow::Borrowed(r#""jump""#)),
+                Accessor::ListIndex(3),
+                Accessor::KeyLiteral(Cow::Borrowed(r#""run""#)),
+                Accessor::KeyLiteral(Cow::Borrowed("42u32")),
+                Accessor::KeyLiteral(Cow::Borrowed("'x'")),
+            ]
+        );
+        assert_eq!(parse(r#"["a\"]"]"#), [Accessor::KeyLiteral(r#""a\"]""#.into())]);
+        assert!(r#"["jump]"#.parse_to_accessor().any(|res| res.is_err()));
+    }
+
+    #[test]
+    fn access_map_keys() {
+        let mut settings = settings();
+        assert_eq!(settings.access_as::<u32>(r#".bindings["jump"]"#), Ok(&32));
+        assert_eq!(settings.access_as::<u32>(".bindings{jump}"), Ok(&32));
+        assert_eq!(settings.access_as::<u32>(r#".bindings["a.b]"]"#), Ok(&7));
+        assert_eq!(settings.access_as::<String>(".slots[1u32]").unwrap(), "sword");
+        assert_eq!(settings.access_as::<char>(".flags['x']"), Ok(&'x'));
+
+        *settings.access_mut_as::<u32>(r#".bindings["jump"]"#).unwrap() = 64;
+        assert_eq!(settings.bindings["jump"], 64);
+
+        let Err(PathAccessError::AccessError(err)) = settings.access(r#".bindings["run"]"#) else {
+            panic!("expected an access error");
+        };
+        assert!(matches!(err.kind(), AccessErrorKind::MissingField(_)));
+
+        // The literal is parsed as the key type of the map.
+        let Err(PathAccessError::AccessError(err)) = settings.access(".slots['x']") else {
+            panic!("expected an access error");
+        };
+        assert!(matches!(err.kind(), AccessErrorKind::InvalidKey { .. }));
+        assert!(err.to_string().contains("expected `u32`"), "{err}");
+    }
+}
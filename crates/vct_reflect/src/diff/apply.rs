@@ -0,0 +1,244 @@
+use alloc::{borrow::Cow, boxed::Box, string::String};
+
+use crate::{
+    Reflect,
+    info::{ReflectKind, TypeInfo},
+    ops::{DynamicStruct, List, Map, ReflectMut, Set, Struct},
+    registry::{TypeRegistry, TypeTraitFromReflect},
+};
+
+use super::{DiffError, FieldDiff, ListDiff, MapDiff, ReflectDiff, SetDiff, compute::clone_value};
+
+/// Applies a [`ReflectDiff`] created by [`diff`] to `target`.
+///
+/// The target should be equal to the old value of the diff, otherwise the result is a merge of
+/// the target and the changes. Changes before the failed one are kept if an error occurs.
+///
+/// Dynamic map keys and set values (e.g. from [`ReflectDiffDeserializer`]) can't be looked up in
+/// dynamic maps and sets, use [`apply_diff_with_registry`] for them.
+///
+/// [`diff`]: super::diff
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+#[inline]
+pub fn apply_diff(target: &mut dyn Reflect, diff: &ReflectDiff) -> Result<(), DiffError> {
+    apply_value(target, diff, None)
+}
+
+/// Applies a [`ReflectDiff`] to `target` like [`apply_diff`], dynamic map keys and set values
+/// are converted to the key (value) type of the target with [`TypeTraitFromReflect`] first.
+pub fn apply_diff_with_registry(
+    target: &mut dyn Reflect,
+    diff: &ReflectDiff,
+    registry: &TypeRegistry,
+) -> Result<(), DiffError> {
+    apply_value(target, diff, Some(registry))
+}
+
+fn apply_value(
+    target: &mut dyn Reflect,
+    diff: &ReflectDiff,
+    registry: Option<&TypeRegistry>,
+) -> Result<(), DiffError> {
+    match diff {
+        ReflectDiff::Unchanged => Ok(()),
+        ReflectDiff::Replaced(value) | ReflectDiff::VariantSwitched(value) => {
+            Ok(target.try_apply(value.as_ref())?)
+        }
+        ReflectDiff::Fields(fields) => {
+            for (name, change) in fields {
+                apply_field(target, name, change, registry)?;
+            }
+            Ok(())
+        }
+        ReflectDiff::Elements(elements) => {
+            for (index, diff) in elements {
+                let index = *index;
+                let element = match target.reflect_mut() {
+                    ReflectMut::TupleStruct(tuple_struct) => tuple_struct.field_mut(index),
+                    ReflectMut::Tuple(tuple) => tuple.field_mut(index),
+                    ReflectMut::Array(array) => array.get_mut(index),
+                    ReflectMut::List(list) => list.get_mut(index),
+                    ReflectMut::Enum(enum_mut) => enum_mut.field_at_mut(index),
+                    actual => return Err(mismatched_kinds(ReflectKind::Tuple, actual.kind())),
+                };
+                apply_value(element.ok_or(DiffError::MissingIndex { index })?, diff, registry)?;
+            }
+            Ok(())
+        }
+        ReflectDiff::List(changes) => match target.reflect_mut() {
+            ReflectMut::List(list) => {
+                for change in changes {
+                    apply_list(list, change, registry)?;
+                }
+                Ok(())
+            }
+            actual => Err(mismatched_kinds(ReflectKind::List, actual.kind())),
+        },
+        ReflectDiff::Map(changes) => match target.reflect_mut() {
+            ReflectMut::Map(map) => {
+                for change in changes {
+                    apply_map(map, change, registry)?;
+                }
+                Ok(())
+            }
+            actual => Err(mismatched_kinds(ReflectKind::Map, actual.kind())),
+        },
+        ReflectDiff::Set(changes) => match target.reflect_mut() {
+            ReflectMut::Set(set) => {
+                for change in changes {
+                    apply_set(set, change, registry)?;
+                }
+                Ok(())
+            }
+            actual => Err(mismatched_kinds(ReflectKind::Set, actual.kind())),
+        },
+    }
+}
+
+#[inline]
+fn mismatched_kinds(diff_kind: ReflectKind, target_kind: ReflectKind) -> DiffError {
+    DiffError::MismatchedKinds {
+        diff_kind,
+        target_kind,
+    }
+}
+
+fn apply_field(
+    target: &mut dyn Reflect,
+    name: &str,
+    change: &FieldDiff,
+    registry: Option<&TypeRegistry>,
+) -> Result<(), DiffError> {
+    let missing_field = || DiffError::MissingField {
+        name: Cow::Owned(name.into()),
+    };
+
+    match (change, target.reflect_mut()) {
+        (FieldDiff::Changed(diff), ReflectMut::Struct(dyn_struct)) => {
+            apply_value(dyn_struct.field_mut(name).ok_or_else(missing_field)?, diff, registry)
+        }
+        (FieldDiff::Changed(diff), ReflectMut::Enum(dyn_enum)) => {
+            apply_value(dyn_enum.field_mut(name).ok_or_else(missing_field)?, diff, registry)
+        }
+        (FieldDiff::Added(value), ReflectMut::Struct(dyn_struct)) => {
+            if let Some(field) = dyn_struct.field_mut(name) {
+                return Ok(field.try_apply(value.as_ref())?);
+            }
+            let value = clone_value(value.as_ref())?;
+            dynamic_struct(dyn_struct)?.insert_boxed(String::from(name), value);
+            Ok(())
+        }
+        (FieldDiff::Removed, ReflectMut::Struct(dyn_struct)) => {
+            dynamic_struct(dyn_struct)?.remove(name);
+            Ok(())
+        }
+        (_, ReflectMut::Enum(dyn_enum)) => Err(DiffError::NotDynamic {
+            type_path: Cow::Owned(dyn_enum.reflect_type_path().into()),
+        }),
+        (_, actual) => Err(mismatched_kinds(ReflectKind::Struct, actual.kind())),
+    }
+}
+
+fn dynamic_struct(dyn_struct: &mut dyn Struct) -> Result<&mut DynamicStruct, DiffError> {
+    let target: &mut dyn Reflect = dyn_struct;
+    if !target.is::<DynamicStruct>() {
+        return Err(DiffError::NotDynamic {
+            type_path: Cow::Owned(target.reflect_type_path().into()),
+        });
+    }
+    Ok(target.downcast_mut().expect("type should be checked"))
+}
+
+fn apply_list(
+    list: &mut dyn List,
+    change: &ListDiff,
+    registry: Option<&TypeRegistry>,
+) -> Result<(), DiffError> {
+    match change {
+        ListDiff::Changed { index, diff } => {
+            let item = list.get_mut(*index).ok_or(DiffError::MissingIndex { index: *index })?;
+            apply_value(item, diff, registry)
+        }
+        ListDiff::Spliced {
+            index,
+            removed,
+            inserted,
+        } => {
+            let end = index + removed;
+            if end > list.len() {
+                return Err(DiffError::MissingIndex { index: end - 1 });
+            }
+            for _ in 0..*removed {
+                list.remove(*index);
+            }
+            for (offset, value) in inserted.iter().enumerate() {
+                list.insert(index + offset, clone_value(value.as_ref())?);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Converts a dynamic map key or set value to the concrete type, see [`apply_diff_with_registry`].
+fn concrete_key(
+    key: &dyn Reflect,
+    key_info: Option<&'static TypeInfo>,
+    registry: Option<&TypeRegistry>,
+) -> Option<Box<dyn Reflect>> {
+    if !key.is_dynamic() {
+        return None;
+    }
+    registry?
+        .get_type_trait::<TypeTraitFromReflect>(key_info?.type_id())?
+        .from_reflect(key)
+}
+
+fn apply_map(
+    map: &mut dyn Map,
+    change: &MapDiff,
+    registry: Option<&TypeRegistry>,
+) -> Result<(), DiffError> {
+    let key_info = map.represented_map_info().map(|info| info.key_info());
+    let (MapDiff::Changed { key, .. } | MapDiff::Inserted { key, .. } | MapDiff::Removed { key }) =
+        change;
+    let converted = concrete_key(key.as_ref(), key_info, registry);
+    let key = converted.as_deref().unwrap_or(key.as_ref());
+
+    match change {
+        MapDiff::Changed { diff, .. } => apply_value(
+            map.get_mut(key).ok_or(DiffError::MissingKey)?,
+            diff,
+            registry,
+        ),
+        MapDiff::Inserted { value, .. } => {
+            map.insert_boxed(clone_value(key)?, clone_value(value.as_ref())?);
+            Ok(())
+        }
+        MapDiff::Removed { .. } => match map.remove(key) {
+            Some(_) => Ok(()),
+            None => Err(DiffError::MissingKey),
+        },
+    }
+}
+
+fn apply_set(
+    set: &mut dyn Set,
+    change: &SetDiff,
+    registry: Option<&TypeRegistry>,
+) -> Result<(), DiffError> {
+    let value_info = set.represented_set_info().map(|info| info.value_info());
+    let (SetDiff::Inserted(value) | SetDiff::Removed(value)) = change;
+    let converted = concrete_key(value.as_ref(), value_info, registry);
+    let value = converted.as_deref().unwrap_or(value.as_ref());
+
+    match change {
+        SetDiff::Inserted(_) => {
+            set.insert_boxed(clone_value(value)?);
+            Ok(())
+        }
+        SetDiff::Removed(_) => match set.remove(value) {
+            true => Ok(()),
+            false => Err(DiffError::MissingKey),
+        },
+    }
+}
@@ -0,0 +1,455 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+
+use crate::{
+    Reflect,
+    info::VariantKind,
+    ops::{Enum, List, Map, ReflectRef, Set, Struct},
+};
+
+use super::{DiffError, FieldDiff, ListDiff, MapDiff, ReflectDiff, SetDiff};
+
+/// Returns the structural difference from `old` to `new`.
+///
+/// Applying the result to `old` with [`apply_diff`] turns it into `new`.
+/// Returns [`DiffError::MismatchedTypes`] if both values represent different types.
+///
+/// - Structs, tuples, tuple structs and arrays are compared field by field.
+/// - Lists keep their common prefix and suffix, and record the rest as [`ListDiff`]s.
+///   Items are matched with [`Reflect::reflect_partial_eq`] if supported, otherwise by diffing.
+/// - Maps and sets are compared by key. Removed and changed entries come first (in the
+///   iteration order of `old`), then inserted ones (in the iteration order of `new`),
+///   so the order is arbitrary for hash maps and hash sets.
+/// - Enums are compared field by field if the variant is unchanged,
+///   otherwise the new value is recorded as [`ReflectDiff::VariantSwitched`].
+/// - Opaque values are compared with [`Reflect::reflect_partial_eq`],
+///   values that don't support it are always [`ReflectDiff::Replaced`].
+///
+/// [`apply_diff`]: super::apply_diff
+pub fn diff(old: &dyn Reflect, new: &dyn Reflect) -> Result<ReflectDiff, DiffError> {
+    if let (Some(old_info), Some(new_info)) =
+        (old.represented_type_info(), new.represented_type_info())
+        && old_info.type_id() != new_info.type_id()
+    {
+        return Err(DiffError::MismatchedTypes {
+            old_type: Cow::Borrowed(old_info.type_path()),
+            new_type: Cow::Borrowed(new_info.type_path()),
+        });
+    }
+
+    diff_value(old, new)
+}
+
+/// Clones a value to be recorded in the diff.
+pub(super) fn clone_value(value: &dyn Reflect) -> Result<Box<dyn Reflect>, DiffError> {
+    match value.reflect_clone() {
+        Ok(value) => Ok(value),
+        Err(err) if matches!(value.reflect_ref(), ReflectRef::Opaque(_)) => Err(err.into()),
+        Err(_) => Ok(value.to_dynamic()),
+    }
+}
+
+fn diff_value(old: &dyn Reflect, new: &dyn Reflect) -> Result<ReflectDiff, DiffError> {
+    use ReflectRef::*;
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (Struct(old), Struct(new)) => diff_struct(old, new),
+        (TupleStruct(old), TupleStruct(new)) if old.field_len() == new.field_len() => {
+            diff_elements(old.iter_fields().zip(new.iter_fields()))
+        }
+        (Tuple(old), Tuple(new)) if old.field_len() == new.field_len() => {
+            diff_elements(old.iter_fields().zip(new.iter_fields()))
+        }
+        (Array(old), Array(new)) if old.len() == new.len() => {
+            diff_elements(old.iter().zip(new.iter()))
+        }
+        (List(old), List(new)) => diff_list(old, new),
+        (Map(old), Map(new)) => diff_map(old, new),
+        (Set(old), Set(new)) => diff_set(old, new),
+        (Enum(old), Enum(new)) => diff_enum(old, new),
+        (Opaque(old), Opaque(new)) => match old.reflect_partial_eq(new) {
+            Some(true) => Ok(ReflectDiff::Unchanged),
+            _ => Ok(ReflectDiff::Replaced(clone_value(new)?)),
+        },
+        _ => Ok(ReflectDiff::Replaced(clone_value(new)?)),
+    }
+}
+
+/// Returns `true` if [`diff_value`] would return [`ReflectDiff::Unchanged`],
+/// without cloning any value.
+fn is_unchanged(old: &dyn Reflect, new: &dyn Reflect) -> bool {
+    use ReflectRef::*;
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (Struct(old), Struct(new)) => {
+            old.field_len() == new.field_len()
+                && new.iter_fields().enumerate().all(|(index, new_field)| {
+                    let name = new.name_at(index).expect("field should exist");
+                    old.field(name)
+                        .is_some_and(|old_field| is_unchanged(old_field, new_field))
+                })
+        }
+        (TupleStruct(old), TupleStruct(new)) => {
+            old.field_len() == new.field_len()
+                && all_unchanged(old.iter_fields().zip(new.iter_fields()))
+        }
+        (Tuple(old), Tuple(new)) => {
+            old.field_len() == new.field_len()
+                && all_unchanged(old.iter_fields().zip(new.iter_fields()))
+        }
+        (Array(old), Array(new)) => {
+            old.len() == new.len() && all_unchanged(old.iter().zip(new.iter()))
+        }
+        // Like `diff_list`, items are matched by `reflect_partial_eq` if supported.
+        (List(old), List(new)) => {
+            old.len() == new.len()
+                && old.iter().zip(new.iter()).all(|(old, new)| {
+                    old.reflect_partial_eq(new).unwrap_or_else(|| is_unchanged(old, new))
+                })
+        }
+        (Map(old), Map(new)) => {
+            old.len() == new.len()
+                && old.iter().all(|(key, old_value)| {
+                    new.get(key)
+                        .is_some_and(|new_value| is_unchanged(old_value, new_value))
+                })
+        }
+        (Set(old), Set(new)) => {
+            old.len() == new.len() && old.iter().all(|value| new.contains(value))
+        }
+        (Enum(old), Enum(new)) => {
+            old.variant_name() == new.variant_name()
+                && old.variant_kind() == new.variant_kind()
+                && old.field_len() == new.field_len()
+                && match new.variant_kind() {
+                    VariantKind::Unit => true,
+                    VariantKind::Tuple => all_unchanged(
+                        old.iter_fields()
+                            .map(|field| field.value())
+                            .zip(new.iter_fields().map(|field| field.value())),
+                    ),
+                    VariantKind::Struct => new.iter_fields().all(|field| {
+                        let name = field.name().expect("field should exist");
+                        old.field(name)
+                            .is_some_and(|old_field| is_unchanged(old_field, field.value()))
+                    }),
+                }
+        }
+        (Opaque(old), Opaque(new)) => old.reflect_partial_eq(new) == Some(true),
+        _ => false,
+    }
+}
+
+fn all_unchanged<'a>(mut pairs: impl Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)>) -> bool {
+    pairs.all(|(old, new)| is_unchanged(old, new))
+}
+
+fn diff_struct(old: &dyn Struct, new: &dyn Struct) -> Result<ReflectDiff, DiffError> {
+    let mut fields = Vec::new();
+
+    for (index, new_field) in new.iter_fields().enumerate() {
+        let name = new.name_at(index).expect("field should exist");
+        let change = match old.field(name) {
+            Some(old_field) => match diff_value(old_field, new_field)? {
+                ReflectDiff::Unchanged => continue,
+                diff => FieldDiff::Changed(diff),
+            },
+            None => FieldDiff::Added(clone_value(new_field)?),
+        };
+        fields.push((Cow::Owned(name.into()), change));
+    }
+
+    for index in 0..old.field_len() {
+        let name = old.name_at(index).expect("field should exist");
+        if new.field(name).is_none() {
+            fields.push((Cow::Owned(name.into()), FieldDiff::Removed));
+        }
+    }
+
+    Ok(if fields.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Fields(fields)
+    })
+}
+
+fn diff_elements<'a>(
+    pairs: impl Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)>,
+) -> Result<ReflectDiff, DiffError> {
+    let mut elements = Vec::new();
+
+    for (index, (old, new)) in pairs.enumerate() {
+        let diff = diff_value(old, new)?;
+        if !diff.is_unchanged() {
+            elements.push((index, diff));
+        }
+    }
+
+    Ok(if elements.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Elements(elements)
+    })
+}
+
+fn diff_list(old: &dyn List, new: &dyn List) -> Result<ReflectDiff, DiffError> {
+    let (old_len, new_len) = (old.len(), new.len());
+    let is_same = |old_index: usize, new_index: usize| -> Result<bool, DiffError> {
+        let old = old.get(old_index).expect("index should be in bounds");
+        let new = new.get(new_index).expect("index should be in bounds");
+        Ok(old.reflect_partial_eq(new).unwrap_or_else(|| is_unchanged(old, new)))
+    };
+
+    let mut prefix = 0;
+    while prefix < old_len.min(new_len) && is_same(prefix, prefix)? {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < old_len.min(new_len) - prefix
+        && is_same(old_len - suffix - 1, new_len - suffix - 1)?
+    {
+        suffix += 1;
+    }
+
+    // Items in the middle are changed in place, the rest is spliced.
+    let mut changes = Vec::new();
+    let (old_end, new_end) = (old_len - suffix, new_len - suffix);
+    let common = (old_end - prefix).min(new_end - prefix);
+
+    for index in prefix..prefix + common {
+        let old = old.get(index).expect("index should be in bounds");
+        let new = new.get(index).expect("index should be in bounds");
+        let diff = diff_value(old, new)?;
+        if !diff.is_unchanged() {
+            changes.push(ListDiff::Changed { index, diff });
+        }
+    }
+
+    let index = prefix + common;
+    if index < old_end || index < new_end {
+        let inserted = (index..new_end)
+            .map(|index| clone_value(new.get(index).expect("index should be in bounds")))
+            .collect::<Result<_, _>>()?;
+        changes.push(ListDiff::Spliced {
+            index,
+            removed: old_end - index,
+            inserted,
+        });
+    }
+
+    Ok(if changes.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::List(changes)
+    })
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> Result<ReflectDiff, DiffError> {
+    let mut changes = Vec::new();
+
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => match diff_value(old_value, new_value)? {
+                ReflectDiff::Unchanged => {}
+                diff => changes.push(MapDiff::Changed {
+                    key: clone_value(key)?,
+                    diff,
+                }),
+            },
+            None => changes.push(MapDiff::Removed {
+                key: clone_value(key)?,
+            }),
+        }
+    }
+
+    for (key, value) in new.iter() {
+        if old.get(key).is_none() {
+            changes.push(MapDiff::Inserted {
+                key: clone_value(key)?,
+                value: clone_value(value)?,
+            });
+        }
+    }
+
+    Ok(if changes.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Map(changes)
+    })
+}
+
+fn diff_set(old: &dyn Set, new: &dyn Set) -> Result<ReflectDiff, DiffError> {
+    let mut changes = Vec::new();
+
+    for value in old.iter() {
+        if !new.contains(value) {
+            changes.push(SetDiff::Removed(clone_value(value)?));
+        }
+    }
+    for value in new.iter() {
+        if !old.contains(value) {
+            changes.push(SetDiff::Inserted(clone_value(value)?));
+        }
+    }
+
+    Ok(if changes.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Set(changes)
+    })
+}
+
+fn diff_enum(old: &dyn Enum, new: &dyn Enum) -> Result<ReflectDiff, DiffError> {
+    if old.variant_name() != new.variant_name()
+        || old.variant_kind() != new.variant_kind()
+        || old.field_len() != new.field_len()
+    {
+        return Ok(ReflectDiff::VariantSwitched(clone_value(new)?));
+    }
+
+    match new.variant_kind() {
+        VariantKind::Unit => Ok(ReflectDiff::Unchanged),
+        VariantKind::Tuple => diff_elements(
+            old.iter_fields()
+                .map(|field| field.value())
+                .zip(new.iter_fields().map(|field| field.value())),
+        ),
+        VariantKind::Struct => {
+            let mut fields = Vec::new();
+            for field in new.iter_fields() {
+                let name = field.name().expect("field should exist");
+                let Some(old_field) = old.field(name) else {
+                    return Ok(ReflectDiff::VariantSwitched(clone_value(new)?));
+                };
+                let diff = diff_value(old_field, field.value())?;
+                if !diff.is_unchanged() {
+                    fields.push((Cow::Owned(name.into()), FieldDiff::Changed(diff)));
+                }
+            }
+            Ok(if fields.is_empty() {
+                ReflectDiff::Unchanged
+            } else {
+                ReflectDiff::Fields(fields)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        diff::{FieldDiff, ListDiff, MapDiff, ReflectDiff, apply_diff, diff},
+    };
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    #[reflect(partial_eq)]
+    struct Item {
+        id: u32,
+        count: u32,
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    enum State {
+        Idle,
+        Busy(u32),
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    struct Inventory {
+        items: Vec<Item>,
+        counts: BTreeMap<String, u32>,
+        state: State,
+    }
+
+    fn item(id: u32) -> Item {
+        Item { id, count: 1 }
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let old = Inventory {
+            items: vec![item(1), item(2), item(3)],
+            counts: BTreeMap::from([("gold".into(), 5), ("gems".into(), 1)]),
+            state: State::Idle,
+        };
+        let mut new = old.clone();
+        new.items.insert(1, item(9));
+        new.counts.insert("gold".into(), 6);
+        new.counts.remove("gems");
+        new.state = State::Busy(3);
+
+        let ReflectDiff::Fields(fields) = diff(&old, &new).unwrap() else {
+            panic!("expected changed fields");
+        };
+        assert_eq!(fields.len(), 3);
+
+        let (_, FieldDiff::Changed(ReflectDiff::List(changes))) = &fields[0] else {
+            panic!("expected list changes");
+        };
+        let [ListDiff::Spliced { index: 1, removed: 0, inserted }] = changes.as_slice() else {
+            panic!("expected a single splice, got {changes:?}");
+        };
+        assert_eq!(Item::from_reflect(inserted[0].as_ref()), Some(item(9)));
+
+        let (_, FieldDiff::Changed(ReflectDiff::Map(changes))) = &fields[1] else {
+            panic!("expected map changes");
+        };
+        assert!(matches!(changes[0], MapDiff::Removed { .. }));
+        assert!(matches!(changes[1], MapDiff::Changed { .. }));
+
+        let (_, FieldDiff::Changed(ReflectDiff::VariantSwitched(_))) = &fields[2] else {
+            panic!("expected a variant switch");
+        };
+
+        let mut target = old.clone();
+        apply_diff(&mut target, &diff(&old, &new).unwrap()).unwrap();
+        assert_eq!(target, new);
+        assert!(diff(&new, &target).unwrap().is_unchanged());
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    struct Slot {
+        item: Item,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn list_items_without_partial_eq() {
+        let slot = |id, tag: &str| Slot { item: item(id), tags: vec![tag.into()] };
+        let old = vec![slot(1, "a"), slot(2, "b"), slot(3, "c")];
+        let mut new = old.clone();
+        new.insert(1, slot(9, "z"));
+
+        // `Slot` doesn't support `reflect_partial_eq`, items are compared structurally.
+        let ReflectDiff::List(changes) = diff(&old, &new).unwrap() else {
+            panic!("expected list changes");
+        };
+        let [ListDiff::Spliced { index: 1, removed: 0, inserted }] = changes.as_slice() else {
+            panic!("expected a single splice, got {changes:?}");
+        };
+        assert_eq!(Slot::from_reflect(inserted[0].as_ref()), Some(slot(9, "z")));
+
+        let mut target = old.clone();
+        apply_diff(&mut target, &diff(&old, &new).unwrap()).unwrap();
+        assert_eq!(target, new);
+    }
+
+    #[test]
+    fn list_items_use_partial_eq() {
+        let old = vec![item(1), item(2)];
+        let mut new = old.clone();
+        new[1].count = 2;
+
+        // The unchanged prefix is matched by `PartialEq`, the changed item is diffed in place.
+        let ReflectDiff::List(changes) = diff(&old, &new).unwrap() else {
+            panic!("expected list changes");
+        };
+        let [ListDiff::Changed { index: 1, diff }] = changes.as_slice() else {
+            panic!("expected a single change, got {changes:?}");
+        };
+        assert!(matches!(diff, ReflectDiff::Fields(fields) if fields.len() == 1));
+    }
+}
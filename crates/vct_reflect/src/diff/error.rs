@@ -0,0 +1,77 @@
+use alloc::borrow::Cow;
+use core::{error, fmt};
+
+use crate::{
+    info::ReflectKind,
+    ops::{ApplyError, ReflectCloneError},
+};
+
+/// A enumeration of all error outcomes that might happen when running
+/// [`diff`](super::diff) or [`apply_diff`](super::apply_diff).
+#[derive(Debug)]
+pub enum DiffError {
+    /// Tried to diff values of different types.
+    MismatchedTypes {
+        old_type: Cow<'static, str>,
+        new_type: Cow<'static, str>,
+    },
+    /// A value recorded in the diff could not be cloned.
+    Clone(ReflectCloneError),
+    /// A replaced value could not be applied to the target.
+    Apply(ApplyError),
+    /// Attempted to apply the diff of a [kind](ReflectKind) to another, e.g. a list to a struct.
+    MismatchedKinds {
+        diff_kind: ReflectKind,
+        target_kind: ReflectKind,
+    },
+    /// The target doesn't have the field changed by the diff.
+    MissingField { name: Cow<'static, str> },
+    /// The target doesn't have the index changed by the diff.
+    MissingIndex { index: usize },
+    /// The target doesn't have the map key or set value changed by the diff.
+    MissingKey,
+    /// Fields can only be added to or removed from a [`DynamicStruct`].
+    ///
+    /// [`DynamicStruct`]: crate::ops::DynamicStruct
+    NotDynamic { type_path: Cow<'static, str> },
+}
+
+impl fmt::Display for DiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MismatchedTypes { old_type, new_type } => {
+                write!(f, "attempted to diff `{old_type}` with `{new_type}`")
+            }
+            Self::Clone(err) => write!(f, "failed to clone value: {err}"),
+            Self::Apply(err) => write!(f, "failed to apply value: {err}"),
+            Self::MismatchedKinds {
+                diff_kind,
+                target_kind,
+            } => {
+                write!(f, "attempted to apply the diff of `{diff_kind}` to `{target_kind}`")
+            }
+            Self::MissingField { name } => write!(f, "the target doesn't have field `{name}`"),
+            Self::MissingIndex { index } => write!(f, "the target doesn't have index `{index}`"),
+            Self::MissingKey => f.write_str("the target doesn't have the key"),
+            Self::NotDynamic { type_path } => {
+                write!(f, "cannot add or remove fields of non-dynamic type `{type_path}`")
+            }
+        }
+    }
+}
+
+impl error::Error for DiffError {}
+
+impl From<ReflectCloneError> for DiffError {
+    #[inline]
+    fn from(value: ReflectCloneError) -> Self {
+        Self::Clone(value)
+    }
+}
+
+impl From<ApplyError> for DiffError {
+    #[inline]
+    fn from(value: ApplyError) -> Self {
+        Self::Apply(value)
+    }
+}
@@ -0,0 +1,24 @@
+//! Structural diff and patch of reflected values.
+//!
+//! [`diff`] walks two values of the same type and records what changed as a [`ReflectDiff`]
+//! tree, [`apply_diff`] replays it onto a value. The diff itself can be serialized with
+//! [`ReflectDiffSerializer`] and [`ReflectDiffDeserializer`], e.g. for undo/redo history
+//! or network delta sync.
+//!
+//! [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+//! [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+
+// Diff tree.
+mod reflect_diff;
+pub use reflect_diff::{FieldDiff, ListDiff, MapDiff, ReflectDiff, SetDiff};
+
+mod error;
+pub use error::DiffError;
+
+// Build a diff from two values.
+mod compute;
+pub use compute::diff;
+
+// Apply a diff to a value.
+mod apply;
+pub use apply::{apply_diff, apply_diff_with_registry};
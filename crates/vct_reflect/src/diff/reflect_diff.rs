@@ -0,0 +1,84 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+
+use crate::Reflect;
+
+/// A structural difference between two reflected values, created by [`diff`].
+///
+/// Values recorded in the diff are clones of the new value,
+/// so the diff doesn't borrow either side.
+///
+/// [`diff`]: super::diff
+#[derive(Debug)]
+pub enum ReflectDiff {
+    /// The values are equal.
+    Unchanged,
+    /// The value is replaced as a whole, e.g. a changed opaque value.
+    Replaced(Box<dyn Reflect>),
+    /// The changed fields of a struct or a struct variant.
+    Fields(Vec<(Cow<'static, str>, FieldDiff)>),
+    /// The changed fields of a tuple, tuple struct or tuple variant,
+    /// or the changed items of an array, keyed by index.
+    Elements(Vec<(usize, ReflectDiff)>),
+    /// The changes of a list, applied in order.
+    List(Vec<ListDiff>),
+    /// The changes of a map.
+    Map(Vec<MapDiff>),
+    /// The changes of a set.
+    Set(Vec<SetDiff>),
+    /// The enum switched to another variant, holding the new value.
+    VariantSwitched(Box<dyn Reflect>),
+}
+
+/// The change of a struct field in a [`ReflectDiff::Fields`].
+///
+/// Fields are only added or removed between [`DynamicStruct`]s with different fields.
+///
+/// [`DynamicStruct`]: crate::ops::DynamicStruct
+#[derive(Debug)]
+pub enum FieldDiff {
+    Changed(ReflectDiff),
+    Added(Box<dyn Reflect>),
+    Removed,
+}
+
+/// A change of a list in a [`ReflectDiff::List`].
+#[derive(Debug)]
+pub enum ListDiff {
+    /// The item at `index` changed.
+    Changed { index: usize, diff: ReflectDiff },
+    /// `removed` items starting at `index` are replaced by `inserted`.
+    Spliced {
+        index: usize,
+        removed: usize,
+        inserted: Vec<Box<dyn Reflect>>,
+    },
+}
+
+/// A change of a map entry in a [`ReflectDiff::Map`].
+#[derive(Debug)]
+pub enum MapDiff {
+    Changed {
+        key: Box<dyn Reflect>,
+        diff: ReflectDiff,
+    },
+    Inserted {
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    },
+    Removed { key: Box<dyn Reflect> },
+}
+
+/// A change of a set in a [`ReflectDiff::Set`].
+#[derive(Debug)]
+pub enum SetDiff {
+    Inserted(Box<dyn Reflect>),
+    Removed(Box<dyn Reflect>),
+}
+
+impl ReflectDiff {
+    /// Returns `true` if the values are equal.
+    #[inline]
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
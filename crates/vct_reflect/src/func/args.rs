@@ -0,0 +1,331 @@
+use alloc::{borrow::{Cow, ToOwned}, boxed::Box, collections::VecDeque};
+use core::fmt;
+
+use crate::{
+    FromReflect, Reflect,
+    func::ArgError,
+    info::{TypeInfo, TypePath, Typed},
+};
+
+/// The ownership of an argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ownership {
+    /// `T`
+    Owned,
+    /// `&T`
+    Ref,
+    /// `&mut T`
+    Mut,
+}
+
+impl fmt::Display for Ownership {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Owned => f.write_str("owned value"),
+            Self::Ref => f.write_str("reference"),
+            Self::Mut => f.write_str("mutable reference"),
+        }
+    }
+}
+
+/// The value of an [`Arg`].
+pub enum ArgValue<'a> {
+    /// An owned value, passed as `T`.
+    Owned(Box<dyn Reflect>),
+    /// A borrowed value, passed as `&T`.
+    Ref(&'a dyn Reflect),
+    /// A mutably borrowed value, passed as `&mut T`.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl ArgValue<'_> {
+    /// Returns the [`Ownership`] of the value.
+    #[inline]
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            Self::Owned(_) => Ownership::Owned,
+            Self::Ref(_) => Ownership::Ref,
+            Self::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns the value as `&dyn Reflect`.
+    #[inline]
+    pub fn as_reflect(&self) -> &dyn Reflect {
+        match self {
+            Self::Owned(value) => value.as_ref(),
+            Self::Ref(value) => *value,
+            Self::Mut(value) => &**value,
+        }
+    }
+}
+
+/// An argument of a dynamic function, with its index in the [`ArgList`].
+pub struct Arg<'a> {
+    index: usize,
+    value: ArgValue<'a>,
+}
+
+impl<'a> Arg<'a> {
+    /// Creates a new [`Arg`].
+    #[inline]
+    pub fn new(index: usize, value: ArgValue<'a>) -> Self {
+        Self { index, value }
+    }
+
+    /// Returns the index of the argument.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the value of the argument.
+    #[inline]
+    pub fn value(&self) -> &ArgValue<'a> {
+        &self.value
+    }
+
+    /// Returns the value of the argument.
+    #[inline]
+    pub fn take(self) -> ArgValue<'a> {
+        self.value
+    }
+
+    fn invalid_type<T: TypePath>(&self) -> ArgError {
+        ArgError::InvalidType {
+            index: self.index,
+            expected: Cow::Borrowed(T::type_path()),
+            received: Cow::Owned(self.value.as_reflect().reflect_type_path().to_owned()),
+        }
+    }
+
+    fn invalid_ownership(&self, expected: Ownership) -> ArgError {
+        ArgError::InvalidOwnership {
+            index: self.index,
+            expected,
+            received: self.value.ownership(),
+        }
+    }
+
+    /// Takes the owned value, it's converted by [`FromReflect`] if the type is not `T`.
+    pub fn take_owned<T: FromReflect + TypePath>(self) -> Result<T, ArgError> {
+        match self.value {
+            ArgValue::Owned(value) => T::take_from_reflect(value).map_err(|value| {
+                Arg::new(self.index, ArgValue::Owned(value)).invalid_type::<T>()
+            }),
+            _ => Err(self.invalid_ownership(Ownership::Owned)),
+        }
+    }
+
+    /// Takes the value as `&T`, mutable references are also accepted.
+    pub fn take_ref<T: Reflect + TypePath>(self) -> Result<&'a T, ArgError> {
+        let error = self.invalid_type::<T>();
+        match self.value {
+            ArgValue::Ref(value) => value.downcast_ref::<T>().ok_or(error),
+            ArgValue::Mut(value) => value.downcast_ref::<T>().ok_or(error),
+            ArgValue::Owned(_) => Err(self.invalid_ownership(Ownership::Ref)),
+        }
+    }
+
+    /// Takes the value as `&mut T`.
+    pub fn take_mut<T: Reflect + TypePath>(self) -> Result<&'a mut T, ArgError> {
+        let error = self.invalid_type::<T>();
+        match self.value {
+            ArgValue::Mut(value) => value.downcast_mut::<T>().ok_or(error),
+            _ => Err(self.invalid_ownership(Ownership::Mut)),
+        }
+    }
+}
+
+/// An ordered list of arguments for calling a dynamic function.
+///
+/// # Example
+///
+/// ```ignore
+/// let args = ArgList::new()
+///     .with_mut(&mut player)
+///     .with_owned(10.0_f32);
+/// ```
+#[derive(Default)]
+pub struct ArgList<'a> {
+    list: VecDeque<Arg<'a>>,
+    next_index: usize,
+}
+
+impl<'a> ArgList<'a> {
+    /// Creates an empty [`ArgList`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            list: VecDeque::new(),
+            next_index: 0,
+        }
+    }
+
+    /// Appends an argument.
+    pub fn push_arg(&mut self, value: ArgValue<'a>) {
+        self.list.push_back(Arg::new(self.next_index, value));
+        self.next_index += 1;
+    }
+
+    /// Appends an owned argument.
+    #[inline]
+    pub fn push_owned(&mut self, value: impl Reflect) {
+        self.push_arg(ArgValue::Owned(Box::new(value)));
+    }
+
+    /// Appends a boxed owned argument.
+    #[inline]
+    pub fn push_boxed(&mut self, value: Box<dyn Reflect>) {
+        self.push_arg(ArgValue::Owned(value));
+    }
+
+    /// Appends a reference argument.
+    #[inline]
+    pub fn push_ref(&mut self, value: &'a dyn Reflect) {
+        self.push_arg(ArgValue::Ref(value));
+    }
+
+    /// Appends a mutable reference argument.
+    #[inline]
+    pub fn push_mut(&mut self, value: &'a mut dyn Reflect) {
+        self.push_arg(ArgValue::Mut(value));
+    }
+
+    /// Appends an owned argument, see [`ArgList::push_owned`].
+    #[inline]
+    pub fn with_owned(mut self, value: impl Reflect) -> Self {
+        self.push_owned(value);
+        self
+    }
+
+    /// Appends a boxed owned argument, see [`ArgList::push_boxed`].
+    #[inline]
+    pub fn with_boxed(mut self, value: Box<dyn Reflect>) -> Self {
+        self.push_boxed(value);
+        self
+    }
+
+    /// Appends a reference argument, see [`ArgList::push_ref`].
+    #[inline]
+    pub fn with_ref(mut self, value: &'a dyn Reflect) -> Self {
+        self.push_ref(value);
+        self
+    }
+
+    /// Appends a mutable reference argument, see [`ArgList::push_mut`].
+    #[inline]
+    pub fn with_mut(mut self, value: &'a mut dyn Reflect) -> Self {
+        self.push_mut(value);
+        self
+    }
+
+    /// Removes the first argument.
+    #[inline]
+    pub fn take_arg(&mut self) -> Result<Arg<'a>, ArgError> {
+        self.list.pop_front().ok_or(ArgError::EmptyArgList)
+    }
+
+    /// Removes the first argument and takes the owned value, see [`Arg::take_owned`].
+    #[inline]
+    pub fn take_owned<T: FromReflect + TypePath>(&mut self) -> Result<T, ArgError> {
+        self.take_arg()?.take_owned()
+    }
+
+    /// Removes the first argument and takes it as `&T`, see [`Arg::take_ref`].
+    #[inline]
+    pub fn take_ref<T: Reflect + TypePath>(&mut self) -> Result<&'a T, ArgError> {
+        self.take_arg()?.take_ref()
+    }
+
+    /// Removes the first argument and takes it as `&mut T`, see [`Arg::take_mut`].
+    #[inline]
+    pub fn take_mut<T: Reflect + TypePath>(&mut self) -> Result<&'a mut T, ArgError> {
+        self.take_arg()?.take_mut()
+    }
+
+    /// Returns the number of remaining arguments.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if there are no remaining arguments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+/// Marker for owned arguments, see [`FromArg`].
+pub struct OwnedArg;
+/// Marker for reference arguments, see [`FromArg`].
+pub struct RefArg;
+/// Marker for mutable reference arguments, see [`FromArg`].
+pub struct MutArg;
+
+/// A trait for types that can be converted from an [`Arg`].
+///
+/// It's implemented for `T: FromReflect`, `&T` and `&mut T`,
+/// the `Marker` is only used to distinguish them, so that the impls do not conflict.
+pub trait FromArg<Marker> {
+    /// The converted type, with the lifetime of the argument.
+    type This<'a>;
+
+    /// The ownership of this argument.
+    const OWNERSHIP: Ownership;
+
+    /// Returns the [`TypeInfo`] of the argument (without reference).
+    fn arg_type_info() -> &'static TypeInfo;
+
+    /// Converts the argument.
+    fn from_arg(arg: Arg<'_>) -> Result<Self::This<'_>, ArgError>;
+}
+
+impl<T: FromReflect + Typed> FromArg<OwnedArg> for T {
+    type This<'a> = T;
+
+    const OWNERSHIP: Ownership = Ownership::Owned;
+
+    #[inline]
+    fn arg_type_info() -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    #[inline]
+    fn from_arg(arg: Arg<'_>) -> Result<Self::This<'_>, ArgError> {
+        arg.take_owned()
+    }
+}
+
+impl<T: Reflect + Typed> FromArg<RefArg> for &T {
+    type This<'a> = &'a T;
+
+    const OWNERSHIP: Ownership = Ownership::Ref;
+
+    #[inline]
+    fn arg_type_info() -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    #[inline]
+    fn from_arg(arg: Arg<'_>) -> Result<Self::This<'_>, ArgError> {
+        arg.take_ref()
+    }
+}
+
+impl<T: Reflect + Typed> FromArg<MutArg> for &mut T {
+    type This<'a> = &'a mut T;
+
+    const OWNERSHIP: Ownership = Ownership::Mut;
+
+    #[inline]
+    fn arg_type_info() -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    #[inline]
+    fn from_arg(arg: Arg<'_>) -> Result<Self::This<'_>, ArgError> {
+        arg.take_mut()
+    }
+}
@@ -0,0 +1,247 @@
+use alloc::{borrow::Cow, boxed::Box};
+use core::fmt;
+use vct_os::sync::Arc;
+
+use crate::func::{ArgList, FunctionError, FunctionInfo, FunctionResult, Ownership};
+
+/// A dynamic function, created from a `Fn` function or closure, see [`IntoFunction`].
+///
+/// It can be cloned cheaply, the internal function is shared.
+///
+/// [`IntoFunction`]: crate::func::IntoFunction
+#[derive(Clone)]
+pub struct DynamicFunction<'env> {
+    info: FunctionInfo,
+    func: Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult + Send + Sync + 'env>,
+}
+
+impl<'env> DynamicFunction<'env> {
+    /// Creates a new [`DynamicFunction`].
+    ///
+    /// The `info` should match the function, it's used for checking the number of arguments.
+    pub fn new<F>(func: F, info: FunctionInfo) -> Self
+    where
+        F: for<'a> Fn(ArgList<'a>) -> FunctionResult + Send + Sync + 'env,
+    {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Sets the name of the function.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// Returns the [`FunctionInfo`].
+    #[inline]
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Returns the name of the function, see [`FunctionInfo::name`].
+    #[inline]
+    pub fn name(&self) -> Option<&Cow<'static, str>> {
+        self.info.name()
+    }
+
+    /// Calls the function with the given arguments.
+    pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult {
+        check_arg_count(&self.info, &args)?;
+        (self.func)(args)
+    }
+}
+
+impl fmt::Debug for DynamicFunction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynamicFunction(")?;
+        function_debug(&self.info, f)?;
+        write!(f, ")")
+    }
+}
+
+/// A dynamic function, created from a `FnMut` function or closure, see [`IntoFunctionMut`].
+///
+/// [`IntoFunctionMut`]: crate::func::IntoFunctionMut
+pub struct DynamicFunctionMut<'env> {
+    info: FunctionInfo,
+    func: Box<dyn for<'a> FnMut(ArgList<'a>) -> FunctionResult + Send + Sync + 'env>,
+}
+
+impl<'env> DynamicFunctionMut<'env> {
+    /// Creates a new [`DynamicFunctionMut`].
+    ///
+    /// The `info` should match the function, it's used for checking the number of arguments.
+    pub fn new<F>(func: F, info: FunctionInfo) -> Self
+    where
+        F: for<'a> FnMut(ArgList<'a>) -> FunctionResult + Send + Sync + 'env,
+    {
+        Self {
+            info,
+            func: Box::new(func),
+        }
+    }
+
+    /// Sets the name of the function.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// Returns the [`FunctionInfo`].
+    #[inline]
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Returns the name of the function, see [`FunctionInfo::name`].
+    #[inline]
+    pub fn name(&self) -> Option<&Cow<'static, str>> {
+        self.info.name()
+    }
+
+    /// Calls the function with the given arguments.
+    pub fn call<'a>(&mut self, args: ArgList<'a>) -> FunctionResult {
+        check_arg_count(&self.info, &args)?;
+        (self.func)(args)
+    }
+
+    /// Calls the function with the given arguments, and consumes it.
+    #[inline]
+    pub fn call_once(mut self, args: ArgList) -> FunctionResult {
+        self.call(args)
+    }
+}
+
+impl<'env> From<DynamicFunction<'env>> for DynamicFunctionMut<'env> {
+    #[inline]
+    fn from(value: DynamicFunction<'env>) -> Self {
+        let func = value.func;
+        Self {
+            info: value.info,
+            func: Box::new(move |args| func(args)),
+        }
+    }
+}
+
+impl fmt::Debug for DynamicFunctionMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynamicFunctionMut(")?;
+        function_debug(&self.info, f)?;
+        write!(f, ")")
+    }
+}
+
+fn check_arg_count(info: &FunctionInfo, args: &ArgList) -> Result<(), FunctionError> {
+    if info.arg_count() != args.len() {
+        return Err(FunctionError::ArgCountMismatch {
+            expected: info.arg_count(),
+            received: args.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Formats as `fn name(&mut foo::Foo, f32) -> bool`.
+fn function_debug(info: &FunctionInfo, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "fn {}(", info.name().map(AsRef::as_ref).unwrap_or("_"))?;
+    for (index, arg) in info.args().iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        match arg.ownership() {
+            Ownership::Owned => {},
+            Ownership::Ref => write!(f, "&")?,
+            Ownership::Mut => write!(f, "&mut ")?,
+        }
+        write!(f, "{}", arg.type_path())?;
+    }
+    write!(f, ") -> {}", info.return_type().path())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+    use core::any::TypeId;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        func::{ArgError, ArgList, FunctionError, IntoFunction, IntoFunctionMut, Ownership},
+        info::{ReflectKind, TypePath},
+        registry::TypeRegistry,
+    };
+
+    #[derive(Reflect)]
+    struct Player {
+        health: f32,
+    }
+
+    impl Player {
+        fn heal(&mut self, amount: f32) -> f32 {
+            self.health += amount;
+            self.health
+        }
+    }
+
+    fn greet(name: &String) -> String {
+        format!("hello {name}")
+    }
+
+    #[test]
+    fn call_function() {
+        let func = greet.into_function();
+        let info = func.info();
+        assert_eq!(info.arg_count(), 1);
+        assert_eq!(info.args()[0].ownership(), Ownership::Ref);
+        assert_eq!(info.args()[0].type_info().kind(), ReflectKind::Opaque);
+        assert_eq!(info.args()[0].ty().id(), TypeId::of::<String>());
+
+        let name = String::from("vct");
+        let result = func.call(ArgList::new().with_ref(&name)).unwrap();
+        assert_eq!(result.take::<String>().unwrap(), "hello vct");
+
+        let err = func.call(ArgList::new().with_ref(&1u32)).err().unwrap();
+        assert!(matches!(err, FunctionError::ArgError(ArgError::InvalidType { index: 0, .. })));
+        let err = func.call(ArgList::new()).err().unwrap();
+        assert_eq!(err, FunctionError::ArgCountMismatch { expected: 1, received: 0 });
+
+        let mut count = 0u32;
+        let mut counter = (|step: u32| {
+            count += step;
+        })
+        .into_function_mut();
+        counter.call(ArgList::new().with_owned(2u32)).unwrap();
+        counter.call_once(ArgList::new().with_owned(3u32)).unwrap();
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn call_method() {
+        let mut registry = TypeRegistry::new();
+        registry.register_method::<Player, _>("heal", Player::heal);
+        let heal = registry.get_method(TypeId::of::<Player>(), "heal").unwrap();
+
+        let info = heal.info();
+        assert_eq!(info.args()[0].ownership(), Ownership::Mut);
+        assert_eq!(info.args()[0].type_info().kind(), ReflectKind::Struct);
+        let expected = format!("fn heal(&mut {}, f32) -> f32", Player::type_path());
+        assert_eq!(format!("{heal:?}"), format!("DynamicFunction({expected})"));
+
+        let mut player = Player { health: 1.0 };
+        let result = heal.call(ArgList::new().with_mut(&mut player).with_owned(2.0f32)).unwrap();
+        assert_eq!(result.take::<f32>().unwrap(), 3.0);
+        assert_eq!(player.health, 3.0);
+
+        let err = heal.call(ArgList::new().with_ref(&player).with_owned(2.0f32)).err().unwrap();
+        let expected = ArgError::InvalidOwnership {
+            index: 0,
+            expected: Ownership::Mut,
+            received: Ownership::Ref,
+        };
+        assert_eq!(err, FunctionError::ArgError(expected));
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::{borrow::Cow, boxed::Box};
+use core::{error, fmt};
+
+use crate::{Reflect, func::Ownership};
+
+/// The result of calling a [`DynamicFunction`](crate::func::DynamicFunction).
+pub type FunctionResult = Result<Box<dyn Reflect>, FunctionError>;
+
+/// An error that occurs when converting an [`Arg`](crate::func::Arg).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    /// The argument is not the expected type.
+    InvalidType {
+        index: usize,
+        expected: Cow<'static, str>,
+        received: Cow<'static, str>,
+    },
+    /// The argument has the wrong ownership, e.g. a reference is passed for `&mut T`.
+    InvalidOwnership {
+        index: usize,
+        expected: Ownership,
+        received: Ownership,
+    },
+    /// Attempted to take an argument from an empty [`ArgList`](crate::func::ArgList).
+    EmptyArgList,
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType { index, expected, received } => {
+                write!(f, "expected `{expected}` for argument {index}, but received `{received}`")
+            },
+            Self::InvalidOwnership { index, expected, received } => {
+                write!(f, "expected {expected} for argument {index}, but received {received}")
+            },
+            Self::EmptyArgList => {
+                write!(f, "expected an argument, but the argument list is empty")
+            },
+        }
+    }
+}
+
+impl error::Error for ArgError {}
+
+/// An error that occurs when calling a [`DynamicFunction`](crate::func::DynamicFunction).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionError {
+    /// An argument could not be converted.
+    ArgError(ArgError),
+    /// The number of arguments does not match.
+    ArgCountMismatch { expected: usize, received: usize },
+}
+
+impl fmt::Display for FunctionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ArgError(err) => fmt::Display::fmt(err, f),
+            Self::ArgCountMismatch { expected, received } => {
+                write!(f, "expected {expected} arguments, but received {received}")
+            },
+        }
+    }
+}
+
+impl error::Error for FunctionError {}
+
+impl From<ArgError> for FunctionError {
+    #[inline]
+    fn from(value: ArgError) -> Self {
+        Self::ArgError(value)
+    }
+}
@@ -0,0 +1,131 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    func::{FromArg, Ownership},
+    info::{Type, TypeInfo, TypePath},
+};
+
+/// Information about an argument of a function.
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    ownership: Ownership,
+    type_info: &'static TypeInfo,
+}
+
+impl ArgInfo {
+    /// Creates a new [`ArgInfo`] for the argument type `T` (such as `&Foo`).
+    #[inline]
+    pub fn new<T: FromArg<Marker>, Marker>(index: usize) -> Self {
+        Self {
+            index,
+            ownership: T::OWNERSHIP,
+            type_info: T::arg_type_info(),
+        }
+    }
+
+    /// Returns the index of the argument.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the [`Ownership`] of the argument.
+    #[inline]
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// Returns the [`TypeInfo`] of the argument (without reference).
+    #[inline]
+    pub fn type_info(&self) -> &'static TypeInfo {
+        self.type_info
+    }
+
+    /// Returns the [`Type`] of the argument (without reference).
+    #[inline]
+    pub fn ty(&self) -> &'static Type {
+        self.type_info.ty()
+    }
+
+    /// See [`TypePath::type_path`].
+    #[inline]
+    pub fn type_path(&self) -> &'static str {
+        self.type_info.type_path()
+    }
+}
+
+/// Information about a function, such as the name, arguments and return type.
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Vec<ArgInfo>,
+    return_type: Type,
+}
+
+impl FunctionInfo {
+    /// Creates a new [`FunctionInfo`] without arguments, the return type is `()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            args: Vec::new(),
+            return_type: Type::of::<()>(),
+        }
+    }
+
+    /// Sets the name of the function.
+    #[inline]
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Appends an argument of type `T` (such as `&Foo`).
+    #[inline]
+    pub fn with_arg<T: FromArg<Marker>, Marker>(mut self) -> Self {
+        self.args.push(ArgInfo::new::<T, Marker>(self.args.len()));
+        self
+    }
+
+    /// Sets the return type of the function.
+    #[inline]
+    pub fn with_return<T: TypePath>(mut self) -> Self {
+        self.return_type = Type::of::<T>();
+        self
+    }
+
+    /// Returns the name of the function.
+    ///
+    /// Functions created from a fn item use the full path of it by default,
+    /// closures have no name.
+    #[inline]
+    pub fn name(&self) -> Option<&Cow<'static, str>> {
+        self.name.as_ref()
+    }
+
+    /// Returns the arguments of the function.
+    #[inline]
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// Returns the number of arguments.
+    #[inline]
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns the return [`Type`] of the function.
+    #[inline]
+    pub fn return_type(&self) -> &Type {
+        &self.return_type
+    }
+}
+
+impl Default for FunctionInfo {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
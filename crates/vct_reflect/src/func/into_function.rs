@@ -0,0 +1,139 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{
+    Reflect,
+    func::{ArgList, DynamicFunction, DynamicFunctionMut, FromArg, FunctionInfo},
+    info::TypePath,
+};
+
+/// A trait for types that can be converted into a [`DynamicFunction`].
+///
+/// It's implemented for `Fn` functions and closures with up to 8 arguments,
+/// the arguments must implement [`FromArg`], and the return type must be `Reflect + TypePath`.
+///
+/// The `Marker` is only used to distinguish the impls, it can be inferred.
+pub trait IntoFunction<'env, Marker> {
+    /// Converts into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction<'env>;
+}
+
+/// A trait for types that can be converted into a [`DynamicFunctionMut`].
+///
+/// It's implemented for `FnMut` functions and closures with up to 8 arguments,
+/// see [`IntoFunction`] for details.
+pub trait IntoFunctionMut<'env, Marker> {
+    /// Converts into a [`DynamicFunctionMut`].
+    fn into_function_mut(self) -> DynamicFunctionMut<'env>;
+}
+
+impl<'env> IntoFunction<'env, ()> for DynamicFunction<'env> {
+    #[inline]
+    fn into_function(self) -> DynamicFunction<'env> {
+        self
+    }
+}
+
+impl<'env> IntoFunctionMut<'env, ()> for DynamicFunction<'env> {
+    #[inline]
+    fn into_function_mut(self) -> DynamicFunctionMut<'env> {
+        self.into()
+    }
+}
+
+impl<'env> IntoFunctionMut<'env, ()> for DynamicFunctionMut<'env> {
+    #[inline]
+    fn into_function_mut(self) -> DynamicFunctionMut<'env> {
+        self
+    }
+}
+
+/// Creates the [`FunctionInfo`] without arguments.
+///
+/// fn items are named by their full path, closures have no name.
+fn function_info<F, R: TypePath>() -> FunctionInfo {
+    let info = FunctionInfo::new().with_return::<R>();
+    let name = core::any::type_name::<F>();
+    if name.contains("{{closure}}") {
+        info
+    } else {
+        info.with_name(Cow::Borrowed(name))
+    }
+}
+
+macro_rules! impl_into_function {
+    ($(($arg:ident, $Arg:ident, $Marker:ident)),*) => {
+        impl<'env, F, R, $($Arg, $Marker,)*> IntoFunction<'env, (fn($($Arg,)*) -> R, ($($Marker,)*))> for F
+        where
+            $($Arg: FromArg<$Marker>,)*
+            R: Reflect + TypePath,
+            F: Fn($($Arg,)*) -> R + Send + Sync + 'env,
+            F: for<'a> Fn($(<$Arg as FromArg<$Marker>>::This<'a>,)*) -> R,
+        {
+            #[allow(unused_mut, unused_variables, reason = "functions without arguments do not take from the list")]
+            fn into_function(self) -> DynamicFunction<'env> {
+                let info = function_info::<F, R>()$(.with_arg::<$Arg, $Marker>())*;
+                DynamicFunction::new(
+                    move |mut args: ArgList<'_>| {
+                        $(let $arg = <$Arg as FromArg<$Marker>>::from_arg(args.take_arg()?)?;)*
+                        Ok(Box::new(self($($arg,)*)) as Box<dyn Reflect>)
+                    },
+                    info,
+                )
+            }
+        }
+
+        impl<'env, F, R, $($Arg, $Marker,)*> IntoFunctionMut<'env, (fn($($Arg,)*) -> R, ($($Marker,)*))> for F
+        where
+            $($Arg: FromArg<$Marker>,)*
+            R: Reflect + TypePath,
+            F: FnMut($($Arg,)*) -> R + Send + Sync + 'env,
+            F: for<'a> FnMut($(<$Arg as FromArg<$Marker>>::This<'a>,)*) -> R,
+        {
+            #[allow(unused_mut, unused_variables, reason = "functions without arguments do not take from the list")]
+            fn into_function_mut(mut self) -> DynamicFunctionMut<'env> {
+                let info = function_info::<F, R>()$(.with_arg::<$Arg, $Marker>())*;
+                DynamicFunctionMut::new(
+                    move |mut args: ArgList<'_>| {
+                        $(let $arg = <$Arg as FromArg<$Marker>>::from_arg(args.take_arg()?)?;)*
+                        Ok(Box::new(self($($arg,)*)) as Box<dyn Reflect>)
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+impl_into_function!();
+impl_into_function!((a0, A0, M0));
+impl_into_function!((a0, A0, M0), (a1, A1, M1));
+impl_into_function!((a0, A0, M0), (a1, A1, M1), (a2, A2, M2));
+impl_into_function!((a0, A0, M0), (a1, A1, M1), (a2, A2, M2), (a3, A3, M3));
+impl_into_function!((a0, A0, M0), (a1, A1, M1), (a2, A2, M2), (a3, A3, M3), (a4, A4, M4));
+impl_into_function!(
+    (a0, A0, M0),
+    (a1, A1, M1),
+    (a2, A2, M2),
+    (a3, A3, M3),
+    (a4, A4, M4),
+    (a5, A5, M5)
+);
+impl_into_function!(
+    (a0, A0, M0),
+    (a1, A1, M1),
+    (a2, A2, M2),
+    (a3, A3, M3),
+    (a4, A4, M4),
+    (a5, A5, M5),
+    (a6, A6, M6)
+);
+impl_into_function!(
+    (a0, A0, M0),
+    (a1, A1, M1),
+    (a2, A2, M2),
+    (a3, A3, M3),
+    (a4, A4, M4),
+    (a5, A5, M5),
+    (a6, A6, M6),
+    (a7, A7, M7)
+);
@@ -0,0 +1,44 @@
+//! Reflection for functions and methods.
+//!
+//! Ordinary Rust functions and closures can be converted into a [`DynamicFunction`]
+//! (or [`DynamicFunctionMut`] for `FnMut`), which can be called with an [`ArgList`]
+//! of reflected arguments, and returns the reflected value.
+//!
+//! - Arguments can be owned (`T: FromReflect`), references (`&T`) or mutable references (`&mut T`).
+//! - The return value must be owned (`R: Reflect`), `()` is used for no return value.
+//! - Methods are functions with the receiver as the first argument, such as `Player::heal`.
+//!
+//! Functions can be registered in [`TypeRegistry`] by name,
+//! and methods can be registered for a type, see [`TypeRegistry::register_method`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! impl Player {
+//!     fn heal(&mut self, amount: f32) { /* ... */ }
+//! }
+//!
+//! registry.register_method::<Player, _>("heal", Player::heal);
+//!
+//! let heal = registry.get_method(TypeId::of::<Player>(), "heal").unwrap();
+//! let args = ArgList::new().with_mut(&mut player).with_owned(10.0_f32);
+//! heal.call(args)?;
+//! ```
+//!
+//! [`TypeRegistry`]: crate::registry::TypeRegistry
+//! [`TypeRegistry::register_method`]: crate::registry::TypeRegistry::register_method
+
+mod args;
+pub use args::{Arg, ArgList, ArgValue, FromArg, MutArg, OwnedArg, Ownership, RefArg};
+
+mod error;
+pub use error::{ArgError, FunctionError, FunctionResult};
+
+mod info;
+pub use info::{ArgInfo, FunctionInfo};
+
+mod dynamic_function;
+pub use dynamic_function::{DynamicFunction, DynamicFunctionMut};
+
+mod into_function;
+pub use into_function::{IntoFunction, IntoFunctionMut};
@@ -0,0 +1,287 @@
+//! Implement read-only pass-through reflection traits for [`Arc<T>`].
+//!
+//! The content of an `Arc<T>` is shared and cannot be borrowed mutably,
+//! so `Arc<T>` is reflected as the `T` it contains, but only for reading:
+//!
+//! - [`Typed`] returns the info of `T` with the type of `Arc<T>`.
+//! - [`Reflect::reflect_kind`] and [`Reflect::reflect_ref`] return the ones of the inner value,
+//!   so the fields of `T` can be inspected and accessed by path.
+//! - [`Reflect::reflect_mut`] and [`Reflect::reflect_owned`] return the `Arc` itself as `Opaque`.
+//! - [`Reflect::reflect_partial_eq`], [`Reflect::reflect_hash`] and [`Reflect::reflect_debug`]
+//!   are forwarded to the inner value.
+//! - [`Reflect::try_apply`] always returns [`ApplyError::NotSupport`].
+//! - [`Reflect::set`] and [`Reflect::reflect_clone`] work on the `Arc` handle,
+//!   the content is not cloned.
+//!
+//! [`GetTypeTraits`] forwards the serde traits of `T`, so an `Arc<T>` is serialized
+//! as its content, and [`FromReflect`] creates a new `Arc` for the converted content.
+//!
+//! `Rc<T>` is not reflected, because [`Reflect`] requires `Send + Sync`.
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{boxed::Box, sync::Arc};
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{ReflectKind, Type, TypeInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDeserialize, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+
+/// If `value` is a `Arc<T>`, returns the inner `T`, otherwise returns `value` itself.
+#[inline]
+fn unwrap_arc<T: Reflect + Typed>(value: &dyn Reflect) -> &dyn Reflect {
+    match value.downcast_ref::<Arc<T>>() {
+        Some(arc) => arc.as_ref(),
+        None => value,
+    }
+}
+
+impl<T: TypePath + ?Sized> TypePath for Arc<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["alloc::sync::Arc<", T::type_path(), ">"]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["Arc<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Arc"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::sync")
+    }
+}
+
+impl<T: Reflect + Typed> Typed for Arc<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| T::type_info().with_type(Type::of::<Self>()))
+    }
+}
+
+impl<T: Reflect + Typed> Reflect for Arc<T> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        (**self).reflect_kind()
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        (**self).reflect_ref()
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, _value: &dyn Reflect) -> Result<(), ApplyError> {
+        Err(ApplyError::NotSupport {
+            type_path: Self::type_path().into(),
+        })
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(Arc::clone(self)))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        (**self).reflect_partial_eq(unwrap_arc::<T>(other))
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        (**self).reflect_partial_cmp(unwrap_arc::<T>(other))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        (**self).reflect_hash()
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).reflect_debug(f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits> GetTypeTraits for Arc<T> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+
+        let inner = T::get_type_traits();
+        if let Some(serialize) = inner.get::<TypeTraitSerialize>() {
+            type_traits.insert::<TypeTraitSerialize>(serialize.for_wrapper(unwrap_arc::<T>));
+        }
+        if let Some(deserialize) = inner.get::<TypeTraitDeserialize>() {
+            let deserialize = deserialize.for_wrapper(|value| match value.downcast::<T>() {
+                Ok(value) => Box::new(Arc::new(*value)),
+                Err(value) => value,
+            });
+            type_traits.insert::<TypeTraitDeserialize>(deserialize);
+        }
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed> FromReflect for Arc<T> {
+    /// Shares the content if `reflect` is a `Arc<T>`, otherwise creates a new `Arc`.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(arc) = reflect.downcast_ref::<Self>() {
+            return Some(Arc::clone(arc));
+        }
+        T::from_reflect(reflect).map(Arc::new)
+    }
+
+    /// Like [`from_reflect`](Self::from_reflect), reports why the content can't be converted.
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+        if let Some(arc) = reflect.downcast_ref::<Self>() {
+            return Ok(Arc::clone(arc));
+        }
+        T::try_from_reflect(reflect).map(Arc::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, sync::Arc, vec, vec::Vec};
+    use core::any::TypeId;
+    use serde::de::DeserializeSeed;
+
+    use crate::{
+        FromReflect, Reflect,
+        access::ReflectPathAccess,
+        info::{ReflectKind, TypePath, Typed},
+        ops::{ApplyError, ReflectMut, ReflectRef},
+        registry::{TypeRegistry, TypeTraitFromReflect},
+        serde::{ReflectDeserializer, ReflectSerializer},
+    };
+
+    type Shared = (Arc<u32>, Arc<Vec<u32>>);
+
+    #[test]
+    fn arc_is_read_only_pass_through() {
+        let mut value = Arc::new(Some(1u32));
+        assert_eq!(value.reflect_kind(), ReflectKind::Enum);
+        assert_eq!(<Arc<Option<u32>>>::type_info().kind(), ReflectKind::Enum);
+        assert!(matches!(value.reflect_ref(), ReflectRef::Enum(_)));
+        assert!(matches!(value.reflect_mut(), ReflectMut::Opaque(_)));
+        assert!(value.as_reflect().is::<Arc<Option<u32>>>());
+        assert!(value.as_reflect_mut().is::<Arc<Option<u32>>>());
+
+        assert_eq!(value.reflect_partial_eq(&Some(1u32)), Some(true));
+        assert_eq!(value.reflect_hash(), Some(1u32).reflect_hash());
+
+        let result = value.try_apply(&Some(2u32));
+        assert!(matches!(result, Err(ApplyError::NotSupport { .. })));
+
+        let cloned = value.reflect_clone().unwrap().take::<Arc<Option<u32>>>().unwrap();
+        assert!(Arc::ptr_eq(&value, &cloned));
+    }
+
+    #[test]
+    fn arc_fields_by_path() {
+        let value: Shared = (Arc::new(1), Arc::new(vec![2, 3]));
+        assert_eq!(value.access_as::<u32>(".1[1]").ok(), Some(&3));
+        let first = value.access(".0").unwrap();
+        assert_eq!(first.reflect_partial_eq(&1u32), Some(true));
+    }
+
+    #[test]
+    fn arc_serde_round_trip() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Shared>();
+
+        let value: Shared = (Arc::new(1), Arc::new(vec![3]));
+        let json = serde_json::to_value(ReflectSerializer::new(&value, &registry)).unwrap();
+        let path = Shared::type_path();
+        assert_eq!(json, serde_json::json!({ path: [1, [3]] }));
+
+        let output = ReflectDeserializer::new(&registry).deserialize(&json).unwrap();
+        assert_eq!(Shared::from_reflect(&*output), Some(value));
+    }
+
+    #[test]
+    fn arc_registers_own_type_traits() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Arc<String>>();
+
+        let type_traits = registry.get_with_type_path(<Arc<String>>::type_path()).unwrap();
+        assert_eq!(type_traits.type_id(), TypeId::of::<Arc<String>>());
+        assert!(registry.contains(TypeId::of::<String>()));
+
+        let from_reflect = type_traits.get::<TypeTraitFromReflect>().unwrap();
+        let value = from_reflect.from_reflect(&String::from("shared")).unwrap();
+        let value = value.take::<Arc<String>>().unwrap();
+        assert_eq!(value.as_str(), "shared");
+        let shared = <Arc<String>>::from_reflect(&value).unwrap();
+        assert!(Arc::ptr_eq(&shared, &value));
+    }
+}
@@ -0,0 +1,257 @@
+//! Implement reflection traits for fixed-size arrays `[T; N]`.
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Array`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{boxed::Box, format, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    access::Accessor,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    info::{
+        ArrayInfo, ConstParamInfo, GenericInfo, Generics, ReflectKind, TypeInfo, TypeParamInfo,
+        TypePath, Typed,
+    },
+    ops::{
+        ApplyError, Array, ArrayItemIter, ReflectCloneError, ReflectMut, ReflectOwned,
+        ReflectRef, array_debug, array_hash, array_partial_cmp, array_partial_eq, array_try_apply,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+impl<T: TypePath, const N: usize> TypePath for [T; N] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_path()))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_name()))
+    }
+
+    fn type_ident() -> &'static str {
+        <Self as TypePath>::type_name()
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> Typed for [T; N] {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let info = ArrayInfo::new::<Self, T>(N).with_generics(Generics::from_iter([
+                GenericInfo::Type(TypeParamInfo::new::<T>("T")),
+                GenericInfo::Const(ConstParamInfo::new::<usize>("N")),
+            ]));
+            TypeInfo::Array(info)
+        })
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> Array for [T; N] {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        <[T]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn iter(&self) -> ArrayItemIter<'_> {
+        ArrayItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        IntoIterator::into_iter(*self)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> Reflect for [T; N] {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Array
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Array(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Array(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Array(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        array_try_apply(self, value)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let mut values = Vec::with_capacity(N);
+        for value in self {
+            values.push(
+                value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"),
+            );
+        }
+        let Ok(array) = <[T; N]>::try_from(values) else {
+            unreachable!("the number of elements is always `N`");
+        };
+        Ok(Box::new(array))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        array_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, other)
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        array_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        array_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits, const N: usize> GetTypeTraits for [T; N] {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed, const N: usize> FromReflect for [T; N] {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Self::try_from_reflect(reflect).ok()
+    }
+
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+        let ReflectRef::Array(ref_array) = reflect.reflect_ref() else {
+            return Err(FromReflectError::mismatched::<Self>(reflect));
+        };
+        if ref_array.len() != N {
+            return Err(FromReflectError::different_size::<Self>(N, ref_array.len()));
+        }
+
+        let mut values = Vec::with_capacity(N);
+        for (index, item) in ref_array.iter().enumerate() {
+            let item = T::try_from_reflect(item)
+                .map_err(|err| err.with_accessor(Accessor::ListIndex(index)))?;
+            values.push(item);
+        }
+        match <[T; N]>::try_from(values) {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("the length has been checked"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, Typed},
+        ops::{Array, DynamicArray},
+    };
+
+    #[test]
+    fn array_reflection() {
+        let mut value = [1u8, 2, 3];
+        assert_eq!(value.reflect_kind(), ReflectKind::Array);
+        assert_eq!(Array::len(&value), 3);
+        assert_eq!(<[u8; 3]>::type_info().as_array().unwrap().capacity(), 3);
+
+        *Array::get_mut(&mut value, 0).unwrap().downcast_mut::<u8>().unwrap() = 9;
+        assert_eq!(value, [9, 2, 3]);
+
+        value.apply(&[4u8, 5, 6]);
+        assert_eq!(value, [4, 5, 6]);
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let value = [1u16, 2];
+        let dynamic = value.to_dynamic();
+        assert_eq!(dynamic.reflect_partial_eq(&value), Some(true));
+        assert_eq!(<[u16; 2]>::from_reflect(&*dynamic), Some(value));
+
+        let dynamic = DynamicArray::from_iter([3u16, 4]);
+        assert_eq!(<[u16; 2]>::from_reflect(&dynamic), Some([3, 4]));
+        assert_eq!(<[u16; 2]>::from_reflect(&[5u16, 6, 7]), None);
+        assert_eq!(<[u16; 2]>::from_reflect(&vec![5u16, 6]), None);
+    }
+}
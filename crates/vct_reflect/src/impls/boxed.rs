@@ -0,0 +1,301 @@
+//! Implement pass-through reflection traits for [`Box<T>`].
+//!
+//! `Box<T>` is reflected as the `T` it contains: all [`Reflect`] methods are forwarded
+//! to the inner value, and [`Typed`] returns the info of `T` with the type of `Box<T>`.
+//! [`Reflect::into_reflect`] keeps the `Box<T>`, but [`Reflect::reflect_owned`] returns
+//! the owned kind of `T`, as `Box<T>` does not implement the kind traits itself.
+//!
+//! [`GetTypeTraits`] registers the type traits that build a `Box<T>` (`TypeTraitFromPtr`
+//! and `TypeTraitFromReflect`), and forwards the serde traits of `T`, so a `Box<T>`
+//! is serialized as its content.
+//!
+//! Note that [`Any::type_id`](core::any::Any::type_id) still returns the id of `Box<T>`,
+//! so `downcast_ref::<T>` on a `&dyn Reflect` pointing at a `Box<T>` fails.
+//! [`Reflect::as_reflect`] can be used to obtain the inner `T`.
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::boxed::Box;
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{ReflectKind, Type, TypeInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDeserialize, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+
+/// If `value` is a `Box<T>`, returns the inner `T`, otherwise returns `value` itself.
+#[inline]
+fn unbox<T: Reflect + Typed>(value: &dyn Reflect) -> &dyn Reflect {
+    match value.downcast_ref::<Box<T>>() {
+        Some(boxed) => boxed.as_ref(),
+        None => value,
+    }
+}
+
+impl<T: TypePath + ?Sized> TypePath for Box<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["alloc::boxed::Box<", T::type_path(), ">"]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["Box<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Box"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::boxed")
+    }
+}
+
+impl<T: Reflect + Typed> Typed for Box<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| T::type_info().with_type(Type::of::<Self>()))
+    }
+}
+
+impl<T: Reflect + Typed> Reflect for Box<T> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        (**self).as_reflect()
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        (**self).as_reflect_mut()
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        match value.downcast::<Self>() {
+            Ok(value) => {
+                *self = *value;
+                Ok(())
+            }
+            Err(value) => (**self).set(value),
+        }
+    }
+
+    #[inline]
+    fn is_dynamic(&self) -> bool {
+        (**self).is_dynamic()
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        if (**self).is_dynamic() {
+            (**self).represented_type_info()
+        } else {
+            Some(Self::type_info())
+        }
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        (**self).reflect_kind()
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        (**self).reflect_ref()
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        (**self).reflect_mut()
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        <T as Reflect>::reflect_owned(*self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        (**self).to_dynamic()
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        (**self).try_apply(unbox::<T>(value))
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let value = (**self)
+            .reflect_clone()?
+            .take::<T>()
+            .expect("`Reflect::reflect_clone` should return the same type");
+        Ok(Box::new(Box::new(value)))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        (**self).reflect_partial_eq(unbox::<T>(other))
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        (**self).reflect_partial_cmp(unbox::<T>(other))
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        (**self).reflect_hash()
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).reflect_debug(f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits> GetTypeTraits for Box<T> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+
+        let inner = T::get_type_traits();
+        if let Some(serialize) = inner.get::<TypeTraitSerialize>() {
+            type_traits.insert::<TypeTraitSerialize>(serialize.for_wrapper(unbox::<T>));
+        }
+        if let Some(deserialize) = inner.get::<TypeTraitDeserialize>() {
+            let deserialize = deserialize.for_wrapper(|value| match value.downcast::<T>() {
+                Ok(value) => Box::new(value),
+                Err(value) => value,
+            });
+            type_traits.insert::<TypeTraitDeserialize>(deserialize);
+        }
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed> FromReflect for Box<T> {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        T::from_reflect(unbox::<T>(reflect)).map(Box::new)
+    }
+
+    #[inline]
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+        T::try_from_reflect(unbox::<T>(reflect)).map(Box::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::String};
+    use core::any::TypeId;
+    use serde::de::DeserializeSeed;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, TypePath, Typed},
+        registry::{TypeRegistry, TypeTraitFromPtr, TypeTraitFromReflect},
+        serde::{ReflectDeserializer, ReflectSerializer},
+    };
+
+    #[test]
+    fn box_is_pass_through() {
+        let boxed: Box<Option<u32>> = Box::new(Some(2));
+        assert_eq!(boxed.reflect_kind(), ReflectKind::Enum);
+        assert_eq!(boxed.as_reflect().downcast_ref::<Option<u32>>(), Some(&Some(2)));
+        assert_eq!(boxed.reflect_partial_eq(&Some(2u32)), Some(true));
+
+        let info = <Box<Option<u32>>>::type_info();
+        assert_eq!(info.kind(), ReflectKind::Enum);
+        assert_eq!(info.type_id(), TypeId::of::<Box<Option<u32>>>());
+
+        let converted = <Box<Option<u32>>>::from_reflect(&*boxed.to_dynamic());
+        assert_eq!(converted, Some(Box::new(Some(2))));
+    }
+
+    #[test]
+    fn box_registers_own_type_traits() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Box<Option<u32>>>();
+        assert!(registry.contains(TypeId::of::<Option<u32>>()));
+
+        let type_traits = registry.get(TypeId::of::<Box<Option<u32>>>()).unwrap();
+        assert_eq!(type_traits.type_id(), TypeId::of::<Box<Option<u32>>>());
+        assert_eq!(
+            type_traits.get::<TypeTraitFromPtr>().unwrap().type_id(),
+            TypeId::of::<Box<Option<u32>>>(),
+        );
+
+        let from_reflect = type_traits.get::<TypeTraitFromReflect>().unwrap();
+        let value = from_reflect.from_reflect(&Some(4u32)).unwrap();
+        assert_eq!(value.take::<Box<Option<u32>>>().ok(), Some(Box::new(Some(4))));
+
+        let path = <Box<Option<u32>>>::type_path();
+        let by_path = registry.get_with_type_path(path).unwrap();
+        assert_eq!(by_path.type_id(), TypeId::of::<Box<Option<u32>>>());
+    }
+
+    #[test]
+    fn box_type_info_agrees() {
+        let boxed: Box<Option<u32>> = Box::new(Some(2));
+        let info = boxed.represented_type_info().unwrap();
+        assert_eq!(info.type_id(), TypeId::of::<Box<Option<u32>>>());
+        assert_eq!(boxed.as_reflect().reflect_kind(), ReflectKind::Enum);
+
+        let reflect = Box::new(boxed).into_reflect();
+        assert_eq!(reflect.downcast_ref::<Box<Option<u32>>>(), Some(&Box::new(Some(2))));
+    }
+
+    #[test]
+    fn box_serde_round_trip() {
+        type Pair = (Box<String>, Box<u32>);
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Pair>();
+
+        let value: Pair = (Box::new(String::from("x")), Box::new(3u32));
+        let json = serde_json::to_value(ReflectSerializer::new(&value, &registry)).unwrap();
+        let path = Pair::type_path();
+        assert_eq!(json, serde_json::json!({ path: ["x", 3] }));
+
+        let output = ReflectDeserializer::new(&registry).deserialize(&json).unwrap();
+        assert_eq!(Pair::from_reflect(&*output), Some(value));
+
+        let boxed = Box::new(String::from("y"));
+        let json = serde_json::to_value(ReflectSerializer::new(&boxed, &registry)).unwrap();
+        let output = ReflectDeserializer::new(&registry).deserialize(&json).unwrap();
+        assert_eq!(<Box<String>>::from_reflect(&*output), Some(boxed));
+    }
+}
@@ -0,0 +1,479 @@
+//! Implement reflection traits for [`Cow<'static, T>`].
+//!
+//! - `Cow<'static, str>`: reflected as `Opaque`.
+//! - `Cow<'static, [T]>`: reflected as `List`, mutations clone the data into the owned form.
+//!
+//! [`FromReflect`] always creates the owned form ([`Cow::Owned`]) except for `Cow<'static, str>`,
+//! the borrowed form is only created by [`Reflect::reflect_clone`] of a borrowed `Cow`,
+//! or by [`TypeTraitDeserializeBorrowed`] for `Cow<'static, str>`.
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`List`] (only `Cow<'static, [T]>`)
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::String,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    access::Accessor,
+    cell::{GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell},
+    impls::concat,
+    info::{
+        Generics, ListInfo, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed,
+    },
+    ops::{
+        ApplyError, List, ListItemIter, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        list_debug, list_hash, list_partial_cmp, list_partial_eq, list_try_apply,
+    },
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitDeserialize,
+        TypeTraitDeserializeBorrowed, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraitSerialize,
+        TypeTraits,
+    },
+};
+use serde::{
+    Deserializer,
+    de::{self, Visitor},
+};
+
+impl<T: TypePath + ToOwned + ?Sized> TypePath for Cow<'static, T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["alloc::borrow::Cow<", T::type_path(), ">"]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["Cow<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Cow"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::borrow")
+    }
+}
+
+impl Typed for Cow<'static, str> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl Reflect for Cow<'static, str> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            self.clone_from(value);
+        } else if let Some(value) = value.downcast_ref::<String>() {
+            *self = Cow::Owned(value.clone());
+        } else {
+            return Err(ApplyError::MismatchedTypes {
+                from_type: value.reflect_type_path().to_owned().into(),
+                to_type: Self::type_path().into(),
+            });
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(self.clone()))
+    }
+
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        if let Some(other) = other.downcast_ref::<Self>() {
+            Some(self == other)
+        } else {
+            other
+                .downcast_ref::<String>()
+                .map(|other| self.as_ref() == other.as_str())
+        }
+    }
+
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        if let Some(other) = other.downcast_ref::<Self>() {
+            Some(Ord::cmp(self, other))
+        } else {
+            other
+                .downcast_ref::<String>()
+                .map(|other| Ord::cmp(self.as_ref(), other.as_str()))
+        }
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        // Same as `String`, so that equal contents have the same hash.
+        let mut hasher = reflect_hasher();
+        Hash::hash(&**self, &mut hasher);
+        Some(hasher.finish())
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl GetTypeTraits for Cow<'static, str> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitSerialize>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDeserialize>(FromType::<Self>::from_type());
+        type_traits.insert(TypeTraitDeserializeBorrowed::new(deserialize_borrowed_cow_str));
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(_registry: &mut TypeRegistry) {}
+}
+
+/// Deserializes `Cow<'static, str>`, the `Deserialize` impl of `Cow` always copies.
+fn deserialize_borrowed_cow_str(
+    deserializer: &mut dyn erased_serde::Deserializer<'static>,
+) -> Result<Box<dyn Reflect>, erased_serde::Error> {
+    struct CowStrVisitor;
+
+    impl Visitor<'static> for CowStrVisitor {
+        type Value = Cow<'static, str>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string")
+        }
+
+        #[inline]
+        fn visit_borrowed_str<E: de::Error>(self, v: &'static str) -> Result<Self::Value, E> {
+            Ok(Cow::Borrowed(v))
+        }
+
+        #[inline]
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v.to_owned()))
+        }
+
+        #[inline]
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v))
+        }
+    }
+
+    Ok(Box::new(deserializer.deserialize_str(CowStrVisitor)?))
+}
+
+impl FromReflect for Cow<'static, str> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let Some(value) = reflect.downcast_ref::<Self>() {
+            // Keeps the borrowed form, it's `'static`.
+            Some(value.clone())
+        } else {
+            reflect
+                .downcast_ref::<String>()
+                .map(|value| Cow::Owned(value.clone()))
+        }
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> Typed for Cow<'static, [T]> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let info = ListInfo::new::<Self, T>()
+                .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")]));
+            TypeInfo::List(info)
+        })
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> List for Cow<'static, [T]> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.to_mut()
+            .get_mut(index)
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+        let value = T::take_from_reflect(element).unwrap_or_else(|value| {
+            panic!(
+                "Attempted to insert invalid value of type `{}` into list of `{}`.",
+                value.reflect_type_path(),
+                T::type_path(),
+            )
+        });
+        self.to_mut().insert(index, value);
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        Box::new(self.to_mut().remove(index))
+    }
+
+    #[inline]
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        let len = <[T]>::len(self);
+        List::insert(self, len, value);
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.to_mut()
+            .pop()
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn iter(&self) -> ListItemIter<'_> {
+        ListItemIter::new(self)
+    }
+
+    #[inline]
+    fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+        self.to_mut()
+            .drain(..)
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+            .collect()
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> Reflect for Cow<'static, [T]> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::List
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    #[inline]
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        list_try_apply(self, value)
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        if let Cow::Borrowed(slice) = self {
+            return Ok(Box::new(Cow::<'static, [T]>::Borrowed(slice)));
+        }
+        let mut list = Vec::with_capacity(<[T]>::len(self));
+        for value in self.iter() {
+            list.push(
+                value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"),
+            );
+        }
+        Ok(Box::new(Cow::<'static, [T]>::Owned(list)))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        list_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        list_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        list_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + Clone + GetTypeTraits> GetTypeTraits for Cow<'static, [T]> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed + Clone> FromReflect for Cow<'static, [T]> {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Self::try_from_reflect(reflect).ok()
+    }
+
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+        let ReflectRef::List(ref_list) = reflect.reflect_ref() else {
+            return Err(FromReflectError::mismatched::<Self>(reflect));
+        };
+
+        let mut list = Vec::with_capacity(ref_list.len());
+        for (index, item) in ref_list.iter().enumerate() {
+            let item = T::try_from_reflect(item)
+                .map_err(|err| err.with_accessor(Accessor::ListIndex(index)))?;
+            list.push(item);
+        }
+        Ok(Cow::Owned(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::Cow, string::String, vec};
+
+    use crate::{FromReflect, Reflect, info::ReflectKind, ops::List};
+
+    #[test]
+    fn cow_str_matches_string() {
+        let borrowed: Cow<'static, str> = Cow::Borrowed("text");
+        let owned = String::from("text");
+        assert_eq!(borrowed.reflect_partial_eq(&owned), Some(true));
+        assert_eq!(borrowed.reflect_hash(), owned.reflect_hash());
+        assert_eq!(borrowed.reflect_hash(), "text".reflect_hash());
+
+        let converted = <Cow<'static, str>>::from_reflect(&owned).unwrap();
+        assert!(matches!(converted, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn cow_slice_is_list() {
+        static ITEMS: [u8; 2] = [1, 2];
+        let mut value: Cow<'static, [u8]> = Cow::Borrowed(&ITEMS);
+        assert_eq!(value.reflect_kind(), ReflectKind::List);
+        assert_eq!(List::len(&value), 2);
+
+        value.apply(&vec![3u8, 4]);
+        assert!(matches!(value, Cow::Owned(_)));
+        assert_eq!(&*value, &[3, 4]);
+
+        let converted = <Cow<'static, [u8]>>::from_reflect(&vec![5u8]).unwrap();
+        assert_eq!(&*converted, &[5]);
+    }
+}
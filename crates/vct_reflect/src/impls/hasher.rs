@@ -0,0 +1,43 @@
+//! Implement [`TypePath`] for hasher builders.
+//!
+//! Hasher builders are part of the type path of hash containers,
+//! e.g. `HashMap<K, V, S>`, so they only need [`TypePath`].
+
+use crate::info::TypePath;
+
+macro_rules! impl_type_path_for_hasher {
+    ($ty:ty, $ident:literal, $module_path:literal) => {
+        impl TypePath for $ty {
+            #[inline]
+            fn type_path() -> &'static str {
+                concat!($module_path, "::", $ident)
+            }
+
+            #[inline]
+            fn type_name() -> &'static str {
+                $ident
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                $ident
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some($module_path.split(':').next().unwrap())
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module_path)
+            }
+        }
+    };
+}
+
+impl_type_path_for_hasher!(vct_utils::hash::FixedHash, "FixedHash", "vct_utils::hash");
+impl_type_path_for_hasher!(vct_utils::hash::RandomState, "RandomState", "foldhash::fast");
+
+#[cfg(feature = "std")]
+impl_type_path_for_hasher!(std::hash::RandomState, "RandomState", "std::hash");
@@ -0,0 +1,398 @@
+//! Implement reflection traits for list-like containers.
+//!
+//! - [`Vec<T>`]
+//! - [`VecDeque<T>`]
+//! - `smallvec::SmallVec<A>` (requires `smallvec` feature)
+//!
+//! Implemented traits:
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`List`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    access::Accessor,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, ListInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, List, ListItemIter, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        list_debug, list_hash, list_partial_cmp, list_partial_eq, list_try_apply,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+/// Convert a boxed reflected value into list item.
+///
+/// # Panics
+///
+/// If the value cannot be converted to `T`.
+fn into_list_item<T: FromReflect + Typed>(value: Box<dyn Reflect>) -> T {
+    T::take_from_reflect(value).unwrap_or_else(|value| {
+        panic!(
+            "Attempted to insert invalid value of type `{}` into list of `{}`.",
+            value.reflect_type_path(),
+            T::type_path(),
+        )
+    })
+}
+
+macro_rules! impl_reflect_for_list {
+    (
+        impl<$param:ident $(: $param_bound:path)?> $ty:ty where [$($where:tt)*] {
+            item: $item:ty,
+            ident: $ident:literal,
+            module_path: $module_path:literal,
+            get: $get:expr,
+            get_mut: $get_mut:expr,
+            push: $push:expr,
+            pop: $pop:expr,
+            remove: $remove:expr,
+        }
+    ) => {
+        impl<$param $(: $param_bound)?> TypePath for $ty
+        where
+            $param: TypePath,
+        {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[$module_path, "::", $ident, "<", $param::type_path(), ">"])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[$ident, "<", $param::type_name(), ">"])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                $ident
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some($module_path.split(':').next().unwrap())
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module_path)
+            }
+        }
+
+        impl<$param $(: $param_bound)?> Typed for $ty
+        where
+            $param: TypePath,
+            $item: FromReflect + Typed,
+            $($where)*
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let info = ListInfo::new::<Self, $item>()
+                        .with_generics(Generics::from_iter([
+                            TypeParamInfo::new::<$param>(stringify!($param)),
+                        ]));
+                    TypeInfo::List(info)
+                })
+            }
+        }
+
+        impl<$param $(: $param_bound)?> List for $ty
+        where
+            $param: TypePath,
+            $item: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn get(&self, index: usize) -> Option<&dyn Reflect> {
+                ($get)(self, index).map(|value| value as &dyn Reflect)
+            }
+
+            #[inline]
+            fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                ($get_mut)(self, index).map(|value| value as &mut dyn Reflect)
+            }
+
+            #[inline]
+            fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+                <$ty>::insert(self, index, into_list_item::<$item>(element));
+            }
+
+            #[inline]
+            fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+                Box::new(($remove)(self, index))
+            }
+
+            #[inline]
+            fn push(&mut self, value: Box<dyn Reflect>) {
+                ($push)(self, into_list_item::<$item>(value));
+            }
+
+            #[inline]
+            fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+                ($pop)(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+
+            #[inline]
+            fn len(&self) -> usize {
+                <$ty>::len(self)
+            }
+
+            #[inline]
+            fn iter(&self) -> ListItemIter<'_> {
+                ListItemIter::new(self)
+            }
+
+            #[inline]
+            fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+                <$ty>::drain(self, ..)
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+        }
+
+        impl<$param $(: $param_bound)?> Reflect for $ty
+        where
+            $param: TypePath,
+            $item: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            #[inline]
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            #[inline]
+            fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::List
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::List(self)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::List(self)
+            }
+
+            #[inline]
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::List(self)
+            }
+
+            #[inline]
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                list_try_apply(self, value)
+            }
+
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                let mut list = <$ty>::with_capacity(<$ty>::len(self));
+                for index in 0..<$ty>::len(self) {
+                    let value = ($get)(self, index)
+                        .unwrap()
+                        .reflect_clone()?
+                        .take::<$item>()
+                        .expect("`Reflect::reflect_clone` should return the same type");
+                    ($push)(&mut list, value);
+                }
+                Ok(Box::new(list))
+            }
+
+            #[inline]
+            fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                list_partial_eq(self, other)
+            }
+
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                list_partial_cmp(self, other)
+            }
+
+            #[inline]
+            fn reflect_hash(&self) -> Option<u64> {
+                list_hash(self)
+            }
+
+            #[inline]
+            fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                list_debug(self, f)
+            }
+        }
+
+        impl<$param $(: $param_bound)?> GetTypeTraits for $ty
+        where
+            $param: TypePath,
+            $item: FromReflect + Typed + GetTypeTraits,
+            $($where)*
+        {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            #[inline]
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<$item>();
+            }
+        }
+
+        impl<$param $(: $param_bound)?> FromReflect for $ty
+        where
+            $param: TypePath,
+            $item: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                Self::try_from_reflect(reflect).ok()
+            }
+
+            fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+                let ReflectRef::List(ref_list) = reflect.reflect_ref() else {
+                    return Err(FromReflectError::mismatched::<Self>(reflect));
+                };
+
+                let mut list = <$ty>::with_capacity(ref_list.len());
+                for (index, item) in ref_list.iter().enumerate() {
+                    let item = <$item as FromReflect>::try_from_reflect(item)
+                        .map_err(|err| err.with_accessor(Accessor::ListIndex(index)))?;
+                    ($push)(&mut list, item);
+                }
+                Ok(list)
+            }
+        }
+    };
+}
+
+impl_reflect_for_list! {
+    impl<T> Vec<T> where [] {
+        item: T,
+        ident: "Vec",
+        module_path: "alloc::vec",
+        get: <[T]>::get,
+        get_mut: <[T]>::get_mut,
+        push: Vec::push,
+        pop: Vec::pop,
+        remove: Vec::remove,
+    }
+}
+
+impl_reflect_for_list! {
+    impl<T> VecDeque<T> where [] {
+        item: T,
+        ident: "VecDeque",
+        module_path: "alloc::collections::vec_deque",
+        get: VecDeque::get,
+        get_mut: VecDeque::get_mut,
+        push: VecDeque::push_back,
+        pop: VecDeque::pop_back,
+        remove: |list: &mut VecDeque<T>, index| {
+            VecDeque::remove(list, index).expect("`List::remove` index out of bounds")
+        },
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl_reflect_for_list! {
+    impl<A: smallvec::Array> smallvec::SmallVec<A> where [A: Send + Sync,] {
+        item: A::Item,
+        ident: "SmallVec",
+        module_path: "smallvec",
+        get: <[A::Item]>::get,
+        get_mut: <[A::Item]>::get_mut,
+        push: smallvec::SmallVec::push,
+        pop: smallvec::SmallVec::pop,
+        remove: smallvec::SmallVec::remove,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, collections::VecDeque, vec, vec::Vec};
+
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, Typed},
+        ops::{DynamicList, List},
+    };
+
+    #[test]
+    fn vec_list_reflection() {
+        let mut value: Vec<u32> = vec![1, 2, 3];
+        assert_eq!(value.reflect_kind(), ReflectKind::List);
+        assert_eq!(List::len(&value), 3);
+        assert_eq!(List::get(&value, 1).unwrap().downcast_ref::<u32>(), Some(&2));
+
+        List::push(&mut value, Box::new(4u32));
+        List::insert(&mut value, 0, Box::new(0u32));
+        assert_eq!(value, vec![0, 1, 2, 3, 4]);
+        assert_eq!(List::remove(&mut value, 4).take::<u32>().ok(), Some(4));
+
+        let info = <Vec<u32>>::type_info().as_list().unwrap();
+        assert_eq!(info.item_info().type_id(), core::any::TypeId::of::<u32>());
+    }
+
+    #[test]
+    fn vec_round_trip() {
+        let value: Vec<u32> = vec![5, 6];
+        let dynamic = value.to_dynamic();
+        assert!(dynamic.is_dynamic());
+        assert_eq!(dynamic.reflect_partial_eq(&value), Some(true));
+        assert_eq!(<Vec<u32>>::from_reflect(&*dynamic), Some(value.clone()));
+
+        // Extra items of the target are kept.
+        let mut other: Vec<u32> = vec![1, 2, 3];
+        other.apply(&value);
+        assert_eq!(other, vec![5, 6, 3]);
+
+        let mut list = DynamicList::new();
+        list.push(7u32);
+        let deque = <VecDeque<u32>>::from_reflect(&list).unwrap();
+        assert_eq!(deque, VecDeque::from([7]));
+        assert_eq!(deque.reflect_partial_eq(&vec![7u32]), Some(true));
+    }
+}
@@ -0,0 +1,424 @@
+//! Implement reflection traits for map-like containers.
+//!
+//! - [`HashMap<K, V, S>`](vct_utils::collections::HashMap)
+//! - [`BTreeMap<K, V>`], iterated and compared in key order.
+//! - `std::collections::HashMap<K, V, S>` (requires `std` feature)
+//!
+//! Implemented traits:
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Map`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeMap, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    access::Accessor,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, MapInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, Map, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, map_debug,
+        map_partial_cmp, map_partial_eq, map_try_apply,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+/// Convert a boxed reflected value into map key or value.
+///
+/// # Panics
+///
+/// If the value cannot be converted to `T`.
+fn into_map_item<T: FromReflect + Typed>(value: Box<dyn Reflect>) -> T {
+    T::take_from_reflect(value).unwrap_or_else(|value| {
+        panic!(
+            "Attempted to insert invalid value of type `{}` into map, expected `{}`.",
+            value.reflect_type_path(),
+            T::type_path(),
+        )
+    })
+}
+
+macro_rules! impl_reflect_for_map {
+    (
+        impl<K, V $(, $hasher:ident)?> $ty:ty where [$($where:tt)*] {
+            ident: $ident:literal,
+            module_path: $module_path:literal,
+            with_capacity: $with_capacity:expr,
+            $(partial_cmp: $partial_cmp:path,)?
+        }
+    ) => {
+        impl<K: TypePath, V: TypePath $(, $hasher: TypePath)?> TypePath for $ty {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[
+                        $module_path, "::", $ident, "<", K::type_path(), ", ", V::type_path(),
+                        $(", ", $hasher::type_path(),)?
+                        ">",
+                    ])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[
+                        $ident, "<", K::type_name(), ", ", V::type_name(),
+                        $(", ", $hasher::type_name(),)?
+                        ">",
+                    ])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                $ident
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some($module_path.split(':').next().unwrap())
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module_path)
+            }
+        }
+
+        impl<K, V $(, $hasher)?> Typed for $ty
+        where
+            K: FromReflect + Typed,
+            V: FromReflect + Typed,
+            $($where)*
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let info = MapInfo::new::<Self, K, V>().with_generics(Generics::from_iter([
+                        TypeParamInfo::new::<K>("K"),
+                        TypeParamInfo::new::<V>("V"),
+                        $(TypeParamInfo::new::<$hasher>(stringify!($hasher)),)?
+                    ]));
+                    TypeInfo::Map(info)
+                })
+            }
+        }
+
+        impl<K, V $(, $hasher)?> Map for $ty
+        where
+            K: FromReflect + Typed,
+            V: FromReflect + Typed,
+            $($where)*
+        {
+            fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+                let value = match key.downcast_ref::<K>() {
+                    Some(key) => <$ty>::get(self, key),
+                    None => <$ty>::get(self, &K::from_reflect(key)?),
+                };
+                value.map(|value| value as &dyn Reflect)
+            }
+
+            fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+                let value = match key.downcast_ref::<K>() {
+                    Some(key) => <$ty>::get_mut(self, key),
+                    None => <$ty>::get_mut(self, &K::from_reflect(key)?),
+                };
+                value.map(|value| value as &mut dyn Reflect)
+            }
+
+            #[inline]
+            fn len(&self) -> usize {
+                <$ty>::len(self)
+            }
+
+            #[inline]
+            fn iter(&self) -> Box<dyn Iterator<Item = (&dyn Reflect, &dyn Reflect)> + '_> {
+                let iter = <$ty>::iter(self)
+                    .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect));
+                Box::new(iter)
+            }
+
+            fn drain(&mut self) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+                core::mem::take(self)
+                    .into_iter()
+                    .map(|(key, value)| {
+                        (Box::new(key) as Box<dyn Reflect>, Box::new(value) as Box<dyn Reflect>)
+                    })
+                    .collect()
+            }
+
+            #[inline]
+            fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect, &mut dyn Reflect) -> bool) {
+                <$ty>::retain(self, move |key, value| f(key, value));
+            }
+
+            fn insert_boxed(
+                &mut self,
+                key: Box<dyn Reflect>,
+                value: Box<dyn Reflect>,
+            ) -> Option<Box<dyn Reflect>> {
+                let key = into_map_item::<K>(key);
+                let value = into_map_item::<V>(value);
+                <$ty>::insert(self, key, value).map(|old| Box::new(old) as Box<dyn Reflect>)
+            }
+
+            fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+                let value = match key.downcast_ref::<K>() {
+                    Some(key) => <$ty>::remove(self, key),
+                    None => <$ty>::remove(self, &K::from_reflect(key)?),
+                };
+                value.map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+        }
+
+        impl<K, V $(, $hasher)?> Reflect for $ty
+        where
+            K: FromReflect + Typed,
+            V: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            #[inline]
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            #[inline]
+            fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::Map
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::Map(self)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Map(self)
+            }
+
+            #[inline]
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Map(self)
+            }
+
+            #[inline]
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                map_try_apply(self, value)
+            }
+
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                let mut map = ($with_capacity)(<$ty>::len(self));
+                for (key, value) in <$ty>::iter(self) {
+                    let key = key
+                        .reflect_clone()?
+                        .take::<K>()
+                        .expect("`Reflect::reflect_clone` should return the same type");
+                    let value = value
+                        .reflect_clone()?
+                        .take::<V>()
+                        .expect("`Reflect::reflect_clone` should return the same type");
+                    <$ty>::insert(&mut map, key, value);
+                }
+                Ok(Box::new(map))
+            }
+
+            #[inline]
+            fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                map_partial_eq(self, other)
+            }
+
+            $(
+                #[inline]
+                fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                    // Dynamic collections are unordered, so only the same type is comparable.
+                    if !other.is::<Self>() {
+                        return None;
+                    }
+                    $partial_cmp(self, other)
+                }
+            )?
+
+            #[inline]
+            fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                map_debug(self, f)
+            }
+        }
+
+        impl<K, V $(, $hasher)?> GetTypeTraits for $ty
+        where
+            K: FromReflect + Typed + GetTypeTraits,
+            V: FromReflect + Typed + GetTypeTraits,
+            $($where)*
+        {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            #[inline]
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<K>();
+                registry.register::<V>();
+            }
+        }
+
+        impl<K, V $(, $hasher)?> FromReflect for $ty
+        where
+            K: FromReflect + Typed,
+            V: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                Self::try_from_reflect(reflect).ok()
+            }
+
+            fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+                let ReflectRef::Map(ref_map) = reflect.reflect_ref() else {
+                    return Err(FromReflectError::mismatched::<Self>(reflect));
+                };
+
+                let mut map = ($with_capacity)(ref_map.len());
+                for (key, value) in ref_map.iter() {
+                    let key_accessor = || Accessor::MapKey(Cow::Borrowed(key)).into_owned();
+                    let key_value = K::try_from_reflect(key)
+                        .map_err(|err| err.with_accessor(key_accessor()))?;
+                    let value = V::try_from_reflect(value)
+                        .map_err(|err| err.with_accessor(key_accessor()))?;
+                    <$ty>::insert(&mut map, key_value, value);
+                }
+                Ok(map)
+            }
+        }
+    };
+}
+
+impl_reflect_for_map! {
+    impl<K, V, S> vct_utils::collections::HashMap<K, V, S> where [
+        K: Eq + Hash,
+        S: TypePath + BuildHasher + Default + Send + Sync,
+    ] {
+        ident: "HashMap",
+        module_path: "vct_utils::collections::hash_map",
+        with_capacity: |capacity| {
+            vct_utils::collections::HashMap::with_capacity_and_hasher(capacity, S::default())
+        },
+    }
+}
+
+impl_reflect_for_map! {
+    impl<K, V> BTreeMap<K, V> where [K: Ord,] {
+        ident: "BTreeMap",
+        module_path: "alloc::collections::btree_map",
+        with_capacity: |_| BTreeMap::new(),
+        partial_cmp: map_partial_cmp,
+    }
+}
+
+#[cfg(feature = "std")]
+impl_reflect_for_map! {
+    impl<K, V, S> std::collections::HashMap<K, V, S> where [
+        K: Eq + Hash,
+        S: TypePath + BuildHasher + Default + Send + Sync,
+    ] {
+        ident: "HashMap",
+        module_path: "std::collections::hash_map",
+        with_capacity: |capacity| {
+            std::collections::HashMap::with_capacity_and_hasher(capacity, S::default())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, collections::BTreeMap, string::String};
+    use vct_utils::collections::HashMap;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::ReflectKind,
+        ops::{DynamicMap, Map},
+    };
+
+    #[test]
+    fn hash_map_reflection() {
+        let mut value: HashMap<String, u32> = HashMap::default();
+        value.insert(String::from("a"), 1);
+        assert_eq!(value.reflect_kind(), ReflectKind::Map);
+        assert_eq!(Map::get(&value, &String::from("a")).unwrap().downcast_ref(), Some(&1u32));
+
+        let old = value.insert_boxed(Box::new(String::from("a")), Box::new(2u32));
+        assert_eq!(old.unwrap().take::<u32>().ok(), Some(1));
+        assert_eq!(value.get("a"), Some(&2));
+        assert!(Map::remove(&mut value, &String::from("a")).is_some());
+        assert!(value.is_empty());
+    }
+
+    #[test]
+    fn map_round_trip() {
+        let mut value: BTreeMap<u8, String> = BTreeMap::new();
+        value.insert(2, String::from("two"));
+        value.insert(1, String::from("one"));
+
+        let keys: alloc::vec::Vec<u8> = Map::iter(&value)
+            .map(|(key, _)| *key.downcast_ref::<u8>().unwrap())
+            .collect();
+        assert_eq!(keys, [1, 2]);
+
+        let dynamic = value.to_dynamic();
+        assert_eq!(dynamic.reflect_partial_eq(&value), Some(true));
+        assert_eq!(<BTreeMap<u8, String>>::from_reflect(&*dynamic), Some(value.clone()));
+
+        let mut patch = DynamicMap::new();
+        patch.insert(3u8, String::from("three"));
+        let mut target: HashMap<u8, String> = HashMap::default();
+        target.apply(&patch);
+        assert_eq!(target.get(&3).map(String::as_str), Some("three"));
+        // Entries missing in the applied map are removed.
+        target.apply(&value);
+        assert_eq!(target.len(), 2);
+        assert_eq!(target.reflect_partial_eq(&value), Some(true));
+    }
+}
@@ -0,0 +1,186 @@
+//! Implement reflection traits for [`PhantomData<T>`].
+//!
+//! `PhantomData<T>` is reflected as `Opaque`, `T` only needs to implement [`TypePath`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{borrow::ToOwned, boxed::Box};
+use core::{
+    any::TypeId,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitDeserialize,
+        TypeTraitFromPtr, TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+
+impl<T: TypePath + ?Sized> TypePath for PhantomData<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            concat(&["core::marker::PhantomData<", T::type_path(), ">"])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["PhantomData<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "PhantomData"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("core")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("core::marker")
+    }
+}
+
+impl<T: TypePath + ?Sized + Send + Sync> Typed for PhantomData<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let info = OpaqueInfo::new::<Self>()
+                .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")]));
+            TypeInfo::Opaque(info)
+        })
+    }
+}
+
+impl<T: TypePath + ?Sized + Send + Sync> Reflect for PhantomData<T> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(PhantomData::<T>)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if value.is::<Self>() {
+            return Ok(());
+        }
+
+        Err(ApplyError::MismatchedTypes {
+            from_type: value.reflect_type_path().to_owned().into(),
+            to_type: Self::type_path().into(),
+        })
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(PhantomData::<T>))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        Some(other.is::<Self>())
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        other.is::<Self>().then_some(Ordering::Equal)
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        let mut hasher = reflect_hasher();
+        TypeId::of::<Self>().hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(<Self as TypePath>::type_name())
+    }
+}
+
+impl<T: TypePath + ?Sized + Send + Sync> GetTypeTraits for PhantomData<T> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitSerialize>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDeserialize>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(_registry: &mut TypeRegistry) {}
+}
+
+impl<T: TypePath + ?Sized + Send + Sync> FromReflect for PhantomData<T> {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.is::<Self>().then_some(PhantomData)
+    }
+}
@@ -24,7 +24,106 @@
 //! - [`Map`](crate::ops::Map)
 //! - [`Enum`](crate::ops::Enum)
 //!
+//! [`Reflect`](crate::Reflect) requires `Send + Sync`, so `Rc<T>`, `Cell<T>` and `RefCell<T>`
+//! cannot be reflected, use `Arc<T>`, `Mutex<T>` or `RwLock<T>` instead.
 
 mod native;
 
+mod arc; // Arc<T>
+mod array; // [T; N]
+mod boxed; // Box<T>
+mod cow; // Cow<'static, str>  Cow<'static, [T]>
+mod hasher; // FixedHash  RandomState
+mod list; // Vec<T>  VecDeque<T>  SmallVec<A>
+mod map; // HashMap<K, V, S>  BTreeMap<K, V>
+mod marker; // PhantomData<T>
+mod net; // IpAddr  Ipv4Addr  Ipv6Addr  SocketAddr  SocketAddrV4  SocketAddrV6
+mod num; // NonZeroU8 - NonZeroU128, NonZeroI8 - NonZeroI128, NonZeroUsize, NonZeroIsize
+mod option; // Option<T>
+#[cfg(feature = "std")]
+mod path; // PathBuf
+mod range; // Range<T>  RangeInclusive<T>  RangeFrom<T>  RangeTo<T>  RangeToInclusive<T>  RangeFull
+mod result; // Result<T, E>
+mod set; // HashSet<T, S>  BTreeSet<T>
+mod slice; // [T]  &'static [T]
+#[cfg(feature = "std")]
+mod sync; // Mutex<T>  RwLock<T>
+mod time; // Duration  Instant
+
 pub(crate) use crate::__macro_exports::alloc_utils::concat;
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use core::{
+        fmt::Debug,
+        marker::PhantomData,
+        net::{IpAddr, Ipv4Addr, SocketAddr},
+        num::NonZeroU32,
+        ops::{Range, RangeInclusive},
+        time::Duration,
+    };
+    use serde::de::DeserializeSeed;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, Typed},
+        registry::{GetTypeTraits, TypeRegistry, TypeTraitDefault},
+        serde::{InternalDeserializer, InternalSerializer},
+    };
+
+    fn round_trip<T>(value: &T) -> String
+    where
+        T: FromReflect + Typed + GetTypeTraits + PartialEq + Debug,
+    {
+        let mut registry = TypeRegistry::new();
+        registry.register::<T>();
+        let json = serde_json::to_string(&InternalSerializer::new(value, &registry)).unwrap();
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let output = InternalDeserializer::of::<T>(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(T::from_reflect(&*output).as_ref(), Some(value));
+        json
+    }
+
+    #[test]
+    fn primitive_round_trip() {
+        assert_eq!(round_trip(&true), "true");
+        assert_eq!(round_trip(&'x'), "\"x\"");
+        assert_eq!(round_trip(&()), "[]");
+        assert_eq!(round_trip(&NonZeroU32::new(3).unwrap()), "3");
+        assert_eq!(round_trip(&Duration::from_millis(1500)), r#"{"secs":1,"nanos":500000000}"#);
+        assert_eq!(round_trip(&(1u8..4)), r#"{"start":1,"end":4}"#);
+        round_trip(&RangeInclusive::new(1u8, 4));
+        round_trip(&IpAddr::V4(Ipv4Addr::LOCALHOST));
+        round_trip(&"127.0.0.1:80".parse::<SocketAddr>().unwrap());
+        round_trip(&PhantomData::<String>);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn path_round_trip() {
+        round_trip(&std::path::PathBuf::from("assets/a.png"));
+    }
+
+    #[test]
+    fn primitive_traits() {
+        assert_eq!('a'.reflect_kind(), ReflectKind::Opaque);
+        assert_eq!("a".reflect_partial_eq(&"a".to_string()), Some(true));
+        assert_eq!(true.reflect_hash(), true.reflect_hash());
+        assert_ne!(true.reflect_hash(), false.reflect_hash());
+
+        let range: Range<u8> = 1..2;
+        assert_eq!(range.reflect_clone().unwrap().take::<Range<u8>>().ok(), Some(1..2));
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Duration>();
+        let default = registry
+            .get_type_trait::<TypeTraitDefault>(core::any::TypeId::of::<Duration>())
+            .unwrap()
+            .default();
+        assert_eq!(default.take::<Duration>().ok(), Some(Duration::ZERO));
+    }
+}
@@ -1,3 +1,4 @@
 mod native_number; // u8 - u64, i8 - i64, f32, f64, usize, isize
-mod native_string;
-mod native_tuple; // ()  (T1,)  (T1, T2)  ...  (T1, T2, .. T12) // 'static str
+mod native_primitive; // bool  char
+mod native_string; // String  str  &'static str
+mod native_tuple; // ()  (T1,)  (T1, T2)  ...  (T1, T2, .. T12)
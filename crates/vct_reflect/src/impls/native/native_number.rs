@@ -1,16 +1,25 @@
 use alloc::boxed::Box;
-use core::{any::TypeId, fmt};
+use core::{
+    any::TypeId,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 use crate::{
     FromReflect, Reflect,
     cell::NonGenericTypeInfoCell,
     info::{OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
     ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
-    registry::{GetTypeTraits, TypeRegistry, TypeTraits},
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitDeserialize,
+        TypeTraitFromPtr, TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
 };
 
 macro_rules! impl_native_number {
-    ($name:ident, $str_name:literal) => {
+    ($name:ident, $str_name:literal, hashable: $hashable:literal) => {
         impl TypePath for $name {
             #[inline]
             fn type_path() -> &'static str {
@@ -119,20 +128,22 @@ macro_rules! impl_native_number {
                     *self = *value.downcast_ref::<isize>().unwrap() as $name;
                 } else if TypeId::of::<usize>() == id {
                     *self = *value.downcast_ref::<usize>().unwrap() as $name;
-                }
+                } else {
+                    let kind = value.reflect_kind();
+                    if kind != ReflectKind::Opaque {
+                        return Err(ApplyError::MismatchedKinds {
+                            from_kind: kind,
+                            to_kind: ReflectKind::Opaque,
+                        });
+                    }
 
-                let kind = value.reflect_kind();
-                if kind != ReflectKind::Opaque {
-                    return Err(ApplyError::MismatchedKinds {
-                        from_kind: kind,
-                        to_kind: ReflectKind::Opaque,
+                    return Err(ApplyError::MismatchedTypes {
+                        from_type: value.reflect_type_info().type_path().into(),
+                        to_type: $str_name.into(),
                     });
                 }
 
-                Err(ApplyError::MismatchedTypes {
-                    from_type: value.reflect_type_info().type_path().into(),
-                    to_type: $str_name.into(),
-                })
+                Ok(())
             }
 
             fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
@@ -142,6 +153,21 @@ macro_rules! impl_native_number {
                 }
             }
 
+            fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                PartialOrd::partial_cmp(self, other.downcast_ref::<$name>()?)
+            }
+
+            fn reflect_hash(&self) -> Option<u64> {
+                // Floating point numbers do not satisfy `Eq`, so they are not hashable.
+                if !$hashable {
+                    return None;
+                }
+                let mut hasher = reflect_hasher();
+                TypeId::of::<$name>().hash(&mut hasher);
+                hasher.write(&self.to_ne_bytes());
+                Some(hasher.finish())
+            }
+
             #[inline]
             fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
                 Ok(Box::new(*self))
@@ -155,9 +181,14 @@ macro_rules! impl_native_number {
         }
 
         impl GetTypeTraits for $name {
-            #[inline]
             fn get_type_traits() -> TypeTraits {
-                TypeTraits::of::<$name>()
+                let mut type_traits = TypeTraits::of::<$name>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<$name>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<$name>::from_type());
+                type_traits.insert::<TypeTraitDefault>(FromType::<$name>::from_type());
+                type_traits.insert::<TypeTraitSerialize>(FromType::<$name>::from_type());
+                type_traits.insert::<TypeTraitDeserialize>(FromType::<$name>::from_type());
+                type_traits
             }
 
             #[inline]
@@ -206,17 +237,68 @@ macro_rules! impl_native_number {
     };
 }
 
-impl_native_number!(u8, "u8");
-impl_native_number!(i8, "i8");
-impl_native_number!(u16, "u16");
-impl_native_number!(i16, "i16");
-impl_native_number!(u32, "u32");
-impl_native_number!(i32, "i32");
-impl_native_number!(u64, "u64");
-impl_native_number!(i64, "i64");
-impl_native_number!(u128, "u128");
-impl_native_number!(i128, "i128");
-impl_native_number!(f32, "f32");
-impl_native_number!(f64, "f64");
-impl_native_number!(usize, "usize");
-impl_native_number!(isize, "isize");
+impl_native_number!(u8, "u8", hashable: true);
+impl_native_number!(i8, "i8", hashable: true);
+impl_native_number!(u16, "u16", hashable: true);
+impl_native_number!(i16, "i16", hashable: true);
+impl_native_number!(u32, "u32", hashable: true);
+impl_native_number!(i32, "i32", hashable: true);
+impl_native_number!(u64, "u64", hashable: true);
+impl_native_number!(i64, "i64", hashable: true);
+impl_native_number!(u128, "u128", hashable: true);
+impl_native_number!(i128, "i128", hashable: true);
+impl_native_number!(f32, "f32", hashable: false);
+impl_native_number!(f64, "f64", hashable: false);
+impl_native_number!(usize, "usize", hashable: true);
+impl_native_number!(isize, "isize", hashable: true);
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use crate::{
+        Reflect,
+        ops::ApplyError,
+        registry::{
+            GetTypeTraits, TypeTraitDefault, TypeTraitDeserialize, TypeTraitFromReflect,
+            TypeTraitSerialize,
+        },
+    };
+
+    #[test]
+    fn number_try_apply() {
+        let mut value = 1u32;
+        assert!(value.try_apply(&2u8).is_ok());
+        assert_eq!(value, 2);
+        assert!(value.try_apply(&3i64).is_ok());
+        assert_eq!(value, 3);
+
+        assert!(matches!(
+            value.try_apply(&String::from("4")),
+            Err(ApplyError::MismatchedTypes { .. })
+        ));
+        assert!(matches!(
+            value.try_apply(&(4u32,)),
+            Err(ApplyError::MismatchedKinds { .. })
+        ));
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    fn number_hash() {
+        assert_eq!(5u32.reflect_hash(), 5u32.reflect_hash());
+        assert_ne!(5u32.reflect_hash(), 6u32.reflect_hash());
+        assert!(5u32.reflect_hash().is_some());
+        assert_eq!(5.0f32.reflect_hash(), None);
+        assert_eq!(5.0f64.reflect_hash(), None);
+    }
+
+    #[test]
+    fn number_type_traits() {
+        let type_traits = u32::get_type_traits();
+        assert!(type_traits.contains::<TypeTraitFromReflect>());
+        assert!(type_traits.contains::<TypeTraitDefault>());
+        assert!(type_traits.contains::<TypeTraitSerialize>());
+        assert!(type_traits.contains::<TypeTraitDeserialize>());
+    }
+}
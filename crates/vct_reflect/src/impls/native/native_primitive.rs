@@ -0,0 +1,39 @@
+use vct_reflect_derive::impl_full_reflect;
+
+use crate::info::TypePath;
+
+macro_rules! impl_type_path_primitive {
+    ($name:ident) => {
+        impl TypePath for $name {
+            #[inline]
+            fn type_path() -> &'static str {
+                stringify!($name)
+            }
+
+            #[inline]
+            fn type_name() -> &'static str {
+                stringify!($name)
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+impl_type_path_primitive!(bool);
+impl_type_path_primitive!(char);
+
+impl_full_reflect! {
+    #[reflect(TypePath = false)]
+    #[reflect(opaque, clone, default, hash, partial_eq, partial_cmp, debug, serde)]
+    struct bool;
+}
+
+impl_full_reflect! {
+    #[reflect(TypePath = false)]
+    #[reflect(opaque, clone, default, hash, partial_eq, partial_cmp, debug, serde)]
+    struct char;
+}
@@ -1,9 +1,187 @@
-use alloc::string::String;
+use alloc::{borrow::ToOwned, boxed::Box, string::String};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
 use vct_reflect_derive::impl_full_reflect;
 
+use crate::{
+    FromReflect, Reflect,
+    cell::NonGenericTypeInfoCell,
+    info::{OpaqueInfo, ReflectKind, TypeInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitDeserializeBorrowed,
+        TypeTraitFromPtr, TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+
 impl_full_reflect!{
-    #[reflect(opaque, clone, default, hash, partial_eq, debug, serde)]
+    #[reflect(opaque, clone, default, hash, partial_eq, partial_cmp, debug, serde)]
     #[reflect(type_path = "alloc::string::String")]
     struct String;
 }
 
+impl TypePath for str {
+    #[inline]
+    fn type_path() -> &'static str {
+        "str"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "str"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "str"
+    }
+}
+
+impl TypePath for &'static str {
+    #[inline]
+    fn type_path() -> &'static str {
+        "&str"
+    }
+
+    #[inline]
+    fn type_name() -> &'static str {
+        "&str"
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "&str"
+    }
+}
+
+impl Typed for &'static str {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_init(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl Reflect for &'static str {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(*self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            *self = *value;
+            return Ok(());
+        }
+
+        Err(ApplyError::MismatchedTypes {
+            from_type: value.reflect_type_path().to_owned().into(),
+            to_type: Self::type_path().into(),
+        })
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self))
+    }
+
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        if let Some(other) = other.downcast_ref::<Self>() {
+            return Some(*self == *other);
+        }
+        // Content comparison with owned strings is also allowed.
+        other.downcast_ref::<String>().map(|other| *self == other)
+    }
+
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        if let Some(other) = other.downcast_ref::<Self>() {
+            return Some(Ord::cmp(*self, *other));
+        }
+        other.downcast_ref::<String>().map(|other| Ord::cmp(*self, other.as_str()))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        // Same as `String`, so that equal contents have the same hash.
+        let mut hasher = reflect_hasher();
+        Hash::hash(*self, &mut hasher);
+        Some(hasher.finish())
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(*self, f)
+    }
+}
+
+impl GetTypeTraits for &'static str {
+    /// `&'static str` cannot be deserialized from a non-static input,
+    /// so only [`TypeTraitDeserializeBorrowed`] is provided.
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitSerialize>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitDeserializeBorrowed>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(_registry: &mut TypeRegistry) {}
+}
+
+impl FromReflect for &'static str {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.downcast_ref::<Self>().copied()
+    }
+}
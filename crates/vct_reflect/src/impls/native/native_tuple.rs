@@ -11,18 +11,18 @@
 //! [`DynamicTyped`]: crate::info::DynamicTyped
 
 use crate::{
-    FromReflect, Reflect,
+    FromReflect, FromReflectError, Reflect,
     cell::{GenericTypeInfoCell, GenericTypePathCell},
     impls::concat,
     info::{ReflectKind, TupleInfo, TypeInfo, TypePath, Typed, UnnamedField},
     ops::{
         ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, Tuple, TupleFieldIter,
-        tuple_debug, tuple_partial_eq, tuple_try_apply,
+        tuple_debug, tuple_partial_cmp, tuple_partial_eq, tuple_try_apply,
     },
     registry::{GetTypeTraits, TypeRegistry, TypeTraits},
 };
 use alloc::{boxed::Box, vec, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 use vct_utils::range_invoke;
 
 macro_rules! impl_type_path_tuple {
@@ -192,6 +192,11 @@ macro_rules! impl_reflect_tuple {
                 tuple_partial_eq(self, other)
             }
 
+            #[inline]
+            fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                tuple_partial_cmp(self, other)
+            }
+
             fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
                 Ok(Box::new((
                     $(
@@ -223,12 +228,26 @@ macro_rules! impl_reflect_tuple {
         }
 
         impl<$($name: FromReflect + Typed),*> FromReflect for ($($name,)*) {
+            #[inline]
             fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
-                let _ref_tuple = reflect.reflect_ref().as_tuple().ok()?;
+                Self::try_from_reflect(reflect).ok()
+            }
+
+            fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+                let ReflectRef::Tuple(_ref_tuple) = reflect.reflect_ref() else {
+                    return Err(FromReflectError::mismatched::<Self>(reflect));
+                };
 
-                Some((
+                Ok((
                     $(
-                        <$name as FromReflect>::from_reflect(_ref_tuple.field($index)?)?,
+                        match _ref_tuple.field($index) {
+                            Some(field) => <$name as FromReflect>::try_from_reflect(field)
+                                .map_err(|err| err.with_tuple_index($index))?,
+                            None => {
+                                return Err(FromReflectError::missing_field::<$name>()
+                                    .with_tuple_index($index));
+                            }
+                        },
                     )*
                 ))
             }
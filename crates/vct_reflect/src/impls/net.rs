@@ -0,0 +1,38 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use vct_reflect_derive::impl_full_reflect;
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::net::IpAddr")]
+    struct IpAddr;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::net::Ipv4Addr")]
+    struct Ipv4Addr;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::net::Ipv6Addr")]
+    struct Ipv6Addr;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::net::SocketAddr")]
+    struct SocketAddr;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::net::SocketAddrV4")]
+    struct SocketAddrV4;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::net::SocketAddrV6")]
+    struct SocketAddrV6;
+}
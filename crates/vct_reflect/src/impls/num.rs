@@ -0,0 +1,78 @@
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use vct_reflect_derive::impl_full_reflect;
+
+// `NonZero*` has no default value, so `default` is not available.
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroU8")]
+    struct NonZeroU8;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroI8")]
+    struct NonZeroI8;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroU16")]
+    struct NonZeroU16;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroI16")]
+    struct NonZeroI16;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroU32")]
+    struct NonZeroU32;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroI32")]
+    struct NonZeroI32;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroU64")]
+    struct NonZeroU64;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroI64")]
+    struct NonZeroI64;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroU128")]
+    struct NonZeroU128;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroI128")]
+    struct NonZeroI128;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroUsize")]
+    struct NonZeroUsize;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::num::NonZeroIsize")]
+    struct NonZeroIsize;
+}
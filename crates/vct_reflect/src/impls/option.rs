@@ -0,0 +1,358 @@
+//! Implement reflection traits for [`Option<T>`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Enum`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{borrow::ToOwned, boxed::Box};
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{
+        EnumInfo, Generics, ReflectKind, TupleVariantInfo, TypeInfo, TypeParamInfo, TypePath,
+        Typed, UnitVariantInfo, UnnamedField, VariantInfo, VariantKind,
+    },
+    ops::{
+        ApplyError, Enum, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        VariantFieldIter, enum_debug, enum_hash, enum_partial_cmp, enum_partial_eq,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+impl<T: TypePath> TypePath for Option<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["core::option::Option<", T::type_path(), ">"]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["Option<", T::type_name(), ">"]))
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Option"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("core")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("core::option")
+    }
+}
+
+impl<T: FromReflect + Typed> Typed for Option<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let variants = [
+                VariantInfo::Unit(UnitVariantInfo::new("None")),
+                VariantInfo::Tuple(TupleVariantInfo::new(
+                    "Some",
+                    &[UnnamedField::new::<T>(0)],
+                )),
+            ];
+            let info = EnumInfo::new::<Self>(&variants)
+                .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")]));
+            TypeInfo::Enum(info)
+        })
+    }
+}
+
+impl<T: FromReflect + Typed> Enum for Option<T> {
+    #[inline]
+    fn field(&self, _name: &str) -> Option<&dyn Reflect> {
+        None
+    }
+
+    #[inline]
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match (self, index) {
+            (Some(value), 0) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    #[inline]
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match (self, index) {
+            (Some(value), 0) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn index_of(&self, _name: &str) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn name_at(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
+    #[inline]
+    fn iter_fields(&self) -> VariantFieldIter<'_> {
+        VariantFieldIter::new(self)
+    }
+
+    #[inline]
+    fn field_len(&self) -> usize {
+        match self {
+            Some(..) => 1,
+            None => 0,
+        }
+    }
+
+    #[inline]
+    fn variant_name(&self) -> &str {
+        match self {
+            Some(..) => "Some",
+            None => "None",
+        }
+    }
+
+    #[inline]
+    fn variant_index(&self) -> usize {
+        match self {
+            None => 0,
+            Some(..) => 1,
+        }
+    }
+
+    #[inline]
+    fn variant_kind(&self) -> VariantKind {
+        match self {
+            Some(..) => VariantKind::Tuple,
+            None => VariantKind::Unit,
+        }
+    }
+}
+
+impl<T: FromReflect + Typed> Reflect for Option<T> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Enum
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Enum(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        let value = value.reflect_ref().as_enum()?;
+
+        if Enum::variant_name(self) == value.variant_name() {
+            // Same variant, only the inner value (if any) needs to be applied.
+            if let Some(this) = self {
+                let Some(field) = value.field_at(0) else {
+                    return Err(ApplyError::MissingEnumField {
+                        variant_name: "Some".into(),
+                        field_name: "0".into(),
+                    });
+                };
+                this.try_apply(field)?;
+            }
+            return Ok(());
+        }
+
+        match value.variant_name() {
+            "None" => *self = None,
+            "Some" => {
+                let Some(field) = value.field_at(0) else {
+                    return Err(ApplyError::MissingEnumField {
+                        variant_name: "Some".into(),
+                        field_name: "0".into(),
+                    });
+                };
+                let Some(field) = T::from_reflect(field) else {
+                    return Err(ApplyError::MismatchedTypes {
+                        from_type: field.reflect_type_path().to_owned().into(),
+                        to_type: T::type_path().into(),
+                    });
+                };
+                *self = Some(field);
+            }
+            name => {
+                return Err(ApplyError::UnknownVariant {
+                    enum_name: Self::type_path().into(),
+                    variant_name: name.to_owned().into(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(match self {
+            Some(value) => Some(
+                value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"),
+            ),
+            None => None,
+        }))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        enum_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        enum_partial_cmp(self, other)
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        enum_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        enum_debug(self, f)
+    }
+}
+
+impl<T: FromReflect + Typed + GetTypeTraits> GetTypeTraits for Option<T> {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: FromReflect + Typed> FromReflect for Option<T> {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Self::try_from_reflect(reflect).ok()
+    }
+
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+        let ReflectRef::Enum(value) = reflect.reflect_ref() else {
+            return Err(FromReflectError::mismatched::<Self>(reflect));
+        };
+        match value.variant_name() {
+            "None" => Ok(None),
+            "Some" => match value.field_at(0) {
+                Some(field) => T::try_from_reflect(field)
+                    .map(Some)
+                    .map_err(|err| err.with_tuple_index(0)),
+                None => Err(FromReflectError::missing_field::<T>().with_tuple_index(0)),
+            },
+            _ => Err(FromReflectError::mismatched::<Self>(reflect)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, Typed},
+        ops::{DynamicEnum, DynamicTuple, DynamicVariant, Enum},
+    };
+
+    #[test]
+    fn option_enum_reflection() {
+        let some: Option<u32> = Some(7);
+        assert_eq!(some.reflect_kind(), ReflectKind::Enum);
+        assert_eq!(some.variant_name(), "Some");
+        assert_eq!(some.variant_index(), 1);
+        assert_eq!(some.field_at(0).unwrap().downcast_ref::<u32>(), Some(&7));
+
+        let none: Option<u32> = None;
+        assert_eq!(none.variant_name(), "None");
+        assert_eq!(none.field_len(), 0);
+
+        let info = <Option<u32>>::type_info().as_enum().unwrap();
+        assert_eq!(info.variant_len(), 2);
+        assert!(info.contains_variant("Some"));
+    }
+
+    #[test]
+    fn option_apply_and_from_reflect() {
+        let mut value: Option<u32> = None;
+        value.apply(&Some(3u32));
+        assert_eq!(value, Some(3));
+
+        let mut fields = DynamicTuple::new();
+        fields.insert(5u32);
+        let dynamic = DynamicEnum::new("Some", DynamicVariant::Tuple(fields));
+        assert_eq!(<Option<u32>>::from_reflect(&dynamic), Some(Some(5)));
+
+        value.apply(&DynamicEnum::new("None", DynamicVariant::Unit));
+        assert_eq!(value, None);
+
+        let round_trip = <Option<u32>>::from_reflect(&*Some(9u32).to_dynamic());
+        assert_eq!(round_trip, Some(Some(9)));
+        assert_eq!(<Option<u32>>::from_reflect(&9u32), None);
+    }
+}
@@ -0,0 +1,8 @@
+use std::path::PathBuf;
+use vct_reflect_derive::impl_full_reflect;
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, default, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "std::path::PathBuf")]
+    struct PathBuf;
+}
@@ -0,0 +1,275 @@
+//! Implement reflection traits for ranges.
+//!
+//! Ranges are reflected as `Opaque`, the bounds are compared, hashed
+//! and debugged through their own reflection, so `Range<f32>` is supported
+//! (but not hashable).
+//!
+//! - `Range<T>`, `RangeInclusive<T>`, `RangeFrom<T>`, `RangeTo<T>`: with serde support.
+//! - `RangeToInclusive<T>`: without serde support, `serde` does not implement it.
+//! - `RangeFull`
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{borrow::ToOwned, boxed::Box};
+use core::{
+    any::TypeId,
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+    ops::{Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+use serde::{Deserialize, Serialize};
+use vct_reflect_derive::impl_full_reflect;
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDeserialize, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraitSerialize, TypeTraits,
+    },
+};
+
+fn bound_partial_eq<T: Reflect>(x: Bound<&T>, y: Bound<&T>) -> Option<bool> {
+    match (x, y) {
+        (Bound::Included(x), Bound::Included(y)) | (Bound::Excluded(x), Bound::Excluded(y)) => {
+            x.reflect_partial_eq(y)
+        }
+        (Bound::Unbounded, Bound::Unbounded) => Some(true),
+        _ => Some(false),
+    }
+}
+
+fn bound_hash<T: Reflect>(bound: Bound<&T>, hasher: &mut impl Hasher) -> Option<()> {
+    mem::discriminant(&bound).hash(hasher);
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) => {
+            hasher.write_u64(value.reflect_hash()?);
+        }
+        Bound::Unbounded => {}
+    }
+    Some(())
+}
+
+/// Formats the range as `start..end`, `start..=end`, `start..`, `..end` or `..=end`.
+fn range_debug<T: Reflect>(range: &impl RangeBounds<T>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if let Bound::Included(start) | Bound::Excluded(start) = range.start_bound() {
+        start.reflect_debug(f)?;
+    }
+    match range.end_bound() {
+        Bound::Included(end) => {
+            f.write_str("..=")?;
+            end.reflect_debug(f)
+        }
+        Bound::Excluded(end) => {
+            f.write_str("..")?;
+            end.reflect_debug(f)
+        }
+        Bound::Unbounded => f.write_str(".."),
+    }
+}
+
+macro_rules! impl_reflect_for_range {
+    ($ident:ident, serde) => {
+        impl_reflect_for_range!(@reflect $ident);
+
+        impl<T: Reflect + Typed + Clone + GetTypeTraits + Serialize + for<'de> Deserialize<'de>>
+            GetTypeTraits for $ident<T>
+        {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitSerialize>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitDeserialize>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            #[inline]
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+    };
+    ($ident:ident) => {
+        impl_reflect_for_range!(@reflect $ident);
+
+        impl<T: Reflect + Typed + Clone + GetTypeTraits> GetTypeTraits for $ident<T> {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            #[inline]
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+    };
+    (@reflect $ident:ident) => {
+        impl<T: TypePath> TypePath for $ident<T> {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&["core::ops::", stringify!($ident), "<", T::type_path(), ">"])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[stringify!($ident), "<", T::type_name(), ">"])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                stringify!($ident)
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some("core")
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some("core::ops")
+            }
+        }
+
+        impl<T: Reflect + Typed + Clone> Typed for $ident<T> {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let info = OpaqueInfo::new::<Self>()
+                        .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")]));
+                    TypeInfo::Opaque(info)
+                })
+            }
+        }
+
+        impl<T: Reflect + Typed + Clone> Reflect for $ident<T> {
+            #[inline]
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            #[inline]
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            #[inline]
+            fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::Opaque
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::Opaque(self)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Opaque(self)
+            }
+
+            #[inline]
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Opaque(self)
+            }
+
+            #[inline]
+            fn to_dynamic(&self) -> Box<dyn Reflect> {
+                Box::new(self.clone())
+            }
+
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                if let Some(value) = value.downcast_ref::<Self>() {
+                    *self = value.clone();
+                    return Ok(());
+                }
+
+                Err(ApplyError::MismatchedTypes {
+                    from_type: value.reflect_type_path().to_owned().into(),
+                    to_type: Self::type_path().into(),
+                })
+            }
+
+            #[inline]
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                Ok(Box::new(self.clone()))
+            }
+
+            fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                let other = other.downcast_ref::<Self>()?;
+                if !bound_partial_eq(self.start_bound(), other.start_bound())? {
+                    return Some(false);
+                }
+                bound_partial_eq(self.end_bound(), other.end_bound())
+            }
+
+            fn reflect_hash(&self) -> Option<u64> {
+                let mut hasher = reflect_hasher();
+                TypeId::of::<Self>().hash(&mut hasher);
+                bound_hash(self.start_bound(), &mut hasher)?;
+                bound_hash(self.end_bound(), &mut hasher)?;
+                Some(hasher.finish())
+            }
+
+            #[inline]
+            fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                range_debug(self, f)
+            }
+        }
+
+        impl<T: Reflect + Typed + Clone> FromReflect for $ident<T> {
+            #[inline]
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                reflect.downcast_ref::<Self>().cloned()
+            }
+        }
+    };
+}
+
+impl_reflect_for_range!(Range, serde);
+impl_reflect_for_range!(RangeInclusive, serde);
+impl_reflect_for_range!(RangeFrom, serde);
+impl_reflect_for_range!(RangeTo, serde);
+impl_reflect_for_range!(RangeToInclusive);
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, default, hash, partial_eq, debug)]
+    #[reflect(type_path = "core::ops::RangeFull")]
+    struct RangeFull;
+}
@@ -0,0 +1,348 @@
+//! Implement reflection traits for [`Result<T, E>`].
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Enum`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{borrow::ToOwned, boxed::Box};
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{
+        EnumInfo, Generics, ReflectKind, TupleVariantInfo, TypeInfo, TypeParamInfo, TypePath,
+        Typed, UnnamedField, VariantInfo, VariantKind,
+    },
+    ops::{
+        ApplyError, Enum, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef,
+        VariantFieldIter, enum_debug, enum_hash, enum_partial_cmp, enum_partial_eq,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+impl<T: TypePath, E: TypePath> TypePath for Result<T, E> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            concat(&["core::result::Result<", T::type_path(), ", ", E::type_path(), ">"])
+        })
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            concat(&["Result<", T::type_name(), ", ", E::type_name(), ">"])
+        })
+    }
+
+    #[inline]
+    fn type_ident() -> &'static str {
+        "Result"
+    }
+
+    #[inline]
+    fn crate_name() -> Option<&'static str> {
+        Some("core")
+    }
+
+    #[inline]
+    fn module_path() -> Option<&'static str> {
+        Some("core::result")
+    }
+}
+
+impl<T: FromReflect + Typed, E: FromReflect + Typed> Typed for Result<T, E> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let variants = [
+                VariantInfo::Tuple(TupleVariantInfo::new("Ok", &[UnnamedField::new::<T>(0)])),
+                VariantInfo::Tuple(TupleVariantInfo::new("Err", &[UnnamedField::new::<E>(0)])),
+            ];
+            let info = EnumInfo::new::<Self>(&variants).with_generics(Generics::from_iter([
+                TypeParamInfo::new::<T>("T"),
+                TypeParamInfo::new::<E>("E"),
+            ]));
+            TypeInfo::Enum(info)
+        })
+    }
+}
+
+impl<T: FromReflect + Typed, E: FromReflect + Typed> Enum for Result<T, E> {
+    #[inline]
+    fn field(&self, _name: &str) -> Option<&dyn Reflect> {
+        None
+    }
+
+    #[inline]
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match (self, index) {
+            (Ok(value), 0) => Some(value),
+            (Err(error), 0) => Some(error),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    #[inline]
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match (self, index) {
+            (Ok(value), 0) => Some(value),
+            (Err(error), 0) => Some(error),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn index_of(&self, _name: &str) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn name_at(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
+    #[inline]
+    fn iter_fields(&self) -> VariantFieldIter<'_> {
+        VariantFieldIter::new(self)
+    }
+
+    #[inline]
+    fn field_len(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn variant_name(&self) -> &str {
+        match self {
+            Ok(..) => "Ok",
+            Err(..) => "Err",
+        }
+    }
+
+    #[inline]
+    fn variant_index(&self) -> usize {
+        match self {
+            Ok(..) => 0,
+            Err(..) => 1,
+        }
+    }
+
+    #[inline]
+    fn variant_kind(&self) -> VariantKind {
+        VariantKind::Tuple
+    }
+}
+
+impl<T: FromReflect + Typed, E: FromReflect + Typed> Reflect for Result<T, E> {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Enum
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Enum(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Enum(self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        let value = value.reflect_ref().as_enum()?;
+
+        let Some(field) = value.field_at(0) else {
+            return Err(ApplyError::MissingEnumField {
+                variant_name: value.variant_name().to_owned().into(),
+                field_name: "0".into(),
+            });
+        };
+
+        match (self, value.variant_name()) {
+            (Ok(this), "Ok") => this.try_apply(field)?,
+            (Err(this), "Err") => this.try_apply(field)?,
+            (this, "Ok") => {
+                let Some(field) = T::from_reflect(field) else {
+                    return Err(ApplyError::MismatchedTypes {
+                        from_type: field.reflect_type_path().to_owned().into(),
+                        to_type: T::type_path().into(),
+                    });
+                };
+                *this = Ok(field);
+            }
+            (this, "Err") => {
+                let Some(field) = E::from_reflect(field) else {
+                    return Err(ApplyError::MismatchedTypes {
+                        from_type: field.reflect_type_path().to_owned().into(),
+                        to_type: E::type_path().into(),
+                    });
+                };
+                *this = Err(field);
+            }
+            (_, name) => {
+                return Err(ApplyError::UnknownVariant {
+                    enum_name: Self::type_path().into(),
+                    variant_name: name.to_owned().into(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(match self {
+            Ok(value) => Ok::<T, E>(
+                value
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type"),
+            ),
+            Err(error) => Err(error
+                .reflect_clone()?
+                .take::<E>()
+                .expect("`Reflect::reflect_clone` should return the same type")),
+        }))
+    }
+
+    #[inline]
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        enum_partial_eq(self, other)
+    }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        enum_partial_cmp(self, other)
+    }
+
+    #[inline]
+    fn reflect_hash(&self) -> Option<u64> {
+        enum_hash(self)
+    }
+
+    #[inline]
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        enum_debug(self, f)
+    }
+}
+
+impl<T, E> GetTypeTraits for Result<T, E>
+where
+    T: FromReflect + Typed + GetTypeTraits,
+    E: FromReflect + Typed + GetTypeTraits,
+{
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+        registry.register::<E>();
+    }
+}
+
+impl<T: FromReflect + Typed, E: FromReflect + Typed> FromReflect for Result<T, E> {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        Self::try_from_reflect(reflect).ok()
+    }
+
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+        let ReflectRef::Enum(value) = reflect.reflect_ref() else {
+            return Err(FromReflectError::mismatched::<Self>(reflect));
+        };
+        match value.variant_name() {
+            "Ok" => match value.field_at(0) {
+                Some(field) => T::try_from_reflect(field)
+                    .map(Ok)
+                    .map_err(|err| err.with_tuple_index(0)),
+                None => Err(FromReflectError::missing_field::<T>().with_tuple_index(0)),
+            },
+            "Err" => match value.field_at(0) {
+                Some(field) => E::try_from_reflect(field)
+                    .map(Err)
+                    .map_err(|err| err.with_tuple_index(0)),
+                None => Err(FromReflectError::missing_field::<E>().with_tuple_index(0)),
+            },
+            _ => Err(FromReflectError::mismatched::<Self>(reflect)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use crate::{FromReflect, Reflect, ops::Enum};
+
+    #[test]
+    fn result_enum_reflection() {
+        let ok: Result<u32, String> = Ok(1);
+        assert_eq!(ok.variant_name(), "Ok");
+        assert_eq!(ok.field_at(0).unwrap().downcast_ref::<u32>(), Some(&1));
+
+        let err: Result<u32, String> = Err(String::from("failed"));
+        assert_eq!(err.variant_name(), "Err");
+        assert_eq!(err.variant_index(), 1);
+
+        let mut value = ok.clone();
+        value.apply(&err);
+        assert_eq!(value, err);
+        assert_eq!(value.reflect_partial_eq(&ok), Some(false));
+
+        let converted = <Result<u32, String>>::from_reflect(&*err.to_dynamic());
+        assert_eq!(converted, Some(err));
+    }
+}
@@ -0,0 +1,397 @@
+//! Implement reflection traits for set-like containers.
+//!
+//! - [`HashSet<T, S>`](vct_utils::collections::HashSet)
+//! - [`BTreeSet<T>`], iterated and compared in value order.
+//! - `std::collections::HashSet<T, S>` (requires `std` feature)
+//!
+//! Implemented traits:
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Set`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeSet, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    FromReflect, FromReflectError, Reflect,
+    access::Accessor,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, ReflectKind, SetInfo, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{
+        ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef, Set, set_debug,
+        set_partial_cmp, set_partial_eq, set_try_apply,
+    },
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDefault, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+/// Convert a boxed reflected value into set value.
+///
+/// # Panics
+///
+/// If the value cannot be converted to `T`.
+fn into_set_item<T: FromReflect + Typed>(value: Box<dyn Reflect>) -> T {
+    T::take_from_reflect(value).unwrap_or_else(|value| {
+        panic!(
+            "Attempted to insert invalid value of type `{}` into set of `{}`.",
+            value.reflect_type_path(),
+            T::type_path(),
+        )
+    })
+}
+
+macro_rules! impl_reflect_for_set {
+    (
+        impl<T $(, $hasher:ident)?> $ty:ty where [$($where:tt)*] {
+            ident: $ident:literal,
+            module_path: $module_path:literal,
+            with_capacity: $with_capacity:expr,
+            $(partial_cmp: $partial_cmp:path,)?
+        }
+    ) => {
+        impl<T: TypePath $(, $hasher: TypePath)?> TypePath for $ty {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[
+                        $module_path, "::", $ident, "<", T::type_path(),
+                        $(", ", $hasher::type_path(),)?
+                        ">",
+                    ])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[
+                        $ident, "<", T::type_name(),
+                        $(", ", $hasher::type_name(),)?
+                        ">",
+                    ])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                $ident
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                Some($module_path.split(':').next().unwrap())
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module_path)
+            }
+        }
+
+        impl<T $(, $hasher)?> Typed for $ty
+        where
+            T: FromReflect + Typed,
+            $($where)*
+        {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let info = SetInfo::new::<Self, T>().with_generics(Generics::from_iter([
+                        TypeParamInfo::new::<T>("T"),
+                        $(TypeParamInfo::new::<$hasher>(stringify!($hasher)),)?
+                    ]));
+                    TypeInfo::Set(info)
+                })
+            }
+        }
+
+        impl<T $(, $hasher)?> Set for $ty
+        where
+            T: FromReflect + Typed,
+            $($where)*
+        {
+            fn get(&self, value: &dyn Reflect) -> Option<&dyn Reflect> {
+                let value = match value.downcast_ref::<T>() {
+                    Some(value) => <$ty>::get(self, value),
+                    None => <$ty>::get(self, &T::from_reflect(value)?),
+                };
+                value.map(|value| value as &dyn Reflect)
+            }
+
+            #[inline]
+            fn len(&self) -> usize {
+                <$ty>::len(self)
+            }
+
+            #[inline]
+            fn iter(&self) -> Box<dyn Iterator<Item = &dyn Reflect> + '_> {
+                Box::new(<$ty>::iter(self).map(|value| value as &dyn Reflect))
+            }
+
+            fn drain(&mut self) -> Vec<Box<dyn Reflect>> {
+                core::mem::take(self)
+                    .into_iter()
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+
+            #[inline]
+            fn retain(&mut self, f: &mut dyn FnMut(&dyn Reflect) -> bool) {
+                <$ty>::retain(self, move |value| f(value));
+            }
+
+            #[inline]
+            fn insert_boxed(&mut self, value: Box<dyn Reflect>) -> bool {
+                <$ty>::insert(self, into_set_item::<T>(value))
+            }
+
+            fn remove(&mut self, value: &dyn Reflect) -> bool {
+                match value.downcast_ref::<T>() {
+                    Some(value) => <$ty>::remove(self, value),
+                    None => match T::from_reflect(value) {
+                        Some(value) => <$ty>::remove(self, &value),
+                        None => false,
+                    },
+                }
+            }
+
+            fn contains(&self, value: &dyn Reflect) -> bool {
+                match value.downcast_ref::<T>() {
+                    Some(value) => <$ty>::contains(self, value),
+                    None => match T::from_reflect(value) {
+                        Some(value) => <$ty>::contains(self, &value),
+                        None => false,
+                    },
+                }
+            }
+        }
+
+        impl<T $(, $hasher)?> Reflect for $ty
+        where
+            T: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            #[inline]
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            #[inline]
+            fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::Set
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::Set(self)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Set(self)
+            }
+
+            #[inline]
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Set(self)
+            }
+
+            #[inline]
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                set_try_apply(self, value)
+            }
+
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                let mut set = ($with_capacity)(<$ty>::len(self));
+                for value in <$ty>::iter(self) {
+                    let value = value
+                        .reflect_clone()?
+                        .take::<T>()
+                        .expect("`Reflect::reflect_clone` should return the same type");
+                    <$ty>::insert(&mut set, value);
+                }
+                Ok(Box::new(set))
+            }
+
+            #[inline]
+            fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                set_partial_eq(self, other)
+            }
+
+            $(
+                #[inline]
+                fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+                    // Dynamic collections are unordered, so only the same type is comparable.
+                    if !other.is::<Self>() {
+                        return None;
+                    }
+                    $partial_cmp(self, other)
+                }
+            )?
+
+            #[inline]
+            fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                set_debug(self, f)
+            }
+        }
+
+        impl<T $(, $hasher)?> GetTypeTraits for $ty
+        where
+            T: FromReflect + Typed + GetTypeTraits,
+            $($where)*
+        {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitDefault>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            #[inline]
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+
+        impl<T $(, $hasher)?> FromReflect for $ty
+        where
+            T: FromReflect + Typed,
+            $($where)*
+        {
+            #[inline]
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                Self::try_from_reflect(reflect).ok()
+            }
+
+            fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError> {
+                let ReflectRef::Set(ref_set) = reflect.reflect_ref() else {
+                    return Err(FromReflectError::mismatched::<Self>(reflect));
+                };
+
+                let mut set = ($with_capacity)(ref_set.len());
+                for value in ref_set.iter() {
+                    let item = T::try_from_reflect(value).map_err(|err| {
+                        err.with_accessor(Accessor::MapKey(Cow::Borrowed(value)).into_owned())
+                    })?;
+                    <$ty>::insert(&mut set, item);
+                }
+                Ok(set)
+            }
+        }
+    };
+}
+
+impl_reflect_for_set! {
+    impl<T, S> vct_utils::collections::HashSet<T, S> where [
+        T: Eq + Hash,
+        S: TypePath + BuildHasher + Default + Send + Sync,
+    ] {
+        ident: "HashSet",
+        module_path: "vct_utils::collections::hash_set",
+        with_capacity: |capacity| {
+            vct_utils::collections::HashSet::with_capacity_and_hasher(capacity, S::default())
+        },
+    }
+}
+
+impl_reflect_for_set! {
+    impl<T> BTreeSet<T> where [T: Ord,] {
+        ident: "BTreeSet",
+        module_path: "alloc::collections::btree_set",
+        with_capacity: |_| BTreeSet::new(),
+        partial_cmp: set_partial_cmp,
+    }
+}
+
+#[cfg(feature = "std")]
+impl_reflect_for_set! {
+    impl<T, S> std::collections::HashSet<T, S> where [
+        T: Eq + Hash,
+        S: TypePath + BuildHasher + Default + Send + Sync,
+    ] {
+        ident: "HashSet",
+        module_path: "std::collections::hash_set",
+        with_capacity: |capacity| {
+            std::collections::HashSet::with_capacity_and_hasher(capacity, S::default())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, collections::BTreeSet};
+    use vct_utils::collections::HashSet;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::ReflectKind,
+        ops::{DynamicSet, Set},
+    };
+
+    #[test]
+    fn set_reflection() {
+        let mut value: HashSet<u32> = HashSet::new();
+        assert_eq!(value.reflect_kind(), ReflectKind::Set);
+        assert!(value.insert_boxed(Box::new(1u32)));
+        assert!(!value.insert_boxed(Box::new(1u32)));
+        assert!(Set::contains(&value, &1u32));
+        assert!(Set::remove(&mut value, &1u32));
+        assert!(Set::is_empty(&value));
+    }
+
+    #[test]
+    fn set_round_trip() {
+        let value: BTreeSet<u32> = BTreeSet::from([3, 1, 2]);
+        let items: alloc::vec::Vec<u32> = Set::iter(&value)
+            .map(|item| *item.downcast_ref::<u32>().unwrap())
+            .collect();
+        assert_eq!(items, [1, 2, 3]);
+
+        let dynamic = value.to_dynamic();
+        assert_eq!(dynamic.reflect_partial_eq(&value), Some(true));
+        assert_eq!(<BTreeSet<u32>>::from_reflect(&*dynamic), Some(value.clone()));
+
+        let mut other = DynamicSet::new();
+        other.insert(4u32);
+        let mut target: HashSet<u32> = <HashSet<u32>>::from_reflect(&value).unwrap();
+        assert_eq!(target.len(), 3);
+        target.apply(&other);
+        assert_eq!(target.reflect_partial_eq(&other), Some(true));
+    }
+}
@@ -0,0 +1,228 @@
+//! Implement reflection traits for slices.
+//!
+//! - `[T]`: only [`TypePath`], so that it can be used by containers like `Box<[T]>`.
+//! - `&'static [T]`: reflected as `Opaque`, items can be compared, hashed
+//!   and debugged through their own reflection. `&'static [u8]` can be deserialized
+//!   from a `'static` input with [`TypeTraitDeserializeBorrowed`].
+
+use alloc::{borrow::ToOwned, boxed::Box};
+use core::{
+    any::TypeId,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    reflect_hasher,
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitDeserializeBorrowed, TypeTraitFromPtr,
+        TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+impl<T: TypePath> TypePath for [T] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["[", T::type_path(), "]"]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["[", T::type_name(), "]"]))
+    }
+
+    fn type_ident() -> &'static str {
+        <Self as TypePath>::type_name()
+    }
+}
+
+impl<T: TypePath> TypePath for &'static [T] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["&", <[T]>::type_path()]))
+    }
+
+    fn type_name() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| concat(&["&", <[T]>::type_name()]))
+    }
+
+    fn type_ident() -> &'static str {
+        <Self as TypePath>::type_name()
+    }
+}
+
+impl<T: Reflect + Typed> Typed for &'static [T] {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            let info = OpaqueInfo::new::<Self>()
+                .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")]));
+            TypeInfo::Opaque(info)
+        })
+    }
+}
+
+impl<T: Reflect + Typed> Reflect for &'static [T] {
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    #[inline]
+    fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    #[inline]
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque(self)
+    }
+
+    #[inline]
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    #[inline]
+    fn to_dynamic(&self) -> Box<dyn Reflect> {
+        Box::new(*self)
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        if let Some(value) = value.downcast_ref::<Self>() {
+            *self = *value;
+            return Ok(());
+        }
+
+        Err(ApplyError::MismatchedTypes {
+            from_type: value.reflect_type_path().to_owned().into(),
+            to_type: Self::type_path().into(),
+        })
+    }
+
+    #[inline]
+    fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        Ok(Box::new(*self))
+    }
+
+    fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+        let other = other.downcast_ref::<Self>()?;
+        if self.len() != other.len() {
+            return Some(false);
+        }
+        for (x, y) in self.iter().zip(other.iter()) {
+            if x.reflect_partial_eq(y)? {
+                continue;
+            }
+            return Some(false);
+        }
+        Some(true)
+    }
+
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        let other = other.downcast_ref::<Self>()?;
+        for (x, y) in self.iter().zip(other.iter()) {
+            match x.reflect_partial_cmp(y) {
+                Some(Ordering::Equal) => {}
+                ordering => return ordering,
+            }
+        }
+        self.len().partial_cmp(&other.len())
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        let mut hasher = reflect_hasher();
+        TypeId::of::<Self>().hash(&mut hasher);
+        self.len().hash(&mut hasher);
+        for value in self.iter() {
+            hasher.write_u64(value.reflect_hash()?);
+        }
+        Some(hasher.finish())
+    }
+
+    fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_list();
+        for value in self.iter() {
+            debug.entry(&(value as &dyn Reflect));
+        }
+        debug.finish()
+    }
+}
+
+impl<T: Reflect + Typed + GetTypeTraits> GetTypeTraits for &'static [T] {
+    fn get_type_traits() -> TypeTraits {
+        let mut type_traits = TypeTraits::of::<Self>();
+        type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+        type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+        // Byte slices can borrow from the (`'static`) input instead of copying it.
+        if TypeId::of::<T>() == TypeId::of::<u8>() {
+            type_traits.insert::<TypeTraitDeserializeBorrowed>(
+                FromType::<&'static [u8]>::from_type(),
+            );
+        }
+        type_traits
+    }
+
+    #[inline]
+    fn register_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<T>();
+    }
+}
+
+impl<T: Reflect + Typed> FromReflect for &'static [T] {
+    #[inline]
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        reflect.downcast_ref::<Self>().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FromReflect, Reflect, info::ReflectKind};
+
+    #[test]
+    fn static_slice_reflection() {
+        static ITEMS: [u32; 3] = [1, 2, 3];
+        let value: &'static [u32] = &ITEMS;
+        assert_eq!(value.reflect_kind(), ReflectKind::Opaque);
+        assert_eq!(value.reflect_partial_eq(&(&ITEMS[..])), Some(true));
+        assert_eq!(value.reflect_partial_eq(&(&ITEMS[1..])), Some(false));
+        assert_eq!(value.reflect_hash(), (&ITEMS[..]).reflect_hash());
+
+        let cloned = value.reflect_clone().unwrap();
+        assert_eq!(<&'static [u32]>::from_reflect(&*cloned), Some(value));
+    }
+}
@@ -0,0 +1,286 @@
+//! Implement lock-on-access reflection traits for [`Mutex<T>`] and [`RwLock<T>`].
+//!
+//! The content cannot be borrowed out of the lock, so these types are reflected as `Opaque`,
+//! every method that reads or writes the content takes the lock for the duration of the call:
+//!
+//! - [`Reflect::try_apply`] applies the value (or the content of another lock) to the content.
+//! - [`Reflect::reflect_clone`] clones the content into a new lock.
+//! - [`Reflect::reflect_partial_eq`], [`Reflect::reflect_hash`] and [`Reflect::reflect_debug`]
+//!   are forwarded to the content. Comparing two locks takes them in address order.
+//!
+//! The type paths use `vct_os::sync`, the module these types are reflected from.
+//!
+//! Poisoned locks are still accessed, the poison flag is ignored.
+//!
+//! - [`TypePath`] -> [`DynamicTypePath`]
+//! - [`Typed`] -> [`DynamicTyped`]
+//! - [`Reflect`]
+//! - [`GetTypeTraits`]
+//! - [`FromReflect`]
+//!
+//! [`DynamicTypePath`]: crate::info::DynamicTypePath
+//! [`DynamicTyped`]: crate::info::DynamicTyped
+
+use alloc::boxed::Box;
+use core::fmt;
+use vct_os::sync::{Mutex, PoisonError, RwLock};
+
+use crate::{
+    FromReflect, Reflect,
+    cell::{GenericTypeInfoCell, GenericTypePathCell},
+    impls::concat,
+    info::{Generics, OpaqueInfo, ReflectKind, TypeInfo, TypeParamInfo, TypePath, Typed},
+    ops::{ApplyError, ReflectCloneError, ReflectMut, ReflectOwned, ReflectRef},
+    registry::{
+        FromType, GetTypeTraits, TypeRegistry, TypeTraitFromPtr, TypeTraitFromReflect, TypeTraits,
+    },
+};
+
+macro_rules! impl_reflect_for_lock {
+    (
+        $ident:ident,
+        read: $read:ident,
+        module_path: $module_path:literal,
+    ) => {
+        impl<T: TypePath + ?Sized> TypePath for $ident<T> {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[$module_path, "::", stringify!($ident), "<", T::type_path(), ">"])
+                })
+            }
+
+            fn type_name() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    concat(&[stringify!($ident), "<", T::type_name(), ">"])
+                })
+            }
+
+            #[inline]
+            fn type_ident() -> &'static str {
+                stringify!($ident)
+            }
+
+            #[inline]
+            fn crate_name() -> Option<&'static str> {
+                $module_path.split("::").next()
+            }
+
+            #[inline]
+            fn module_path() -> Option<&'static str> {
+                Some($module_path)
+            }
+        }
+
+        impl<T: FromReflect + Typed> Typed for $ident<T> {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    let info = OpaqueInfo::new::<Self>()
+                        .with_generics(Generics::from_iter([TypeParamInfo::new::<T>("T")]));
+                    TypeInfo::Opaque(info)
+                })
+            }
+        }
+
+        impl<T: FromReflect + Typed> Reflect for $ident<T> {
+            #[inline]
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            #[inline]
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            #[inline]
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            #[inline]
+            fn represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            #[inline]
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::Opaque
+            }
+
+            #[inline]
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::Opaque(self)
+            }
+
+            #[inline]
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Opaque(self)
+            }
+
+            #[inline]
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Opaque(self)
+            }
+
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                let this = self.get_mut().unwrap_or_else(PoisonError::into_inner);
+                match value.downcast_ref::<Self>() {
+                    Some(other) => {
+                        let other = other.$read().unwrap_or_else(PoisonError::into_inner);
+                        this.try_apply(&*other)
+                    }
+                    None => this.try_apply(value),
+                }
+            }
+
+            fn reflect_clone(&self) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+                let value = self
+                    .$read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .reflect_clone()?
+                    .take::<T>()
+                    .expect("`Reflect::reflect_clone` should return the same type");
+                Ok(Box::new(<$ident<T>>::new(value)))
+            }
+
+            fn reflect_partial_eq(&self, other: &dyn Reflect) -> Option<bool> {
+                let Some(other) = other.downcast_ref::<Self>() else {
+                    let this = self.$read().unwrap_or_else(PoisonError::into_inner);
+                    return this.reflect_partial_eq(other);
+                };
+                // Avoid locking the same lock twice.
+                if core::ptr::eq(self, other) {
+                    return Some(true);
+                }
+                // Lock in address order, so `a == b` and `b == a` on two threads can't deadlock.
+                let self_first = (self as *const Self) < (other as *const Self);
+                let (first, second) = if self_first { (self, other) } else { (other, self) };
+                let first = first.$read().unwrap_or_else(PoisonError::into_inner);
+                let second = second.$read().unwrap_or_else(PoisonError::into_inner);
+                if self_first {
+                    first.reflect_partial_eq(&*second)
+                } else {
+                    second.reflect_partial_eq(&*first)
+                }
+            }
+
+            /// Returns the hash of the content, so it agrees with
+            /// [`reflect_partial_eq`](Reflect::reflect_partial_eq) against a bare `T`.
+            fn reflect_hash(&self) -> Option<u64> {
+                self.$read().unwrap_or_else(PoisonError::into_inner).reflect_hash()
+            }
+
+            fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($ident), "("))?;
+                let this = self.$read().unwrap_or_else(PoisonError::into_inner);
+                this.reflect_debug(f)?;
+                f.write_str(")")
+            }
+        }
+
+        impl<T: FromReflect + Typed + GetTypeTraits> GetTypeTraits for $ident<T> {
+            fn get_type_traits() -> TypeTraits {
+                let mut type_traits = TypeTraits::of::<Self>();
+                type_traits.insert::<TypeTraitFromPtr>(FromType::<Self>::from_type());
+                type_traits.insert::<TypeTraitFromReflect>(FromType::<Self>::from_type());
+                type_traits
+            }
+
+            #[inline]
+            fn register_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+
+        impl<T: FromReflect + Typed> FromReflect for $ident<T> {
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                let value = match reflect.downcast_ref::<Self>() {
+                    Some(other) => {
+                        let other = other.$read().unwrap_or_else(PoisonError::into_inner);
+                        T::from_reflect(&*other)?
+                    }
+                    None => T::from_reflect(reflect)?,
+                };
+                Some(<$ident<T>>::new(value))
+            }
+        }
+    };
+}
+
+impl_reflect_for_lock! {
+    Mutex,
+    read: lock,
+    module_path: "vct_os::sync",
+}
+
+impl_reflect_for_lock! {
+    RwLock,
+    read: read,
+    module_path: "vct_os::sync",
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use vct_os::sync::{Mutex, RwLock};
+
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, TypePath},
+    };
+
+    #[test]
+    fn lock_on_access() {
+        let mut value = Mutex::new(1u32);
+        assert_eq!(value.reflect_kind(), ReflectKind::Opaque);
+        value.apply(&2u32);
+        assert_eq!(*value.lock().unwrap(), 2);
+
+        let other = Mutex::new(2u32);
+        assert_eq!(value.reflect_partial_eq(&other), Some(true));
+        assert_eq!(value.reflect_partial_eq(&value), Some(true));
+
+        value.apply(&Mutex::new(3u32));
+        let cloned = value.reflect_clone().unwrap().take::<Mutex<u32>>().unwrap();
+        assert_eq!(*cloned.lock().unwrap(), 3);
+
+        let lock = <RwLock<u32>>::from_reflect(&2u32).unwrap();
+        assert_eq!(*lock.read().unwrap(), 2);
+    }
+
+    #[test]
+    fn lock_hash_and_path() {
+        let value = RwLock::new(5u32);
+        assert_eq!(value.reflect_partial_eq(&5u32), Some(true));
+        assert_eq!(value.reflect_hash(), 5u32.reflect_hash());
+        assert_eq!(<Mutex<u32>>::type_path(), "vct_os::sync::Mutex<u32>");
+        assert_eq!(<RwLock<u32>>::crate_name(), Some("vct_os"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn compare_locks_both_ways() {
+        let a = Arc::new(Mutex::new(1u32));
+        let b = Arc::new(Mutex::new(1u32));
+        let handles: [_; 2] = core::array::from_fn(|index| {
+            let (x, y) = if index == 0 { (a.clone(), b.clone()) } else { (b.clone(), a.clone()) };
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    assert_eq!(x.reflect_partial_eq(&*y), Some(true));
+                }
+            })
+        });
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use core::time::Duration;
+use vct_os::time::Instant;
+use vct_reflect_derive::impl_full_reflect;
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, default, hash, partial_eq, partial_cmp, debug, serde)]
+    #[reflect(type_path = "core::time::Duration")]
+    struct Duration;
+}
+
+impl_full_reflect!{
+    #[reflect(opaque, clone, partial_cmp, debug)]
+    #[reflect(type_path = "vct_os::time::Instant")]
+    struct Instant;
+}
@@ -8,11 +8,32 @@ use crate::{
         attributes::{impl_custom_attributes_fn, impl_with_custom_attributes},
         docs_macro::impl_docs_fn,
         generics::impl_generic_fn,
-        type_struct::impl_type_fn,
+        type_struct::{impl_type_fn, impl_with_type_fn},
+        version_macro::impl_version_fn,
     },
     ops::Enum,
 };
 
+/// The representation of an enum in reflected serialization, same as serde-derive.
+///
+/// It's set by `#[reflect(tag = "...")]`, `#[reflect(tag = "...", content = "...")]`
+/// or `#[reflect(untagged)]`, it's ignored by the compact format.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EnumTagging {
+    /// `{"Variant": value}`, the default.
+    #[default]
+    External,
+    /// `{"tag": "Variant", ...fields}`, a tuple variant must have one field containing a struct.
+    Internal { tag: &'static str },
+    /// `{"tag": "Variant", "content": value}`.
+    Adjacent {
+        tag: &'static str,
+        content: &'static str,
+    },
+    /// `value`, the first variant that matches the data is used.
+    Untagged,
+}
+
 /// Container for storing compile-time enum information.
 #[derive(Clone, Debug)]
 pub struct EnumInfo {
@@ -23,16 +44,20 @@ pub struct EnumInfo {
     variant_indices: HashMap<&'static str, usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
+    version: u32,
+    tagging: EnumTagging,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
 
 impl EnumInfo {
     impl_type_fn!(ty);
+    impl_with_type_fn!(ty);
     impl_docs_fn!(docs);
     impl_generic_fn!(generics);
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
+    impl_version_fn!(version);
 
     /// Creates a new [`EnumInfo`].
     pub fn new<TEnum: Enum + TypePath>(variants: &[VariantInfo]) -> Self {
@@ -52,11 +77,26 @@ impl EnumInfo {
             variant_names,
             variant_indices,
             custom_attributes: None,
+            version: 0,
+            tagging: EnumTagging::External,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
     }
 
+    /// Sets the [`EnumTagging`] used by reflected serialization.
+    #[inline]
+    pub fn with_tagging(mut self, tagging: EnumTagging) -> Self {
+        self.tagging = tagging;
+        self
+    }
+
+    /// Returns the [`EnumTagging`] used by reflected serialization.
+    #[inline]
+    pub fn tagging(&self) -> EnumTagging {
+        self.tagging
+    }
+
     /// Returns the list of variant names.
     #[inline]
     pub fn variant_names(&self) -> &[&'static str] {
@@ -1,13 +1,13 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box};
 use core::fmt;
 use vct_os::sync::Arc;
 
-use crate::info::{
+use crate::{Reflect, info::{
     CustomAttributes, Type, TypeInfo, Typed,
     attributes::{impl_custom_attributes_fn, impl_with_custom_attributes},
     docs_macro::impl_docs_fn,
     type_struct::impl_type_fn,
-};
+}};
 
 /// A named (struct) field.
 #[derive(Clone, Debug)]
@@ -18,6 +18,10 @@ pub struct NamedField {
     type_info: fn() -> &'static TypeInfo,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
+    // The name used by serialization, `None` means the same as `name`.
+    rename: Option<&'static str>,
+    aliases: &'static [&'static str],
+    default: Option<fn() -> Box<dyn Reflect>>,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
@@ -36,6 +40,9 @@ impl NamedField {
             type_info: T::type_info,
             ty: Type::of::<T>(),
             custom_attributes: None,
+            rename: None,
+            aliases: &[],
+            default: None,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
@@ -47,6 +54,52 @@ impl NamedField {
         self.name
     }
 
+    /// Sets the name used by serialization, see `#[reflect(rename = "...")]`.
+    #[inline]
+    pub fn with_rename(mut self, rename: &'static str) -> Self {
+        self.rename = Some(rename);
+        self
+    }
+
+    /// Returns the name used by serialization, it's the field name if not renamed.
+    #[inline]
+    pub fn serde_name(&self) -> &'static str {
+        self.rename.unwrap_or(self.name)
+    }
+
+    /// Sets the other names accepted by deserialization, see `#[reflect(alias = "...")]`.
+    #[inline]
+    pub fn with_aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Returns the other names accepted by deserialization.
+    #[inline]
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns `true` if the given serialized name refers to this field.
+    #[inline]
+    pub fn is_serde_name(&self, name: &str) -> bool {
+        self.serde_name() == name || self.aliases.contains(&name)
+    }
+
+    /// Sets the function that creates the value when the field is missing in deserialization,
+    /// see `#[reflect(default)]`.
+    #[inline]
+    pub fn with_default(mut self, default: fn() -> Box<dyn Reflect>) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Creates the default value for the missing field, if set.
+    #[inline]
+    pub fn default_value(&self) -> Option<Box<dyn Reflect>> {
+        self.default.map(|default| default())
+    }
+
     /// Returns the field's [`TypeInfo`].
     #[inline]
     pub fn type_info(&self) -> &'static TypeInfo {
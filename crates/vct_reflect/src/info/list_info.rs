@@ -7,7 +7,7 @@ use crate::{
         attributes::{impl_custom_attributes_fn, impl_with_custom_attributes},
         docs_macro::impl_docs_fn,
         generics::impl_generic_fn,
-        type_struct::impl_type_fn,
+        type_struct::{impl_type_fn, impl_with_type_fn},
     },
     ops::List,
 };
@@ -29,6 +29,7 @@ pub struct ListInfo {
 impl ListInfo {
     impl_docs_fn!(docs);
     impl_type_fn!(ty);
+    impl_with_type_fn!(ty);
     impl_generic_fn!(generics);
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
@@ -1,5 +1,6 @@
 // A macro used to simplify code
 mod docs_macro;
+mod version_macro;
 
 mod type_path;
 pub use type_path::{DynamicTypePath, TypePath, TypePathTable};
@@ -50,7 +51,7 @@ pub use variant_info::{
 };
 
 mod enum_info;
-pub use enum_info::EnumInfo;
+pub use enum_info::{EnumInfo, EnumTagging};
 
 mod type_info_impl;
 pub use type_info_impl::{ReflectKind, ReflectKindError, TypeInfo};
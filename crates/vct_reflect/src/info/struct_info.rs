@@ -8,7 +8,8 @@ use crate::{
         attributes::{impl_custom_attributes_fn, impl_with_custom_attributes},
         docs_macro::impl_docs_fn,
         generics::impl_generic_fn,
-        type_struct::impl_type_fn,
+        type_struct::{impl_type_fn, impl_with_type_fn},
+        version_macro::impl_version_fn,
     },
     ops::Struct,
 };
@@ -20,9 +21,12 @@ pub struct StructInfo {
     generics: Generics,
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
+    serde_field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
+    version: u32,
+    deny_unknown_fields: bool,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
@@ -30,9 +34,11 @@ pub struct StructInfo {
 impl StructInfo {
     impl_docs_fn!(docs);
     impl_type_fn!(ty);
+    impl_with_type_fn!(ty);
     impl_generic_fn!(generics);
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
+    impl_version_fn!(version);
 
     /// Creates a new [`StructInfo`].
     ///
@@ -45,25 +51,48 @@ impl StructInfo {
             .collect();
 
         let field_names = fields.iter().map(NamedField::name).collect();
+        let serde_field_names = fields.iter().map(NamedField::serde_name).collect();
 
         Self {
             ty: Type::of::<T>(),
             generics: Generics::new(),
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
+            serde_field_names,
             field_indices,
             custom_attributes: None,
+            version: 0,
+            deny_unknown_fields: false,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
     }
 
+    /// Sets whether deserialization rejects unknown fields, see `#[reflect(deny_unknown_fields)]`.
+    #[inline]
+    pub fn with_deny_unknown_fields(mut self, deny: bool) -> Self {
+        self.deny_unknown_fields = deny;
+        self
+    }
+
+    /// Returns `true` if deserialization rejects unknown fields, they are ignored by default.
+    #[inline]
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
     /// Returns the list of field names.
     #[inline]
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
     }
 
+    /// Returns the list of field names used by serialization, see [`NamedField::serde_name`].
+    #[inline]
+    pub fn serde_field_names(&self) -> &[&'static str] {
+        &self.serde_field_names
+    }
+
     /// Returns the [`NamedField`] by name, if it exists.
     #[inline]
     pub fn field(&self, name: &str) -> Option<&NamedField> {
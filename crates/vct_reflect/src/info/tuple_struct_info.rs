@@ -7,7 +7,8 @@ use crate::{
         attributes::{impl_custom_attributes_fn, impl_with_custom_attributes},
         docs_macro::impl_docs_fn,
         generics::impl_generic_fn,
-        type_struct::impl_type_fn,
+        type_struct::{impl_type_fn, impl_with_type_fn},
+        version_macro::impl_version_fn,
     },
     ops::TupleStruct,
 };
@@ -20,6 +21,7 @@ pub struct TupleStructInfo {
     fields: Box<[UnnamedField]>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
+    version: u32,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
@@ -27,9 +29,11 @@ pub struct TupleStructInfo {
 impl TupleStructInfo {
     impl_docs_fn!(docs);
     impl_type_fn!(ty);
+    impl_with_type_fn!(ty);
     impl_generic_fn!(generics);
     impl_custom_attributes_fn!(custom_attributes);
     impl_with_custom_attributes!(custom_attributes);
+    impl_version_fn!(version);
 
     /// Creates a new [`TupleStructInfo`].
     ///
@@ -41,6 +45,7 @@ impl TupleStructInfo {
             generics: Generics::new(),
             fields: fields.to_vec().into_boxed_slice(),
             custom_attributes: None,
+            version: 0,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
@@ -112,6 +112,23 @@ impl TypeInfo {
         }
     }
 
+    /// Returns a copy of this info describing `ty` instead.
+    ///
+    /// Used by pass-through wrappers (e.g. `Box<T>`) which are laid out as the type they wrap.
+    pub(crate) fn with_type(&self, ty: Type) -> Self {
+        match self.clone() {
+            Self::Struct(info) => Self::Struct(info.with_type(ty)),
+            Self::TupleStruct(info) => Self::TupleStruct(info.with_type(ty)),
+            Self::Tuple(info) => Self::Tuple(info.with_type(ty)),
+            Self::List(info) => Self::List(info.with_type(ty)),
+            Self::Array(info) => Self::Array(info.with_type(ty)),
+            Self::Map(info) => Self::Map(info.with_type(ty)),
+            Self::Set(info) => Self::Set(info.with_type(ty)),
+            Self::Enum(info) => Self::Enum(info.with_type(ty)),
+            Self::Opaque(info) => Self::Opaque(info.with_type(ty)),
+        }
+    }
+
     /// Returns the [`TypeId`].
     #[inline]
     pub fn type_id(&self) -> TypeId {
@@ -178,6 +195,18 @@ impl TypeInfo {
         }
     }
 
+    /// Returns the schema version, see `#[reflect(version = N)]`.
+    ///
+    /// It's always `0` for the types that cannot be derived, such as lists and maps.
+    pub fn version(&self) -> u32 {
+        match self {
+            Self::Struct(info) => info.version(),
+            Self::TupleStruct(info) => info.version(),
+            Self::Enum(info) => info.version(),
+            _ => 0,
+        }
+    }
+
     /// Returns the docs, if any.
     #[cfg(feature = "reflect_docs")]
     pub fn docs(&self) -> Option<&str> {
@@ -145,3 +145,17 @@ macro_rules! impl_type_fn {
 }
 
 pub(crate) use impl_type_fn;
+
+/// impl `with_type`, only for the infos stored in [`TypeInfo`](crate::info::TypeInfo).
+macro_rules! impl_with_type_fn {
+    ($field:ident) => {
+        /// Replaces the underlying [`Type`](crate::info::Type).
+        #[inline]
+        pub(crate) fn with_type(mut self, ty: $crate::info::Type) -> Self {
+            self.$field = ty;
+            self
+        }
+    };
+}
+
+pub(crate) use impl_with_type_fn;
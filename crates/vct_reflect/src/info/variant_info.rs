@@ -64,9 +64,11 @@ pub struct StructVariantInfo {
     name: &'static str,
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
+    serde_field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
     // Use `Option` to reduce unnecessary heap requests (when empty content).
     custom_attributes: Option<Arc<CustomAttributes>>,
+    deny_unknown_fields: bool,
     #[cfg(feature = "reflect_docs")]
     docs: Option<&'static str>,
 }
@@ -85,13 +87,16 @@ impl StructVariantInfo {
             .collect();
 
         let field_names = fields.iter().map(NamedField::name).collect();
+        let serde_field_names = fields.iter().map(NamedField::serde_name).collect();
 
         Self {
             name,
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
+            serde_field_names,
             field_indices,
             custom_attributes: None,
+            deny_unknown_fields: false,
             #[cfg(feature = "reflect_docs")]
             docs: None,
         }
@@ -103,12 +108,33 @@ impl StructVariantInfo {
         self.name
     }
 
+    /// Sets whether deserialization rejects unknown fields,
+    /// see `#[reflect(deny_unknown_fields)]` on the enum.
+    #[inline]
+    pub fn with_deny_unknown_fields(mut self, deny: bool) -> Self {
+        self.deny_unknown_fields = deny;
+        self
+    }
+
+    /// Returns `true` if deserialization rejects unknown fields, they are ignored by default.
+    #[inline]
+    pub fn deny_unknown_fields(&self) -> bool {
+        self.deny_unknown_fields
+    }
+
     /// A slice containing the names of all fields in order.
     #[inline]
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
     }
 
+    /// A slice containing the names of all fields used by serialization,
+    /// see [`NamedField::serde_name`].
+    #[inline]
+    pub fn serde_field_names(&self) -> &[&'static str] {
+        &self.serde_field_names
+    }
+
     /// Get the field with the given name.
     #[inline]
     pub fn field(&self, name: &str) -> Option<&NamedField> {
@@ -0,0 +1,23 @@
+/// Implements `version` and `with_version` helpers.
+macro_rules! impl_version_fn {
+    ($field:ident) => {
+        /// Returns the schema version, the default is `0`.
+        ///
+        /// It's set by `#[reflect(version = N)]`, and used for migrating serialized data,
+        /// see [`TypeTraitMigrate`](crate::registry::TypeTraitMigrate).
+        #[inline]
+        pub fn version(&self) -> u32 {
+            self.$field
+        }
+
+        /// Sets the schema version.
+        ///
+        /// Used by the proc-macro crate.
+        #[inline]
+        pub fn with_version(self, $field: u32) -> Self {
+            Self { $field, ..self }
+        }
+    };
+}
+
+pub(crate) use impl_version_fn;
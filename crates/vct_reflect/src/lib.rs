@@ -7,13 +7,19 @@ extern crate alloc;
 
 pub mod access;
 pub mod cell;
+pub mod diff;
+pub mod func;
 pub mod info;
 pub mod ops;
 pub mod registry;
 pub mod serde;
+pub mod visit;
 
 mod reflect;
-pub use reflect::{FromReflect, Reflect, Reflectable, reflect_hasher};
+pub use reflect::{
+    FromReflect, FromReflectError, FromReflectErrorKind, Reflect, ReflectAlias, Reflectable,
+    reflect_hasher,
+};
 
 mod impls;
 
@@ -1,5 +1,6 @@
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -133,6 +134,11 @@ impl Reflect for DynamicArray {
         array_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicArray(")?;
@@ -364,6 +370,27 @@ pub fn array_partial_eq(x: &dyn Array, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Items are compared lexicographically like slices, returns `None` if `y` is not an array.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn array_partial_cmp(x: &dyn Array, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Array(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    for (x_item, y_item) in x.iter().zip(y.iter()) {
+        match x_item.reflect_partial_cmp(y_item) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    x.len().partial_cmp(&y.len())
+}
+
 /// A function used to assist in the implementation of `reflect_hash`
 ///
 /// Avoid compilation overhead when implementing multiple types.
@@ -5,6 +5,7 @@ use alloc::{
     string::String,
 };
 use core::{
+    cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -295,6 +296,11 @@ impl Reflect for DynamicEnum {
         enum_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        enum_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_hash(&self) -> Option<u64> {
         enum_hash(self)
@@ -553,6 +559,40 @@ pub fn enum_partial_eq(x: &dyn Enum, y: &dyn Reflect) -> Option<bool> {
     }
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Different variants are ordered by their indices, the fields of the same variant are
+/// compared in order, like `#[derive(PartialOrd)]`. Returns `None` if `y` is not an enum.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn enum_partial_cmp(x: &dyn Enum, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Enum(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    if x.variant_name() != y.variant_name() {
+        return x.variant_index().partial_cmp(&y.variant_index());
+    }
+
+    if x.variant_kind() != y.variant_kind() || x.field_len() != y.field_len() {
+        return None;
+    }
+
+    for (idx, field) in x.iter_fields().enumerate() {
+        let y_field = match field.name() {
+            Some(name) => y.field(name)?,
+            None => y.field_at(idx)?,
+        };
+        match field.value().reflect_partial_cmp(y_field) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// A function used to assist in the implementation of `reflect_hash`
 ///
 /// Avoid compilation overhead when implementing multiple types.
@@ -594,3 +634,81 @@ pub(crate) fn enum_debug(dyn_enum: &dyn Enum, f: &mut fmt::Formatter<'_>) -> fmt
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::{Typed, VariantKind},
+        ops::{DynamicEnum, DynamicStruct, DynamicVariant, Enum},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    enum Shape {
+        Circle { radius: u32 },
+        Point(u32, #[reflect(ignore)] Vec<u32>, u32),
+        Empty,
+    }
+
+    #[test]
+    fn derive_enum() {
+        let circle = Shape::Circle { radius: 2 };
+        assert_eq!(circle.variant_name(), "Circle");
+        assert_eq!(circle.variant_index(), 0);
+        assert_eq!(circle.variant_kind(), VariantKind::Struct);
+        assert_eq!(circle.field("radius").unwrap().downcast_ref::<u32>(), Some(&2));
+        assert_eq!(circle.name_at(0), Some("radius"));
+
+        // ignored fields are skipped by the reflection indices
+        let point = Shape::Point(1, Vec::new(), 3);
+        assert_eq!(point.field_len(), 2);
+        assert_eq!(point.field_at(1).unwrap().downcast_ref::<u32>(), Some(&3));
+        assert_eq!(Shape::Empty.variant_index(), 2);
+
+        let info = Shape::type_info().as_enum().unwrap();
+        assert_eq!(info.variant_len(), 3);
+        assert_eq!(info.variant_at(1).unwrap().name(), "Point");
+
+        let dynamic = circle.to_dynamic();
+        assert_eq!(Shape::from_reflect(dynamic.as_ref()), Some(Shape::Circle { radius: 2 }));
+        assert_eq!(
+            Shape::from_reflect(point.to_dynamic().as_ref()),
+            Some(Shape::Point(1, Vec::new(), 3)),
+        );
+
+        let err = point.reflect_clone().err().unwrap();
+        assert!(err.to_string().contains("Point::1"));
+        assert!(circle.reflect_clone().unwrap().reflect_partial_eq(&circle).unwrap());
+    }
+
+    #[test]
+    fn derive_enum_apply() {
+        let mut shape = Shape::Empty;
+        shape.apply(&Shape::Circle { radius: 2 });
+        assert_eq!(shape, Shape::Circle { radius: 2 });
+
+        let mut fields = DynamicStruct::new();
+        fields.insert("radius", 5u32);
+        shape.apply(&DynamicEnum::new("Circle", DynamicVariant::Struct(fields)));
+        assert_eq!(shape, Shape::Circle { radius: 5 });
+
+        let missing = DynamicEnum::new("Circle", DynamicVariant::Struct(DynamicStruct::new()));
+        let err = Shape::Empty.try_apply(&missing).err().unwrap();
+        assert!(err.to_string().contains("radius"));
+
+        let mut fields = DynamicStruct::new();
+        fields.insert("radius", "wide");
+        let wrong = DynamicEnum::new("Circle", DynamicVariant::Struct(fields));
+        let err = Shape::try_from_reflect(&wrong).err().unwrap();
+        assert_eq!(err.path().len(), 1);
+        assert!(err.to_string().contains("radius"));
+
+        let unknown = DynamicEnum::new("Square", DynamicVariant::Unit);
+        assert!(shape.try_apply(&unknown).is_err());
+        assert!(Shape::from_reflect(&unknown).is_none());
+    }
+}
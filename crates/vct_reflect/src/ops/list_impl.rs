@@ -8,6 +8,7 @@ use crate::{
 };
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
 };
@@ -157,6 +158,11 @@ impl Reflect for DynamicList {
         list_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        list_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicList(")?;
@@ -432,6 +438,27 @@ pub fn list_partial_eq(x: &dyn List, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Items are compared lexicographically like slices, returns `None` if `y` is not a list.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn list_partial_cmp(x: &dyn List, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::List(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    for (x_item, y_item) in x.iter().zip(y.iter()) {
+        match x_item.reflect_partial_cmp(y_item) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    x.len().partial_cmp(&y.len())
+}
+
 /// A function used to assist in the implementation of `reflect_partial_eq`
 ///
 /// Avoid compilation overhead when implementing multiple types.
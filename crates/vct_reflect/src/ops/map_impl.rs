@@ -6,7 +6,7 @@ use crate::{
     reflect::impl_cast_reflect_fn,
 };
 use alloc::{boxed::Box, format, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 use vct_utils::collections::{HashTable, hash_table};
 
 /// Representing [`Map`], used to dynamically modify the type of data and information.
@@ -445,6 +445,34 @@ pub fn map_partial_eq(x: &dyn Map, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Entries are compared lexicographically in iteration order, key before value,
+/// like `BTreeMap`. Returns `None` if `y` is not a map.
+///
+/// This is only meaningful if both maps iterate in key order.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn map_partial_cmp(x: &dyn Map, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Map(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    for ((x_key, x_val), (y_key, y_val)) in x.iter().zip(y.iter()) {
+        match x_key.reflect_partial_cmp(y_key) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+        match x_val.reflect_partial_cmp(y_val) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    x.len().partial_cmp(&y.len())
+}
+
 /// The default debug formatter for [`Map`] types.
 ///
 /// Avoid compilation overhead when implementing multiple types.
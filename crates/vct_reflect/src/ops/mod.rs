@@ -9,40 +9,50 @@ pub use kind::{ReflectMut, ReflectOwned, ReflectRef};
 
 mod struct_impl;
 pub(crate) use struct_impl::struct_debug; // Only used for `PartialReflect::reflect_debug`
-pub use struct_impl::{DynamicStruct, GetStructField, Struct, StructFieldIter, struct_partial_eq};
+pub use struct_impl::{
+    DynamicStruct, GetStructField, Struct, StructFieldIter, struct_partial_cmp,
+    struct_partial_eq,
+};
 
 mod tuple_struct_impl;
 pub(crate) use tuple_struct_impl::tuple_struct_debug; // Only used for `PartialReflect::reflect_debug`
 pub use tuple_struct_impl::{
     DynamicTupleStruct, GetTupleStructField, TupleStruct, TupleStructFieldIter,
-    tuple_struct_partial_eq,
+    tuple_struct_partial_cmp, tuple_struct_partial_eq,
 };
 
 mod tuple_impl;
 pub(crate) use tuple_impl::tuple_debug; // Only used for `PartialReflect::reflect_debug`
 pub use tuple_impl::{
-    DynamicTuple, GetTupleField, Tuple, TupleFieldIter, tuple_partial_eq, tuple_try_apply,
+    DynamicTuple, GetTupleField, Tuple, TupleFieldIter, tuple_partial_cmp, tuple_partial_eq,
+    tuple_try_apply,
 };
 
 mod list_impl;
 pub(crate) use list_impl::list_debug; // Only used for `PartialReflect::reflect_debug`
-pub use list_impl::{DynamicList, List, ListItemIter, list_partial_eq};
+pub use list_impl::{
+    DynamicList, List, ListItemIter, list_hash, list_partial_cmp, list_partial_eq,
+    list_try_apply,
+};
 
 mod array_impl;
 pub(crate) use array_impl::array_debug; // Only used for `PartialReflect::reflect_debug`
-pub use array_impl::{Array, ArrayItemIter, DynamicArray, array_partial_eq};
+pub use array_impl::{
+    Array, ArrayItemIter, DynamicArray, array_hash, array_partial_cmp, array_partial_eq,
+    array_try_apply,
+};
 
 mod map_impl;
 pub(crate) use map_impl::map_debug; // Only used for `PartialReflect::reflect_debug`
-pub use map_impl::{DynamicMap, Map, map_partial_eq};
+pub use map_impl::{DynamicMap, Map, map_partial_cmp, map_partial_eq, map_try_apply};
 
 mod set_impl;
 pub(crate) use set_impl::set_debug; // Only used for `PartialReflect::reflect_debug`
-pub use set_impl::{DynamicSet, Set, set_partial_eq};
+pub use set_impl::{DynamicSet, Set, set_partial_cmp, set_partial_eq, set_try_apply};
 
 mod variant_impl;
 pub use variant_impl::{DynamicVariant, VariantField, VariantFieldIter};
 
 mod enum_impl;
 pub(crate) use enum_impl::enum_debug; // Only used for `PartialReflect::reflect_debug`
-pub use enum_impl::{DynamicEnum, Enum, enum_partial_eq};
+pub use enum_impl::{DynamicEnum, Enum, enum_hash, enum_partial_cmp, enum_partial_eq};
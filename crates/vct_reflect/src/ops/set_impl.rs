@@ -6,7 +6,7 @@ use crate::{
     reflect::impl_cast_reflect_fn,
 };
 use alloc::{boxed::Box, format, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 use vct_utils::collections::{HashTable, hash_table};
 
 /// Represents a [`Set`], used to dynamically modify data and its reflected type information.
@@ -423,6 +423,30 @@ pub fn set_partial_eq(x: &dyn Set, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Values are compared lexicographically in iteration order, like `BTreeSet`.
+/// Returns `None` if `y` is not a set.
+///
+/// This is only meaningful if both sets iterate in value order.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn set_partial_cmp(x: &dyn Set, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Set(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    for (x_val, y_val) in x.iter().zip(y.iter()) {
+        match x_val.reflect_partial_cmp(y_val) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    x.len().partial_cmp(&y.len())
+}
+
 /// The default debug formatter for [`Set`] types.
 ///
 /// Avoid compilation overhead when implementing multiple types.
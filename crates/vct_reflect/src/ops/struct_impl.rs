@@ -6,7 +6,7 @@ use crate::{
     reflect::impl_cast_reflect_fn,
 };
 use alloc::{borrow::Cow, boxed::Box, string::ToString, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 use vct_utils::collections::HashMap;
 
 /// Represents a [`Struct`], used to dynamically modify data and its reflected type information.
@@ -124,6 +124,20 @@ impl DynamicStruct {
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
     }
+
+    /// Removes the field with the given name, returning its value.
+    ///
+    /// The order of the remaining fields is preserved.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Reflect>> {
+        let index = self.field_indices.remove(name)?;
+        self.field_names.remove(index);
+        for later in &self.field_names[index..] {
+            if let Some(i) = self.field_indices.get_mut(later) {
+                *i -= 1;
+            }
+        }
+        Some(self.fields.remove(index))
+    }
 }
 
 impl Reflect for DynamicStruct {
@@ -169,6 +183,11 @@ impl Reflect for DynamicStruct {
         struct_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        struct_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicStruct(")?;
@@ -437,6 +456,33 @@ pub fn struct_partial_eq(x: &dyn Struct, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Fields are compared in the order of `x`, like `#[derive(PartialOrd)]`,
+/// returns `None` if `y` is not a struct with the same fields.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn struct_partial_cmp(x: &dyn Struct, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Struct(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    if x.field_len() != y.field_len() {
+        return None;
+    }
+
+    for (idx, x_field) in x.iter_fields().enumerate() {
+        let y_field = y.field(x.name_at(idx)?)?;
+        match x_field.reflect_partial_cmp(y_field) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// The default debug formatter for [`Struct`] types.
 ///
 /// Avoid compilation overhead when implementing multiple types.
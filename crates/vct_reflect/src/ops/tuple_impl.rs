@@ -6,7 +6,7 @@ use crate::{
     reflect::impl_cast_reflect_fn,
 };
 use alloc::{boxed::Box, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 
 /// Represents a [`Tuple`], used to dynamically modify data and its reflected type information.
 ///
@@ -150,6 +150,11 @@ impl Reflect for DynamicTuple {
         tuple_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        tuple_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicTuple(")?;
@@ -391,6 +396,31 @@ pub fn tuple_partial_eq(x: &dyn Tuple, y: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Fields are compared in order, returns `None` if `y` is not a tuple of the same length.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn tuple_partial_cmp(x: &dyn Tuple, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Tuple(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    if x.field_len() != y.field_len() {
+        return None;
+    }
+
+    for (x_field, y_field) in x.iter_fields().zip(y.iter_fields()) {
+        match x_field.reflect_partial_cmp(y_field) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// The default debug formatter for [`Tuple`] types.
 ///
 /// Avoid compilation overhead when implementing multiple types.
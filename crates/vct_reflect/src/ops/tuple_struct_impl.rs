@@ -1,5 +1,5 @@
 use alloc::{boxed::Box, vec::Vec};
-use core::fmt;
+use core::{cmp::Ordering, fmt};
 
 use crate::{
     Reflect,
@@ -155,6 +155,11 @@ impl Reflect for DynamicTupleStruct {
         tuple_struct_partial_eq(self, other)
     }
 
+    #[inline]
+    fn reflect_partial_cmp(&self, other: &dyn Reflect) -> Option<Ordering> {
+        tuple_struct_partial_cmp(self, other)
+    }
+
     #[inline]
     fn reflect_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "DynamicTupleStruct(")?;
@@ -382,6 +387,31 @@ pub fn tuple_struct_partial_eq(x: &dyn TupleStruct, y: &dyn Reflect) -> Option<b
     Some(true)
 }
 
+/// A function used to assist in the implementation of `reflect_partial_cmp`
+///
+/// Fields are compared in order, returns `None` if `y` is not a tuple struct of the same length.
+///
+/// Avoid compilation overhead when implementing multiple types.
+#[inline(never)]
+pub fn tuple_struct_partial_cmp(x: &dyn TupleStruct, y: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::TupleStruct(y) = y.reflect_ref() else {
+        return None;
+    };
+
+    if x.field_len() != y.field_len() {
+        return None;
+    }
+
+    for (x_field, y_field) in x.iter_fields().zip(y.iter_fields()) {
+        match x_field.reflect_partial_cmp(y_field) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// The default debug formatter for [`Tuple`] types.
 ///
 /// Avoid compilation overhead when implementing multiple types.
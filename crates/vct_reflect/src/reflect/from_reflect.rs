@@ -1,4 +1,4 @@
-use crate::Reflect;
+use crate::{FromReflectError, Reflect, info::Typed};
 use alloc::boxed::Box;
 
 /// A trait that enables types to be dynamically constructed from reflected data.
@@ -8,6 +8,19 @@ pub trait FromReflect: Reflect + Sized {
     /// Constructs a concrete instance of `Self` from a reflected value.
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self>;
 
+    /// Constructs a concrete instance of `Self` from a reflected value,
+    /// returns the path and the reason on failure.
+    ///
+    /// The default implementation calls [`from_reflect`](Self::from_reflect) and only reports
+    /// the mismatched types of the whole value. It's overridden by the derive macro and
+    /// the impls of containers, so the failing field can be located.
+    fn try_from_reflect(reflect: &dyn Reflect) -> Result<Self, FromReflectError>
+    where
+        Self: Typed,
+    {
+        Self::from_reflect(reflect).ok_or_else(|| FromReflectError::mismatched::<Self>(reflect))
+    }
+
     /// Attempts to downcast the given value to `Self`; if that fails, constructs
     /// the value using [`from_reflect`].
     fn take_from_reflect(reflect: Box<dyn Reflect>) -> Result<Self, Box<dyn Reflect>> {
@@ -0,0 +1,196 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::{error, fmt};
+
+use crate::{
+    Reflect,
+    access::Accessor,
+    info::{ReflectKind, Typed},
+};
+
+/// The reason of a [`FromReflectError`].
+#[derive(Debug)]
+pub enum FromReflectErrorKind {
+    /// The value cannot be converted to the expected type.
+    MismatchedTypes {
+        expected_type: Cow<'static, str>,
+        expected_kind: ReflectKind,
+        actual_type: Cow<'static, str>,
+        actual_kind: ReflectKind,
+    },
+    /// A field without default value is missing.
+    MissingField { expected_type: Cow<'static, str> },
+    /// The length of an array-like value does not match, e.g. a list of 3 items to `[u8; 4]`.
+    DifferentSize {
+        expected_type: Cow<'static, str>,
+        expected_size: usize,
+        actual_size: usize,
+    },
+}
+
+/// An error returned by [`FromReflect::try_from_reflect`].
+///
+/// It records the path from the converted value to the failing field, e.g. `.items[2].name`.
+///
+/// [`FromReflect::try_from_reflect`]: crate::FromReflect::try_from_reflect
+#[derive(Debug)]
+pub struct FromReflectError {
+    path: Vec<Accessor<'static>>,
+    kind: FromReflectErrorKind,
+}
+
+impl FromReflectError {
+    /// Creates an error with an empty path.
+    #[inline]
+    pub fn new(kind: FromReflectErrorKind) -> Self {
+        Self {
+            path: Vec::new(),
+            kind,
+        }
+    }
+
+    /// Creates an error that `actual` cannot be converted to `T`.
+    pub fn mismatched<T: Typed>(actual: &dyn Reflect) -> Self {
+        Self::new(FromReflectErrorKind::MismatchedTypes {
+            expected_type: Cow::Borrowed(T::type_path()),
+            expected_kind: T::type_info().kind(),
+            actual_type: Cow::Owned(actual.reflect_type_path().into()),
+            actual_kind: actual.reflect_kind(),
+        })
+    }
+
+    /// Creates an error that a field of type `T` is missing.
+    ///
+    /// The field should be added by [`with_field`](Self::with_field) or
+    /// [`with_tuple_index`](Self::with_tuple_index).
+    #[inline]
+    pub fn missing_field<T: Typed>() -> Self {
+        Self::new(FromReflectErrorKind::MissingField {
+            expected_type: Cow::Borrowed(T::type_path()),
+        })
+    }
+
+    /// Creates an error that an array-like value of `actual_size` cannot be converted to `T`.
+    #[inline]
+    pub fn different_size<T: Typed>(expected_size: usize, actual_size: usize) -> Self {
+        Self::new(FromReflectErrorKind::DifferentSize {
+            expected_type: Cow::Borrowed(T::type_path()),
+            expected_size,
+            actual_size,
+        })
+    }
+
+    /// Prepends an accessor to the path, used when the error comes from an inner value.
+    #[inline]
+    pub fn with_accessor(mut self, accessor: Accessor<'static>) -> Self {
+        self.path.insert(0, accessor);
+        self
+    }
+
+    /// Prepends a named field to the path.
+    #[inline]
+    pub fn with_field(self, name: &'static str) -> Self {
+        self.with_accessor(Accessor::FieldName(Cow::Borrowed(name)))
+    }
+
+    /// Prepends a tuple field to the path.
+    #[inline]
+    pub fn with_tuple_index(self, index: usize) -> Self {
+        self.with_accessor(Accessor::TupleIndex(index))
+    }
+
+    /// Returns the path to the failing value, empty if it's the converted value itself.
+    #[inline]
+    pub fn path(&self) -> &[Accessor<'static>] {
+        &self.path
+    }
+
+    /// Returns the reason of the error.
+    #[inline]
+    pub fn kind(&self) -> &FromReflectErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for FromReflectErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MismatchedTypes {
+                expected_type,
+                expected_kind,
+                actual_type,
+                actual_kind,
+            } => write!(
+                f,
+                "expected `{expected_type}` ({expected_kind}), found `{actual_type}` ({actual_kind})"
+            ),
+            Self::MissingField { expected_type } => {
+                write!(f, "missing field of type `{expected_type}`")
+            }
+            Self::DifferentSize {
+                expected_type,
+                expected_size,
+                actual_size,
+            } => write!(
+                f,
+                "expected {expected_size} items for `{expected_type}`, found {actual_size}"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for FromReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)?;
+        if !self.path.is_empty() {
+            f.write_str(" at `")?;
+            for accessor in &self.path {
+                fmt::Display::fmt(accessor, f)?;
+            }
+            f.write_str("`")?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for FromReflectError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        borrow::Cow,
+        boxed::Box,
+        collections::BTreeSet,
+        string::{String, ToString},
+        sync::Arc,
+        vec,
+        vec::Vec,
+    };
+
+    use crate::{FromReflect, Reflect, info::Typed};
+
+    fn error_of<T: FromReflect + Typed>(value: &dyn Reflect) -> String {
+        T::try_from_reflect(value).err().unwrap().to_string()
+    }
+
+    #[test]
+    fn container_paths() {
+        let tuple = (1_u32, String::from("x"));
+        assert!(error_of::<(u32, u32)>(&tuple).ends_with("at `.1`"));
+
+        let result: Result<String, u32> = Ok(String::from("x"));
+        assert!(error_of::<Result<u32, u32>>(&result).ends_with("at `.0`"));
+
+        let set = BTreeSet::from([String::from("x")]);
+        assert!(error_of::<BTreeSet<u32>>(&set).ends_with("at `[\"x\"]`"));
+
+        let list = vec![String::from("x")];
+        assert!(error_of::<Cow<'static, [u32]>>(&list).ends_with("at `[0]`"));
+        assert!(error_of::<Arc<Vec<u32>>>(&list).ends_with("at `[0]`"));
+        assert!(error_of::<Box<Vec<u32>>>(&list).ends_with("at `[0]`"));
+
+        let nested = vec![(0_u32, Ok::<String, u32>(String::from("x")))];
+        let message = error_of::<Vec<(u32, Result<u32, u32>)>>(&nested);
+        assert!(message.starts_with("expected `u32`"));
+        assert!(message.ends_with("at `[0].1.0`"));
+    }
+}
@@ -14,5 +14,11 @@ pub(crate) use reflect_impl::impl_cast_reflect_fn;
 mod from_reflect;
 pub use from_reflect::FromReflect;
 
+mod from_reflect_error;
+pub use from_reflect_error::{FromReflectError, FromReflectErrorKind};
+
+mod reflect_alias;
+pub use reflect_alias::ReflectAlias;
+
 mod reflectable;
 pub use reflectable::Reflectable;
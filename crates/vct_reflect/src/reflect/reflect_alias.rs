@@ -0,0 +1,143 @@
+#![expect(unsafe_code, reason = "Alias wrappers cast references between types with the same layout.")]
+
+use crate::{FromReflect, Reflect};
+use alloc::boxed::Box;
+
+/// A trait for wrappers that reflect a foreign type in its place.
+///
+/// Reflection traits cannot be implemented for types from other crates directly,
+/// so a `#[repr(transparent)]` wrapper is reflected with the path of the foreign type,
+/// and the value is converted between the two when needed.
+///
+/// Usually implemented by `#[derive(Reflect)]` with `#[reflect(alias = B)]`,
+/// which also asserts that the two types have the same size and alignment.
+/// The derived wrapper is reflected as an opaque value, the fields of the foreign type
+/// are not reflected.
+///
+/// # Safety
+///
+/// `Self` must have the same memory layout as [`Self::Alias`],
+/// so that references can be cast between the two.
+pub unsafe trait ReflectAlias: Reflect + Sized {
+    /// The foreign type reflected by this wrapper.
+    type Alias;
+
+    /// Wraps the foreign value.
+    fn from_alias(alias: Self::Alias) -> Self;
+
+    /// Unwraps the foreign value.
+    fn into_alias(self) -> Self::Alias;
+
+    /// Returns the foreign value.
+    #[inline]
+    fn as_alias(&self) -> &Self::Alias {
+        // Safety: `Self` and `Self::Alias` have the same layout.
+        unsafe { &*(self as *const Self as *const Self::Alias) }
+    }
+
+    /// Returns the foreign value.
+    #[inline]
+    fn as_alias_mut(&mut self) -> &mut Self::Alias {
+        // Safety: `Self` and `Self::Alias` have the same layout.
+        unsafe { &mut *(self as *mut Self as *mut Self::Alias) }
+    }
+
+    /// Views the foreign value as the wrapper, so it can be reflected in place.
+    #[inline]
+    fn from_alias_ref(alias: &Self::Alias) -> &Self {
+        // Safety: `Self` and `Self::Alias` have the same layout.
+        unsafe { &*(alias as *const Self::Alias as *const Self) }
+    }
+
+    /// Views the foreign value as the wrapper, so it can be reflected in place.
+    #[inline]
+    fn from_alias_mut(alias: &mut Self::Alias) -> &mut Self {
+        // Safety: `Self` and `Self::Alias` have the same layout.
+        unsafe { &mut *(alias as *mut Self::Alias as *mut Self) }
+    }
+
+    /// Constructs the foreign value from a reflected value, see [`FromReflect::from_reflect`].
+    #[inline]
+    fn alias_from_reflect(reflect: &dyn Reflect) -> Option<Self::Alias>
+    where
+        Self: FromReflect,
+    {
+        Self::from_reflect(reflect).map(Self::into_alias)
+    }
+
+    /// Sets the foreign value with a reflected value, see [`Reflect::set`].
+    #[inline]
+    fn set_alias(alias: &mut Self::Alias, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        Self::from_alias_mut(alias).set(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use vct_reflect_derive::Reflect;
+
+    use super::ReflectAlias;
+    use crate::{
+        FromReflect, Reflect,
+        info::{ReflectKind, TypePath, Typed},
+    };
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Foreign {
+        content: u64,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(alias = Foreign, type_path = "foreign::Foreign", clone, partial_eq, debug)]
+    #[repr(transparent)]
+    struct Wrapper(Foreign);
+
+    mod foreign {
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Point(pub u32);
+    }
+
+    #[derive(Reflect, Clone)]
+    #[reflect(alias = foreign::Point, clone)]
+    #[repr(transparent)]
+    struct PointWrapper(foreign::Point);
+
+    mod relative {
+        use vct_reflect_derive::Reflect;
+
+        // Relative paths (`crate::`, `self::`, `super::`) require an explicit type path.
+        #[derive(Reflect, Clone)]
+        #[reflect(alias = super::foreign::Point, type_path = "geometry::Point", clone)]
+        #[repr(transparent)]
+        pub struct RelativeWrapper(pub super::foreign::Point);
+    }
+
+    #[test]
+    fn alias_reflection() {
+        assert_eq!(Wrapper::type_path(), "foreign::Foreign");
+        assert_eq!(Wrapper::type_info().kind(), ReflectKind::Opaque);
+
+        let mut foreign = Foreign { content: 1 };
+        let wrapper = Wrapper::from_alias_ref(&foreign);
+        assert_eq!(wrapper.reflect_kind(), ReflectKind::Opaque);
+        assert_eq!(wrapper.as_alias(), &foreign);
+
+        let other = Wrapper::from_alias(Foreign { content: 2 });
+        assert_eq!(other.reflect_partial_eq(wrapper), Some(false));
+        assert_eq!(Wrapper::alias_from_reflect(&other), Some(Foreign { content: 2 }));
+
+        Wrapper::set_alias(&mut foreign, Box::new(other)).unwrap();
+        assert_eq!(foreign.content, 2);
+        let converted = Wrapper::from_reflect(Wrapper::from_alias_ref(&foreign));
+        assert_eq!(converted.map(Wrapper::into_alias), Some(Foreign { content: 2 }));
+    }
+
+    #[test]
+    fn alias_type_paths() {
+        assert_eq!(PointWrapper::type_path(), "foreign::Point");
+        assert_eq!(PointWrapper::type_ident(), "Point");
+        assert_eq!(relative::RelativeWrapper::type_path(), "geometry::Point");
+        assert_eq!(relative::RelativeWrapper(foreign::Point(1)).as_alias().0, 1);
+    }
+}
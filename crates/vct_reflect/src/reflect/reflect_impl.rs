@@ -13,6 +13,7 @@ use alloc::{
 };
 use core::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt,
 };
 
@@ -189,6 +190,18 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
         None
     }
 
+    /// Returns a "partial comparison" result.
+    ///
+    /// If the underlying type does not support ordering, returns `None`.
+    /// Lists, arrays, tuples, `BTreeMap` and `BTreeSet` are compared lexicographically,
+    /// see [`list_partial_cmp`].
+    ///
+    /// [`list_partial_cmp`]: crate::ops::list_partial_cmp
+    #[inline]
+    fn reflect_partial_cmp(&self, _other: &dyn Reflect) -> Option<Ordering> {
+        None
+    }
+
     /// Returns a hash of the value (which includes the type).
     ///
     /// If the underlying type does not support hashing, returns `None`.
@@ -221,6 +234,23 @@ pub trait Reflect: DynamicTypePath + DynamicTyped + Send + Sync + Any {
     }
 }
 
+/// Allows `Cow<'_, dyn Reflect>`, e.g. borrowed keys of [`Accessor::MapKey`].
+///
+/// [`Accessor::MapKey`]: crate::access::Accessor::MapKey
+impl ToOwned for dyn Reflect {
+    type Owned = Box<dyn Reflect>;
+
+    /// Clones the value with [`Reflect::reflect_clone`], or [`Reflect::to_dynamic`] if it fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is [opaque](ReflectKind::Opaque) and doesn't support `reflect_clone`.
+    #[inline]
+    fn to_owned(&self) -> Box<dyn Reflect> {
+        self.reflect_clone().unwrap_or_else(|_| self.to_dynamic())
+    }
+}
+
 impl dyn Reflect {
     /// Returns `true` if the underlying value is of type `T`.
     #[inline]
@@ -331,3 +361,229 @@ macro_rules! impl_cast_reflect_fn {
 }
 
 pub(crate) use impl_cast_reflect_fn;
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        boxed::Box,
+        collections::{BTreeMap, BTreeSet},
+        format,
+        string::String,
+        vec,
+        vec::Vec,
+    };
+    use core::{
+        cmp::Ordering,
+        fmt,
+        hash::{Hash, Hasher},
+    };
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect, Reflect,
+        ops::DynamicStruct,
+        registry::{GetTypeTraits, TypeTraitDefault},
+    };
+
+    #[allow(clippy::ptr_arg, reason = "Custom functions take `&T` of the field type.")]
+    fn hash_ignoring_case(name: &String, hasher: &mut impl Hasher) {
+        name.to_lowercase().hash(hasher);
+    }
+
+    #[allow(clippy::ptr_arg, reason = "Custom functions take `&T` of the field type.")]
+    fn eq_ignoring_case(a: &String, b: &String) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+
+    fn default_count() -> u32 {
+        7
+    }
+
+    fn debug_hidden(_: &String, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<hidden>")
+    }
+
+    #[derive(Reflect)]
+    #[reflect(partial_cmp = cmp_by_count)]
+    struct Fields {
+        #[reflect(hash = hash_ignoring_case, partial_eq = eq_ignoring_case)]
+        name: String,
+        #[reflect(default = default_count)]
+        count: u32,
+        #[reflect(debug = debug_hidden)]
+        secret: String,
+    }
+
+    fn cmp_by_count(a: &Fields, b: &Fields) -> Option<Ordering> {
+        a.count.partial_cmp(&b.count)
+    }
+
+    fn clone_reset(value: &Counter) -> Counter {
+        Counter(value.0, 0)
+    }
+
+    fn default_counter() -> Counter {
+        Counter(1, 0)
+    }
+
+    #[derive(Reflect)]
+    #[reflect(opaque, clone = clone_reset, default = default_counter)]
+    struct Counter(u32, u32);
+
+    fn fields(name: &str, count: u32) -> Fields {
+        Fields { name: name.into(), count, secret: "password".into() }
+    }
+
+    #[test]
+    fn field_custom_functions() {
+        let a = fields("Alice", 1);
+        let b = fields("ALICE", 2);
+        assert!(a.reflect_hash().is_some());
+        assert_eq!(a.reflect_hash(), fields("ALICE", 1).reflect_hash());
+        assert_ne!(a.reflect_hash(), b.reflect_hash());
+        assert_eq!(a.reflect_partial_eq(&fields("aLiCe", 1)), Some(true));
+        assert_eq!(a.reflect_partial_eq(&b), Some(false));
+        assert_eq!(a.reflect_partial_cmp(&b), Some(Ordering::Less));
+
+        let debug = format!("{:?}", &a as &dyn Reflect);
+        assert!(debug.contains("<hidden>"));
+        assert!(!debug.contains("password"));
+
+        // `count` is missing.
+        let mut dynamic = DynamicStruct::new();
+        dynamic.insert("name", String::from("Alice"));
+        dynamic.insert("secret", String::from("password"));
+        let converted = Fields::from_reflect(&dynamic).unwrap();
+        assert_eq!(converted.count, 7);
+    }
+
+    #[allow(clippy::ptr_arg, reason = "Custom functions take `&T` of the field type.")]
+    fn cmp_ignoring_case(a: &String, b: &String) -> Option<Ordering> {
+        Some(a.to_lowercase().cmp(&b.to_lowercase()))
+    }
+
+    #[derive(Reflect)]
+    struct Label {
+        #[reflect(partial_cmp = cmp_ignoring_case)]
+        text: String,
+        order: u32,
+    }
+
+    #[test]
+    fn field_custom_functions_with_dynamic() {
+        let a = fields("Alice", 1);
+        assert_eq!(a.reflect_partial_eq(&*fields("ALICE", 1).to_dynamic()), Some(true));
+        assert_eq!(a.reflect_partial_eq(&*fields("ALICE", 2).to_dynamic()), Some(false));
+        assert_eq!(a.reflect_partial_eq(&*fields("Bob", 1).to_dynamic()), Some(false));
+
+        let label = |text: &str, order| Label { text: text.into(), order };
+        let a = label("apple", 2);
+        let upper = label("APPLE", 2).to_dynamic();
+        assert_eq!(a.reflect_partial_cmp(&label("APPLE", 2)), Some(Ordering::Equal));
+        assert_eq!(a.reflect_partial_cmp(&*upper), Some(Ordering::Equal));
+        assert_eq!(a.reflect_partial_cmp(&*label("Banana", 1).to_dynamic()), Some(Ordering::Less));
+        assert_eq!(a.reflect_partial_cmp(&*label("APPLE", 3).to_dynamic()), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn type_custom_functions() {
+        let cloned = Counter(3, 5).reflect_clone().unwrap().take::<Counter>().unwrap();
+        assert_eq!((cloned.0, cloned.1), (3, 0));
+
+        let traits = Counter::get_type_traits();
+        let default = traits.get::<TypeTraitDefault>().unwrap().default();
+        let default = default.take::<Counter>().unwrap();
+        assert_eq!((default.0, default.1), (1, 0));
+    }
+
+    #[derive(Reflect, PartialEq, PartialOrd)]
+    #[reflect(partial_ord = PartialOrd)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+
+    #[derive(Reflect)]
+    #[reflect(partial_ord = Internal)]
+    struct Score {
+        points: u32,
+        name: String,
+    }
+
+    #[derive(Reflect)]
+    #[reflect(partial_ord = Internal)]
+    enum Rank {
+        Bronze,
+        Silver(u32),
+        Gold { stars: u32 },
+    }
+
+    fn score(points: u32, name: &str) -> Score {
+        Score { points, name: name.into() }
+    }
+
+    #[test]
+    fn partial_ord_options() {
+        let version = Version { major: 1, minor: 2 };
+        let next = Version { major: 1, minor: 3 };
+        assert_eq!(version.reflect_partial_cmp(&next), Some(Ordering::Less));
+        assert_eq!(version.reflect_partial_cmp(&1_u32), None);
+
+        assert_eq!(score(2, "a").reflect_partial_cmp(&score(1, "b")), Some(Ordering::Greater));
+        assert_eq!(score(1, "a").reflect_partial_cmp(&score(1, "b")), Some(Ordering::Less));
+        let dynamic = score(1, "a").to_dynamic();
+        assert_eq!(score(1, "a").reflect_partial_cmp(&*dynamic), Some(Ordering::Equal));
+
+        assert_eq!(Rank::Bronze.reflect_partial_cmp(&Rank::Silver(1)), Some(Ordering::Less));
+        assert_eq!(Rank::Silver(2).reflect_partial_cmp(&Rank::Silver(1)), Some(Ordering::Greater));
+        let gold = Rank::Gold { stars: 3 };
+        assert_eq!(gold.reflect_partial_cmp(&Rank::Gold { stars: 3 }), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn sort_boxed_reflect() {
+        let mut values: Vec<Box<dyn Reflect>> = vec![
+            Box::new(Rank::Gold { stars: 1 }),
+            Box::new(Rank::Silver(5)),
+            Box::new(Rank::Bronze),
+            Box::new(Rank::Silver(2)),
+        ];
+        values.sort_by(|a, b| a.reflect_partial_cmp(&**b).unwrap());
+        let sorted: Vec<Box<dyn Reflect>> = vec![
+            Box::new(Rank::Bronze),
+            Box::new(Rank::Silver(2)),
+            Box::new(Rank::Silver(5)),
+            Box::new(Rank::Gold { stars: 1 }),
+        ];
+        for (value, expected) in values.iter().zip(&sorted) {
+            assert_eq!(value.reflect_partial_cmp(&**expected), Some(Ordering::Equal));
+        }
+
+        let mut sets: Vec<Box<dyn Reflect>> = vec![
+            Box::new(BTreeSet::from([2_u32, 3])),
+            Box::new(BTreeSet::from([1_u32, 9])),
+            Box::new(BTreeSet::from([2_u32])),
+        ];
+        sets.sort_by(|a, b| a.reflect_partial_cmp(&**b).unwrap());
+        let sets: Vec<_> = sets.iter().map(|set| set.downcast_ref::<BTreeSet<u32>>()).collect();
+        assert_eq!(
+            sets,
+            [
+                Some(&BTreeSet::from([1, 9])),
+                Some(&BTreeSet::from([2])),
+                Some(&BTreeSet::from([2, 3])),
+            ],
+        );
+
+        let a = BTreeMap::from([(1_u32, String::from("a")), (2, String::from("b"))]);
+        let b = BTreeMap::from([(1_u32, String::from("a")), (2, String::from("c"))]);
+        assert_eq!(a.reflect_partial_cmp(&b), Some(Ordering::Less));
+        assert_eq!(a.reflect_partial_cmp(&a.clone()), Some(Ordering::Equal));
+        assert_eq!(a.reflect_partial_cmp(&*a.to_dynamic()), None);
+
+        let ok: Result<u32, String> = Ok(3);
+        let err: Result<u32, String> = Err(String::from("e"));
+        assert_eq!(ok.reflect_partial_cmp(&err), Some(Ordering::Less));
+        assert_eq!(ok.reflect_partial_cmp(&Ok::<u32, String>(1)), Some(Ordering::Greater));
+    }
+}
@@ -8,7 +8,7 @@ mod from_type;
 pub use from_type::FromType;
 
 mod type_registry;
-pub use type_registry::{TypeRegistry, TypeRegistryArc};
+pub use type_registry::{TypeRegistry, TypeRegistryArc, type_path_hash};
 
 pub mod traits;
 pub use traits::*;
@@ -8,6 +8,14 @@ pub struct TypeTraitDefault {
 }
 
 impl TypeTraitDefault {
+    /// Create from a custom function, such as `#[reflect(default = my_default)]`.
+    ///
+    /// The function should return the same type as the type that owns this type trait.
+    #[inline]
+    pub const fn new(func: fn() -> Box<dyn Reflect>) -> Self {
+        Self { func }
+    }
+
     /// Call T's [`Default`]
     ///
     /// [`TypeTraitDefault`] does not have a type flag,
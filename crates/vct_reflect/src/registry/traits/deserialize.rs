@@ -1,4 +1,4 @@
-use std::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 use crate::{Reflect, info::Typed, registry::FromType};
 use serde::Deserialize;
@@ -9,9 +9,22 @@ pub struct TypeTraitDeserialize {
     func: fn(
         deserializer: &mut dyn erased_serde::Deserializer,
     ) -> Result<Box<dyn Reflect>, erased_serde::Error>,
+    /// Wraps the value into the wrapper types, from the innermost one,
+    /// see [`Self::for_wrapper`].
+    wraps: Vec<fn(value: Box<dyn Reflect>) -> Box<dyn Reflect>>,
 }
 
 impl TypeTraitDeserialize {
+    /// Creates the deserialization of a wrapper type (such as `Box<T>`) from the one of
+    /// its content, `wrap` turns a content value into a wrapper value.
+    ///
+    /// The wrapper is deserialized from the data of its content.
+    pub fn for_wrapper(&self, wrap: fn(value: Box<dyn Reflect>) -> Box<dyn Reflect>) -> Self {
+        let mut wraps = self.wraps.clone();
+        wraps.push(wrap);
+        Self { func: self.func, wraps }
+    }
+
     /// Deserializes a reflected value.
     ///
     /// The underlying type of the reflected value, and thus the expected
@@ -23,7 +36,8 @@ impl TypeTraitDeserialize {
         deserializer: D,
     ) -> Result<Box<dyn Reflect>, D::Error> {
         let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
-        (self.func)(&mut erased).map_err(<D::Error as serde::de::Error>::custom)
+        let value = (self.func)(&mut erased).map_err(<D::Error as serde::de::Error>::custom)?;
+        Ok(self.wraps.iter().fold(value, |value, wrap| wrap(value)))
     }
 }
 
@@ -31,6 +45,7 @@ impl<T: for<'a> Deserialize<'a> + Typed + Reflect> FromType<T> for TypeTraitDese
     fn from_type() -> Self {
         Self {
             func: |deserializer| Ok(Box::new(T::deserialize(deserializer)?)),
+            wraps: Vec::new(),
         }
     }
 }
@@ -0,0 +1,56 @@
+use alloc::boxed::Box;
+
+use crate::{Reflect, info::Typed, registry::FromType};
+use serde::Deserialize;
+
+/// A struct used to deserialize borrowed data to reflected instances of a type.
+///
+/// Unlike [`TypeTraitDeserialize`], the type only needs to implement `Deserialize<'static>`,
+/// so types like `&'static str`, `&'static [u8]` and `Cow<'static, str>` can borrow from
+/// the input instead of copying it.
+///
+/// Reflected values are `'static`, so only `'static` input can be borrowed, see
+/// [`InternalDeserializer::deserialize_borrowed`]. The buffer must really live for the rest
+/// of the program (e.g. leaked with [`Box::leak`]), it can't be freed or unmapped while any
+/// value deserialized from it is alive, so don't extend the lifetime of a temporary buffer.
+///
+/// [`TypeTraitDeserialize`]: crate::registry::TypeTraitDeserialize
+/// [`InternalDeserializer::deserialize_borrowed`]: crate::serde::InternalDeserializer::deserialize_borrowed
+#[derive(Clone)]
+pub struct TypeTraitDeserializeBorrowed {
+    func: fn(
+        deserializer: &mut dyn erased_serde::Deserializer<'static>,
+    ) -> Result<Box<dyn Reflect>, erased_serde::Error>,
+}
+
+impl TypeTraitDeserializeBorrowed {
+    /// Creates a new instance from a custom deserialization function.
+    ///
+    /// Useful when the `Deserialize` impl of the type always copies, e.g. `Cow<'static, str>`.
+    #[inline]
+    pub const fn new(
+        func: fn(
+            deserializer: &mut dyn erased_serde::Deserializer<'static>,
+        ) -> Result<Box<dyn Reflect>, erased_serde::Error>,
+    ) -> Self {
+        Self { func }
+    }
+
+    /// Deserializes a reflected value, borrowing from the input if possible.
+    #[inline(always)]
+    pub fn deserialize<D: serde::Deserializer<'static>>(
+        &self,
+        deserializer: D,
+    ) -> Result<Box<dyn Reflect>, D::Error> {
+        let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.func)(&mut erased).map_err(<D::Error as serde::de::Error>::custom)
+    }
+}
+
+impl<T: Deserialize<'static> + Typed + Reflect> FromType<T> for TypeTraitDeserializeBorrowed {
+    fn from_type() -> Self {
+        Self {
+            func: |deserializer| Ok(Box::new(T::deserialize(deserializer)?)),
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use alloc::boxed::Box;
 
-use crate::{FromReflect, Reflect, info::Typed, registry::FromType};
+use crate::{FromReflect, FromReflectError, Reflect, info::Typed, registry::FromType};
 
 /// See [`FromReflect`]
 #[derive(Clone)]
 pub struct TypeTraitFromReflect {
-    func: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+    func: fn(&dyn Reflect) -> Result<Box<dyn Reflect>, FromReflectError>,
 }
 
 impl TypeTraitFromReflect {
@@ -15,6 +15,15 @@ impl TypeTraitFromReflect {
     /// but the functions used internally are type specific.
     #[inline(always)]
     pub fn from_reflect(&self, param_1: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.func)(param_1).ok()
+    }
+
+    /// Call T's [`FromReflect::try_from_reflect`].
+    #[inline(always)]
+    pub fn try_from_reflect(
+        &self,
+        param_1: &dyn Reflect,
+    ) -> Result<Box<dyn Reflect>, FromReflectError> {
         (self.func)(param_1)
     }
 }
@@ -22,7 +31,9 @@ impl TypeTraitFromReflect {
 impl<T: Typed + FromReflect> FromType<T> for TypeTraitFromReflect {
     fn from_type() -> Self {
         Self {
-            func: |param_1| T::from_reflect(param_1).map(|val| Box::new(val) as Box<dyn Reflect>),
+            func: |param_1| {
+                T::try_from_reflect(param_1).map(|val| Box::new(val) as Box<dyn Reflect>)
+            },
         }
     }
 }
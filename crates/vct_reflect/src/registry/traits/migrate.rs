@@ -0,0 +1,93 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use core::any::{Any, TypeId};
+
+use crate::Reflect;
+
+/// The function type of [`TypeTraitMigrate`].
+///
+/// It receives the version of the serialized data and the deserialized dynamic value,
+/// and returns the value in the current shape.
+pub type MigrateFn = fn(u32, Box<dyn Reflect>) -> Result<Box<dyn Reflect>, Cow<'static, str>>;
+
+/// Migrates serialized data of an older version into the current shape.
+///
+/// The version of a type is set by `#[reflect(version = N)]`, see [`TypeInfo::version`].
+/// The data version is written for the top-level value and for each nested value of a
+/// versioned type, so the migration also applies to fields and elements.
+/// When the data version is older than the current version, the deserializer:
+///
+/// 1. Deserializes the value leniently: unknown struct fields are deserialized with the type
+///    declared by [`with_legacy_field`], or ignored if not declared.
+///    Missing fields (and trailing elements of tuple-like values) are left to the migration,
+///    extra elements are ignored.
+/// 2. Calls the migration function with the dynamic value (such as `DynamicStruct` or `DynamicEnum`).
+/// 3. Converts the result by `FromReflect` as usual.
+///
+/// This type trait cannot be created by [`FromType`], it should be inserted manually.
+///
+/// # Example
+///
+/// ```ignore
+/// // version 1: `struct Save { hp: u32 }`
+/// #[derive(Reflect)]
+/// #[reflect(version = 2)]
+/// struct Save { health: u32 }
+///
+/// fn migrate_save(version: u32, value: Box<dyn Reflect>) -> Result<Box<dyn Reflect>, Cow<'static, str>> {
+///     let mut value = value.downcast::<DynamicStruct>().map_err(|_| "expected a struct")?;
+///     if version < 2 && let Some(hp) = value.remove("hp") {
+///         value.insert_boxed("health", hp);
+///     }
+///     Ok(value)
+/// }
+///
+/// registry.get_mut(TypeId::of::<Save>()).unwrap().insert(
+///     TypeTraitMigrate::new(migrate_save).with_legacy_field::<u32>("hp"),
+/// );
+/// ```
+///
+/// [`TypeInfo::version`]: crate::info::TypeInfo::version
+/// [`with_legacy_field`]: TypeTraitMigrate::with_legacy_field
+/// [`FromType`]: crate::registry::FromType
+#[derive(Clone)]
+pub struct TypeTraitMigrate {
+    func: MigrateFn,
+    legacy_fields: Vec<(&'static str, TypeId)>,
+}
+
+impl TypeTraitMigrate {
+    /// Creates a new [`TypeTraitMigrate`] with the migration function.
+    #[inline]
+    pub const fn new(func: MigrateFn) -> Self {
+        Self {
+            func,
+            legacy_fields: Vec::new(),
+        }
+    }
+
+    /// Declares a field that only exists in older versions, with type `T`.
+    ///
+    /// The type `T` must be registered, so that the field can be deserialized.
+    pub fn with_legacy_field<T: Any>(mut self, name: &'static str) -> Self {
+        self.legacy_fields.push((name, TypeId::of::<T>()));
+        self
+    }
+
+    /// Returns the [`TypeId`] of the legacy field with the given name, if declared.
+    pub fn legacy_field(&self, name: &str) -> Option<TypeId> {
+        self.legacy_fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, id)| *id)
+    }
+
+    /// Migrates the value from the given data version.
+    #[inline]
+    pub fn migrate(
+        &self,
+        version: u32,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<dyn Reflect>, Cow<'static, str>> {
+        (self.func)(version, value)
+    }
+}
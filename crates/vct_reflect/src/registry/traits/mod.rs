@@ -15,3 +15,9 @@ pub use serialize::TypeTraitSerialize;
 
 mod deserialize;
 pub use deserialize::TypeTraitDeserialize;
+
+mod deserialize_borrowed;
+pub use deserialize_borrowed::TypeTraitDeserializeBorrowed;
+
+mod migrate;
+pub use migrate::{MigrateFn, TypeTraitMigrate};
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::{Reflect, info::Typed, registry::FromType};
 use serde::Serialize;
 
@@ -7,6 +9,8 @@ use serde::Serialize;
 #[derive(Clone)]
 pub struct TypeTraitSerialize {
     fun: fn(value: &dyn Reflect) -> &dyn erased_serde::Serialize,
+    /// Unwraps the wrapper types, from the outermost one, see [`Self::for_wrapper`].
+    unwraps: Vec<fn(value: &dyn Reflect) -> &dyn Reflect>,
 }
 
 impl<T: erased_serde::Serialize + Typed + Reflect> FromType<T> for TypeTraitSerialize {
@@ -22,11 +26,23 @@ impl<T: erased_serde::Serialize + Typed + Reflect> FromType<T> for TypeTraitSeri
                     );
                 }
             },
+            unwraps: Vec::new(),
         }
     }
 }
 
 impl TypeTraitSerialize {
+    /// Creates the serialization of a wrapper type (such as `Box<T>`) from the one of
+    /// its content, `unwrap` returns the content of a wrapper value.
+    ///
+    /// The wrapper is serialized as its content.
+    pub fn for_wrapper(&self, unwrap: fn(value: &dyn Reflect) -> &dyn Reflect) -> Self {
+        let mut unwraps = Vec::with_capacity(self.unwraps.len() + 1);
+        unwraps.push(unwrap);
+        unwraps.extend_from_slice(&self.unwraps);
+        Self { fun: self.fun, unwraps }
+    }
+
     /// Call T's [`Serialize`]
     ///
     /// [`TypeTraitSerialize`] does not have a type flag,
@@ -40,6 +56,7 @@ impl TypeTraitSerialize {
         value: &dyn Reflect,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
+        let value = self.unwraps.iter().fold(value, |value, unwrap| unwrap(value));
         (self.fun)(value).serialize(serializer)
     }
 }
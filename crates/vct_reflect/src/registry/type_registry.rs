@@ -1,6 +1,8 @@
+use alloc::{borrow::Cow, string::String};
 use core::{any::TypeId, fmt};
 
 use crate::{
+    func::{DynamicFunction, IntoFunction},
     info::{TypeInfo, Typed},
     registry::{FromType, GetTypeTraits, TypeTrait, TypeTraits},
 };
@@ -13,6 +15,10 @@ pub struct TypeRegistry {
     type_path_to_id: HashMap<&'static str, TypeId>,
     type_name_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    type_path_hash_to_id: HashMap<u64, TypeId>,
+    ambiguous_hashes: HashSet<u64>,
+    functions: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+    methods: TypeIdMap<HashMap<Cow<'static, str>, DynamicFunction<'static>>>,
 }
 
 impl TypeRegistry {
@@ -24,6 +30,10 @@ impl TypeRegistry {
             type_path_to_id: HashMap::<_, _>::new(),
             type_name_to_id: HashMap::<_, _>::new(),
             ambiguous_names: HashSet::new(),
+            type_path_hash_to_id: HashMap::<_, _>::new(),
+            ambiguous_hashes: HashSet::new(),
+            functions: HashMap::<_, _>::new(),
+            methods: TypeIdMap::new(),
         }
     }
 
@@ -34,6 +44,8 @@ impl TypeRegistry {
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
         type_name_to_id: &mut HashMap<&'static str, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
+        type_path_hash_to_id: &mut HashMap<u64, TypeId>,
+        ambiguous_hashes: &mut HashSet<u64>,
     ) {
         let type_name = type_traits.type_info().type_path_table().name();
 
@@ -46,7 +58,17 @@ impl TypeRegistry {
             type_name_to_id.insert(type_name, type_traits.type_id());
         }
         // For new type, assuming that the full path cannot be duplicated.
-        type_path_to_id.insert(type_traits.type_info().type_path(), type_traits.type_id());
+        let type_path = type_traits.type_info().type_path();
+        type_path_to_id.insert(type_path, type_traits.type_id());
+
+        // Hash collisions are unlikely, but they should not silently resolve to a wrong type.
+        let hash = type_path_hash(type_path);
+        if type_path_hash_to_id.contains_key(&hash) || ambiguous_hashes.contains(&hash) {
+            type_path_hash_to_id.remove(&hash);
+            ambiguous_hashes.insert(hash);
+        } else {
+            type_path_hash_to_id.insert(hash, type_traits.type_id());
+        }
     }
 
     // If key [`TypeId`] has already exist, the function will do nothing and return `false`.
@@ -65,6 +87,8 @@ impl TypeRegistry {
                     &mut self.type_path_to_id,
                     &mut self.type_name_to_id,
                     &mut self.ambiguous_names,
+                    &mut self.type_path_hash_to_id,
+                    &mut self.ambiguous_hashes,
                 );
                 entry.insert(type_traits);
                 true
@@ -100,6 +124,8 @@ impl TypeRegistry {
                     &mut self.type_path_to_id,
                     &mut self.type_name_to_id,
                     &mut self.ambiguous_names,
+                    &mut self.type_path_hash_to_id,
+                    &mut self.ambiguous_hashes,
                 );
                 entry.insert(type_traits);
             }
@@ -109,7 +135,7 @@ impl TypeRegistry {
     /// Create a new [`TypeRegistry`].
     ///
     /// This function will register some types by default,
-    /// such as `u8`-`u128`, `i8`-`i128`, `usize`, and `isize`.
+    /// such as `u8`-`u128`, `i8`-`i128`, `usize`, `isize`, `bool`, `char` and `String`.
     pub fn new() -> Self {
         let mut registry = Self::empty();
 
@@ -126,7 +152,9 @@ impl TypeRegistry {
         registry.register::<usize>();
         registry.register::<isize>();
 
-        // TODO: bool String
+        registry.register::<bool>();
+        registry.register::<char>();
+        registry.register::<String>();
 
         registry
     }
@@ -225,6 +253,18 @@ impl TypeRegistry {
         }
     }
 
+    /// Returns a reference to the [`TypeTraits`] of the type with the given [type path hash].
+    ///
+    /// Returns `None` if the hash is ambiguous, that is, it matches multiple registered types.
+    ///
+    /// [type path hash]: type_path_hash
+    pub fn get_with_type_path_hash(&self, hash: u64) -> Option<&TypeTraits> {
+        match self.type_path_hash_to_id.get(&hash) {
+            Some(id) => self.get(*id),
+            None => None,
+        }
+    }
+
     /// Returns `true` if the given [type name] is ambiguous, that is, it matches multiple registered types.
     ///
     /// [type name]: TypePath::type_name
@@ -275,6 +315,89 @@ impl TypeRegistry {
             type_trait.map(|t| (item, t))
         })
     }
+
+    /// Registers a function with the given name, see [`func`](crate::func).
+    ///
+    /// The name of the [`DynamicFunction`] will be set to `name`.
+    /// If a function with the same name already exists, it will be overwritten and returned.
+    pub fn register_function<M>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        func: impl IntoFunction<'static, M>,
+    ) -> Option<DynamicFunction<'static>> {
+        let name = name.into();
+        let func = func.into_function().with_name(name.clone());
+        self.functions.insert(name, func)
+    }
+
+    /// Returns the function with the given name.
+    #[inline]
+    pub fn get_function(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.functions.get(name)
+    }
+
+    /// Returns an iterator over the registered functions.
+    #[inline]
+    pub fn iter_functions(&self) -> impl Iterator<Item = &DynamicFunction<'static>> {
+        self.functions.values()
+    }
+
+    /// Registers a method of type `T` with the given name, see [`func`](crate::func).
+    ///
+    /// A method is a function with the receiver as the first argument,
+    /// such as `Player::heal` for `fn heal(&mut self, amount: f32)`.
+    ///
+    /// The name of the [`DynamicFunction`] will be set to `name`.
+    /// If a method with the same name already exists for `T`, it will be overwritten and returned.
+    ///
+    /// Type `T` does not need to be registered in advance.
+    pub fn register_method<T: Typed, M>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        func: impl IntoFunction<'static, M>,
+    ) -> Option<DynamicFunction<'static>> {
+        let name = name.into();
+        let func = func.into_function().with_name(name.clone());
+        self.methods
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .insert(name, func)
+    }
+
+    /// Returns the method with the given name of the type with the given [`TypeId`].
+    pub fn get_method(&self, type_id: TypeId, name: &str) -> Option<&DynamicFunction<'static>> {
+        // Manual inline
+        match self.methods.get(&type_id) {
+            Some(methods) => methods.get(name),
+            None => None,
+        }
+    }
+
+    /// Returns an iterator over the registered methods of the type with the given [`TypeId`].
+    pub fn iter_methods(&self, type_id: TypeId) -> impl Iterator<Item = &DynamicFunction<'static>> {
+        self.methods.get(&type_id).into_iter().flat_map(HashMap::values)
+    }
+}
+
+/// Returns a stable hash of the [type path], used as a compact type id in serialization.
+///
+/// It uses the 64-bit FNV-1a algorithm, so the result does not depend on
+/// the platform, compiler version or the registration order.
+///
+/// [type path]: crate::info::TypePath::type_path
+pub const fn type_path_hash(type_path: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x00000100000001b3;
+
+    let bytes = type_path.as_bytes();
+    let mut hash = OFFSET_BASIS;
+    let mut index = 0;
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        index += 1;
+    }
+    hash
 }
 
 impl Default for TypeRegistry {
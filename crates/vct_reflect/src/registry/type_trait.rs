@@ -160,3 +160,91 @@ impl fmt::Debug for TypeTraits {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use core::any::TypeId;
+    use vct_reflect_derive::{Reflect, reflect_trait};
+
+    use crate::{
+        Reflect,
+        info::Typed,
+        registry::{FromType, GetTypeTraits, TypeRegistry, TypeTraitFromReflect},
+    };
+
+    #[derive(Clone)]
+    struct TypeTraitName(&'static str);
+
+    impl<T: Typed> FromType<T> for TypeTraitName {
+        fn from_type() -> Self {
+            Self(T::type_name())
+        }
+    }
+
+    #[derive(Clone)]
+    struct TypeTraitMarker;
+
+    impl<T: Typed> FromType<T> for TypeTraitMarker {
+        fn from_type() -> Self {
+            Self
+        }
+    }
+
+    #[reflect_trait]
+    trait Damageable {
+        fn health(&self) -> u32;
+        fn damage(&mut self, amount: u32);
+    }
+
+    #[derive(Reflect)]
+    #[reflect(type_trait(TypeTraitName))]
+    #[reflect(type_trait(TypeTraitMarker, TypeTraitDamageable))]
+    struct Player {
+        health: u32,
+    }
+
+    impl Damageable for Player {
+        fn health(&self) -> u32 {
+            self.health
+        }
+
+        fn damage(&mut self, amount: u32) {
+            self.health = self.health.saturating_sub(amount);
+        }
+    }
+
+    #[test]
+    fn derive_type_traits() {
+        let traits = Player::get_type_traits();
+        assert_eq!(traits.type_info().type_id(), Player::type_info().type_id());
+        assert_eq!(traits.get::<TypeTraitName>().unwrap().0, "Player");
+        assert!(traits.contains::<TypeTraitMarker>());
+        assert!(traits.contains::<TypeTraitFromReflect>());
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        let type_id = TypeId::of::<Player>();
+        assert!(registry.get_type_trait::<TypeTraitMarker>(type_id).is_some());
+        assert_eq!(registry.get_type_trait::<TypeTraitName>(type_id).unwrap().0, "Player");
+    }
+
+    #[test]
+    fn reflect_trait_cast() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        let type_trait = registry
+            .get_type_trait::<TypeTraitDamageable>(TypeId::of::<Player>())
+            .unwrap()
+            .clone();
+
+        let mut value: Box<dyn Reflect> = Box::new(Player { health: 30 });
+        type_trait.get_mut(value.as_mut()).unwrap().damage(10);
+        assert_eq!(type_trait.get(value.as_ref()).unwrap().health(), 20);
+        assert_eq!(type_trait.get_boxed(value).ok().unwrap().health(), 20);
+
+        let other: Box<dyn Reflect> = Box::new(5u32);
+        assert!(type_trait.get(other.as_ref()).is_none());
+        assert!(type_trait.get_boxed(other).is_err());
+    }
+}
@@ -9,13 +9,14 @@ use super::{DeserializerProcessor, InternalDeserializer};
 /// A [`Visitor`] for deserializing [`Array`] values.
 ///
 /// [`Array`]: crate::ops::Array
-pub(super) struct ArrayVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct ArrayVisitor<'a, P> {
     pub array_info: &'static ArrayInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for ArrayVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for ArrayVisitor<'_, P> {
     type Value = DynamicArray;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -39,6 +40,7 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for ArrayVisitor<'_, P> {
             type_traits,
             self.registry,
             self.processor.as_deref_mut(),
+            self.compact,
         ))? {
             vec.push(value);
         }
@@ -1,29 +1,51 @@
-use alloc::{boxed::Box, format};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::fmt;
 use serde::{
     Deserializer,
-    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor},
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
 };
 
 use crate::{
     Reflect,
-    info::{TypeInfo, Typed},
+    info::{EnumTagging, TypeInfo, Typed},
     registry::{
-        GetTypeTraits, TypeRegistry, TypeTraitDeserialize, TypeTraitFromReflect, TypeTraits,
+        GetTypeTraits, TypeRegistry, TypeTraitDeserialize, TypeTraitFromReflect, TypeTraitMigrate,
+        TypeTraits,
     },
 };
 
 use super::{
-    DeserializerProcessor, array_visitor::ArrayVisitor, enum_visitor::EnumVisitor,
-    list_visitor::ListVisitor, map_visitor::MapVisitor, option_visitor::OptionVisitor,
-    set_visitor::SetVisitor, struct_visitor::StructVisitor,
-    tuple_struct_visitor::TupleStructVisitor, tuple_visitor::TupleVisitor,
+    super::{VALUE_KEY, VERSION_KEY}, DeserializerProcessor,
+    processor::BorrowedProcessor,
+    array_visitor::ArrayVisitor,
+    content::{Content, ContentDeserializer},
+    enum_visitor::{CompactEnumVisitor, EnumVisitor},
+    list_visitor::ListVisitor,
+    map_visitor::MapVisitor,
+    option_visitor::OptionVisitor,
+    set_visitor::SetVisitor,
+    struct_like_utils::{Ident, StructLikeInfo},
+    struct_visitor::StructVisitor,
+    tagged_enum::deserialize_tagged_enum,
+    tuple_struct_visitor::TupleStructVisitor,
+    tuple_visitor::TupleVisitor,
 };
 
-pub struct InternalDeserializer<'a, P: DeserializerProcessor = ()> {
+/// A deserializer without type path attached, see [`InternalSerializer`].
+///
+/// The value is converted to the concrete type with [`TypeTraitFromReflect`] if the type
+/// registers it, otherwise it's returned as a dynamic value. A failed conversion is an error,
+/// earlier versions returned the dynamic value instead.
+///
+/// [`InternalSerializer`]: crate::serde::InternalSerializer
+pub struct InternalDeserializer<'a, P = ()> {
     type_traits: &'a TypeTraits,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    compact: bool,
+    data_version: Option<u32>,
+    /// Nested values of versioned types carry their own data version.
+    nested: bool,
 }
 
 impl<'a> InternalDeserializer<'a, ()> {
@@ -33,6 +55,9 @@ impl<'a> InternalDeserializer<'a, ()> {
             type_traits,
             registry,
             processor: None,
+            compact: false,
+            data_version: None,
+            nested: false,
         }
     }
 
@@ -46,11 +71,14 @@ impl<'a> InternalDeserializer<'a, ()> {
             type_traits,
             registry,
             processor: None,
+            compact: false,
+            data_version: None,
+            nested: false,
         }
     }
 }
 
-impl<'a, P: DeserializerProcessor> InternalDeserializer<'a, P> {
+impl<'a, P> InternalDeserializer<'a, P> {
     #[inline]
     pub fn with_processor(
         type_traits: &'a TypeTraits,
@@ -61,31 +89,117 @@ impl<'a, P: DeserializerProcessor> InternalDeserializer<'a, P> {
             type_traits,
             registry,
             processor: Some(processor),
+            compact: false,
+            data_version: None,
+            nested: false,
         }
     }
 
-    /// An internal constructor for creating a deserializer without resetting the type info stack.
+    /// An internal constructor for deserializing nested values.
     #[inline]
     pub(super) fn new_internal(
         type_traits: &'a TypeTraits,
         registry: &'a TypeRegistry,
         processor: Option<&'a mut P>,
+        compact: bool,
     ) -> Self {
         Self {
             type_traits,
             registry,
             processor,
+            compact,
+            data_version: None,
+            nested: true,
         }
     }
+
+    /// Sets whether the value is nested, i.e. its data version is stored in the data.
+    #[inline]
+    pub(super) fn with_nested(mut self, nested: bool) -> Self {
+        self.nested = nested;
+        self
+    }
+}
+
+impl<P> InternalDeserializer<'_, P> {
+    /// Sets whether to use the compact format, see [`ReflectDeserializer::with_compact`].
+    #[inline]
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Sets the version of the serialized data, the default is unknown (no migration).
+    ///
+    /// If the data version is newer than the [version] of the type, deserialization fails.
+    /// If it's older and the type has [`TypeTraitMigrate`], the value will be migrated.
+    ///
+    /// [version]: crate::info::TypeInfo::version
+    #[inline]
+    pub fn with_data_version(mut self, version: u32) -> Self {
+        self.data_version = Some(version);
+        self
+    }
+
+    /// Deserializes a value from a `'static` input, borrowing data instead of copying it.
+    ///
+    /// Types with [`TypeTraitDeserializeBorrowed`] (at any depth) use it instead of
+    /// [`TypeTraitDeserialize`], so `&'static str` or `&'static [u8]` fields can point
+    /// into the input. The input must live for the rest of the program, e.g. a leaked buffer.
+    ///
+    /// [`TypeTraitDeserializeBorrowed`]: crate::registry::TypeTraitDeserializeBorrowed
+    pub fn deserialize_borrowed<D: Deserializer<'static>>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn Reflect>, D::Error>
+    where
+        P: DeserializerProcessor<'static>,
+    {
+        let mut processor = BorrowedProcessor {
+            processor: self.processor,
+        };
+        InternalDeserializer {
+            type_traits: self.type_traits,
+            registry: self.registry,
+            processor: Some(&mut processor),
+            compact: self.compact,
+            data_version: self.data_version,
+            nested: self.nested,
+        }
+        .deserialize(deserializer)
+    }
 }
 
-impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserializer<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> DeserializeSeed<'de> for InternalDeserializer<'_, P> {
     type Value = Box<dyn Reflect>;
 
     fn deserialize<D: Deserializer<'de>>(
         mut self,
         deserializer: D,
     ) -> Result<Self::Value, D::Error> {
+        let current_version = self.type_traits.type_info().version();
+        if self.nested && self.data_version.is_none() && current_version != 0 {
+            return if self.compact {
+                deserializer.deserialize_tuple(2, VersionedVisitor(self))
+            } else {
+                deserializer.deserialize_map(VersionedVisitor(self))
+            };
+        }
+
+        let migrate = match self.data_version {
+            Some(version) if version > current_version => {
+                return Err(Error::custom(format!(
+                    "data version `{version}` is newer than version `{current_version}` of `{}`",
+                    self.type_traits.type_info().type_path(),
+                )));
+            }
+            Some(version) if version < current_version => self
+                .type_traits
+                .get::<TypeTraitMigrate>()
+                .map(|migrate| (version, migrate)),
+            _ => None,
+        };
+
         let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
             match processor.try_deserialize(self.type_traits, self.registry, deserializer) {
                 Ok(Ok(value)) => return Ok(value),
@@ -100,19 +214,28 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
             return deserialize_reflect.deserialize(deserializer);
         }
 
+        // Outdated data is deserialized leniently, the rest is left to the migration.
+        let legacy = migrate.map(|(_, migrate)| migrate);
         let dynamic_value: Box<dyn Reflect> = match self.type_traits.type_info() {
             TypeInfo::Struct(struct_info) => {
-                let mut dynamic_struct = deserializer.deserialize_struct(
-                    struct_info
-                        .type_path_table()
-                        .ident(),
-                    struct_info.field_names(),
-                    StructVisitor {
-                        struct_info,
-                        registry: self.registry,
-                        processor: self.processor,
-                    },
-                )?;
+                let visitor = StructVisitor {
+                    struct_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                    compact: self.compact,
+                    legacy,
+                };
+                let mut dynamic_struct = if self.compact {
+                    deserializer.deserialize_tuple(struct_info.serialized_field_len(), visitor)?
+                } else {
+                    deserializer.deserialize_struct(
+                        struct_info
+                            .type_path_table()
+                            .ident(),
+                        struct_info.serde_field_names(),
+                        visitor,
+                    )?
+                };
                 dynamic_struct.set_type_info(Some(self.type_traits.type_info()));
                 Box::new(dynamic_struct)
             }
@@ -126,6 +249,8 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                             tuple_struct_info,
                             registry: self.registry,
                             processor: self.processor,
+                            compact: self.compact,
+                            legacy,
                         },
                     )?
                 } else {
@@ -138,6 +263,8 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                             tuple_struct_info,
                             registry: self.registry,
                             processor: self.processor,
+                            compact: self.compact,
+                            legacy,
                         },
                     )?
                 };
@@ -151,6 +278,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                         tuple_info,
                         registry: self.registry,
                         processor: self.processor,
+                        compact: self.compact,
                     },
                 )?;
                 dynamic_tuple.set_type_info(Some(self.type_traits.type_info()));
@@ -161,6 +289,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                     list_info,
                     registry: self.registry,
                     processor: self.processor,
+                    compact: self.compact,
                 })?;
                 dynamic_list.set_type_info(Some(self.type_traits.type_info()));
                 Box::new(dynamic_list)
@@ -172,6 +301,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                         array_info,
                         registry: self.registry,
                         processor: self.processor,
+                        compact: self.compact,
                     },
                 )?;
                 dynamic_array.set_type_info(Some(self.type_traits.type_info()));
@@ -182,6 +312,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                     map_info,
                     registry: self.registry,
                     processor: self.processor,
+                    compact: self.compact,
                 })?;
                 dynamic_map.set_type_info(Some(self.type_traits.type_info()));
                 Box::new(dynamic_map)
@@ -191,6 +322,7 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                     set_info,
                     registry: self.registry,
                     processor: self.processor,
+                    compact: self.compact,
                 })?;
                 dynamic_set.set_type_info(Some(self.type_traits.type_info()));
                 Box::new(dynamic_set)
@@ -204,7 +336,26 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                         enum_info,
                         registry: self.registry,
                         processor: self.processor,
+                        compact: self.compact,
                     })?
+                } else if self.compact {
+                    deserializer.deserialize_tuple(
+                        2,
+                        CompactEnumVisitor {
+                            enum_info,
+                            registry: self.registry,
+                            processor: self.processor,
+                            legacy,
+                        },
+                    )?
+                } else if enum_info.tagging() != EnumTagging::External {
+                    deserialize_tagged_enum(
+                        deserializer,
+                        enum_info,
+                        self.registry,
+                        self.processor,
+                        legacy,
+                    )?
                 } else {
                     deserializer.deserialize_enum(
                         enum_info.type_path_table().ident(),
@@ -213,6 +364,8 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
                             enum_info,
                             registry: self.registry,
                             processor: self.processor,
+                            compact: self.compact,
+                            legacy,
                         },
                     )?
                 };
@@ -226,19 +379,137 @@ impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for InternalDeserialize
             }
         };
 
-        if let Some(from_reflect) = self.type_traits.get::<TypeTraitFromReflect>()
-            && let Some(value) = from_reflect.from_reflect(&*dynamic_value)
-        {
-            return Ok(value);
+        let dynamic_value = match migrate {
+            Some((version, migrate)) => {
+                migrate.migrate(version, dynamic_value).map_err(Error::custom)?
+            }
+            None => dynamic_value,
+        };
+
+        // The value is kept dynamic if the type has no `FromReflect`,
+        // a failed conversion is reported instead of returning the dynamic value.
+        if let Some(from_reflect) = self.type_traits.get::<TypeTraitFromReflect>() {
+            return from_reflect.try_from_reflect(&*dynamic_value).map_err(|err| {
+                Error::custom(format!(
+                    "cannot convert the data to `{}`: {err}",
+                    self.type_traits.type_info().type_path()
+                ))
+            });
         }
 
         Ok(dynamic_value)
     }
 }
 
-pub struct ReflectDeserializer<'a, P: DeserializerProcessor = ()> {
+/// A [`Visitor`] for nested values of versioned types, which are written as
+/// `{ "version": version, "value": value }`, or `(version, value)` in the compact format.
+///
+/// A map without these entries is data written before the type was versioned, it's
+/// deserialized as version `0` (so it's migrated), unless its first key is `version` or `value`.
+/// The compact format can't tell them apart.
+struct VersionedVisitor<'a, P>(InternalDeserializer<'a, P>);
+
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for VersionedVisitor<'_, P> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("versioned value with `version` and `value` entries")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &"2"))?;
+        seq.next_element_seed(self.0.with_data_version(version))?
+            .ok_or_else(|| Error::invalid_length(1, &"2"))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut seed = Some(self.0);
+        let mut version = None;
+        let mut value = None;
+        // The value is buffered if it comes before the version.
+        let mut content = None;
+
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            match key.as_str() {
+                VERSION_KEY if version.is_some() => return Err(Error::duplicate_field(VERSION_KEY)),
+                VERSION_KEY => version = Some(map.next_value::<u32>()?),
+                VALUE_KEY if value.is_some() || content.is_some() => {
+                    return Err(Error::duplicate_field(VALUE_KEY));
+                }
+                VALUE_KEY => match (version, seed.take()) {
+                    (Some(version), Some(seed)) => {
+                        value = Some(map.next_value_seed(seed.with_data_version(version))?);
+                    }
+                    (_, unused) => {
+                        seed = unused;
+                        content = Some(map.next_value::<Content>()?);
+                    }
+                },
+                // The first key is neither of them, the value isn't wrapped.
+                _ if version.is_none() && content.is_none() => {
+                    return deserialize_unversioned(seed.take(), key, map);
+                }
+                _ => return Err(Error::unknown_field(&key, &[VERSION_KEY, VALUE_KEY])),
+            }
+        }
+
+        match (version, value, content, seed) {
+            (_, Some(value), _, _) => Ok(value),
+            (Some(version), None, Some(content), Some(seed)) => seed
+                .with_data_version(version)
+                .deserialize(ContentDeserializer::new(content)),
+            // An empty map, such as an unversioned struct without fields.
+            (None, None, None, Some(seed)) => seed
+                .with_data_version(0)
+                .deserialize(ContentDeserializer::new(Content::Map(Vec::new()))),
+            (None, ..) => Err(Error::missing_field(VERSION_KEY)),
+            _ => Err(Error::missing_field(VALUE_KEY)),
+        }
+    }
+}
+
+/// Deserializes an unversioned map (see [`VersionedVisitor`]) as version `0`,
+/// `key` is its first key, which has been read.
+fn deserialize_unversioned<'de, P, A>(
+    seed: Option<InternalDeserializer<'_, P>>,
+    key: String,
+    mut map: A,
+) -> Result<Box<dyn Reflect>, A::Error>
+where
+    P: DeserializerProcessor<'de>,
+    A: MapAccess<'de>,
+{
+    let Some(seed) = seed else {
+        return Err(Error::unknown_field(&key, &[VERSION_KEY, VALUE_KEY]));
+    };
+    let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(1024) + 1);
+    entries.push((Content::String(key), map.next_value::<Content>()?));
+    while let Some(entry) = map.next_entry::<Content, Content>()? {
+        entries.push(entry);
+    }
+    seed.with_data_version(0)
+        .deserialize(ContentDeserializer::new(Content::Map(entries)))
+}
+
+/// A deserializer of values written by [`ReflectSerializer`], with the type path attached.
+///
+/// The value is converted to the concrete type with [`TypeTraitFromReflect`] if the type
+/// registers it, otherwise it's returned as a dynamic value. A failed conversion is an error,
+/// earlier versions returned the dynamic value instead.
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+pub struct ReflectDeserializer<'a, P = ()> {
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    compact: bool,
 }
 
 impl<'a> ReflectDeserializer<'a, ()> {
@@ -247,64 +518,214 @@ impl<'a> ReflectDeserializer<'a, ()> {
         Self {
             registry,
             processor: None,
+            compact: false,
         }
     }
 }
 
-impl<'a, P: DeserializerProcessor> ReflectDeserializer<'a, P> {
+impl<'a, P> ReflectDeserializer<'a, P> {
     #[inline]
     pub fn with_processor(registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
         Self {
             registry,
             processor: Some(processor),
+            compact: false,
+        }
+    }
+}
+
+impl<P> ReflectDeserializer<'_, P> {
+    /// Sets whether to use the compact format, the default is `false`.
+    ///
+    /// It must match the serializer, see [`ReflectSerializer::with_compact`].
+    ///
+    /// [`ReflectSerializer::with_compact`]: crate::serde::ReflectSerializer::with_compact
+    #[inline]
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Deserializes a value from a `'static` input, borrowing data instead of copying it.
+    ///
+    /// See [`InternalDeserializer::deserialize_borrowed`].
+    pub fn deserialize_borrowed<D: Deserializer<'static>>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn Reflect>, D::Error>
+    where
+        P: DeserializerProcessor<'static>,
+    {
+        let mut processor = BorrowedProcessor {
+            processor: self.processor,
+        };
+        ReflectDeserializer {
+            registry: self.registry,
+            processor: Some(&mut processor),
+            compact: self.compact,
         }
+        .deserialize(deserializer)
     }
 }
 
-impl<'de, P: DeserializerProcessor> DeserializeSeed<'de> for ReflectDeserializer<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> DeserializeSeed<'de> for ReflectDeserializer<'_, P> {
     type Value = Box<dyn Reflect>;
 
     fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
         struct ReflectDeserializerVisitor<'a, P> {
             registry: &'a TypeRegistry,
             processor: Option<&'a mut P>,
+            compact: bool,
         }
 
-        impl<'de, P: DeserializerProcessor> Visitor<'de> for ReflectDeserializerVisitor<'_, P> {
+        impl<'de, P> Visitor<'de> for ReflectDeserializerVisitor<'_, P>
+        where
+            P: DeserializerProcessor<'de>,
+        {
             type Value = Box<dyn Reflect>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter
-                    .write_str("map containing `type` and `value` entries for the reflected value")
+                if self.compact {
+                    formatter.write_str("tuple containing type path hash, version and value")
+                } else {
+                    formatter.write_str(
+                        "map containing `type` and `value` entries for the reflected value",
+                    )
+                }
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let hash = seq
+                    .next_element::<u64>()?
+                    .ok_or_else(|| Error::invalid_length(0, &"3"))?;
+                let version = seq
+                    .next_element::<u32>()?
+                    .ok_or_else(|| Error::invalid_length(1, &"3"))?;
+
+                let type_traits = self.registry.get_with_type_path_hash(hash).ok_or_else(|| {
+                    Error::custom(format!(
+                        "no registration found for type path hash `{hash:#018x}`"
+                    ))
+                })?;
+
+                seq.next_element_seed(
+                    InternalDeserializer::new_internal(
+                        type_traits,
+                        self.registry,
+                        self.processor,
+                        self.compact,
+                    )
+                    .with_data_version(version),
+                )?
+                .ok_or_else(|| Error::invalid_length(2, &"3"))
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
             where
                 A: MapAccess<'de>,
             {
-                // Get `TypeTraits` from registry
-                let type_traits = map
-                    .next_key_seed(TypePathDeserializer::new(self.registry))?
-                    .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
-
-                let value = map.next_value_seed(InternalDeserializer::new_internal(
-                    type_traits,
-                    self.registry,
-                    self.processor,
-                ))?;
-
-                if map.next_key::<IgnoredAny>()?.is_some() {
-                    return Err(Error::invalid_length(2, &"a single entry"));
+                let mut processor = self.processor;
+                let mut deserializer = |type_traits, version: Option<u32>| {
+                    // The data without version entry is version `0`.
+                    InternalDeserializer::new_internal(
+                        type_traits,
+                        self.registry,
+                        processor.take(),
+                        self.compact,
+                    )
+                    .with_data_version(version.unwrap_or(0))
+                };
+
+                // The version entry of versioned types usually comes before the type path entry,
+                // otherwise the value is buffered until the version is known.
+                let mut version = None;
+                let mut content: Option<(&TypeTraits, Content)> = None;
+                loop {
+                    match map.next_key_seed(EntryKeyDeserializer(self.registry))? {
+                        Some(EntryKey::Version) if version.is_none() => {
+                            version = Some(map.next_value::<u32>()?);
+                        }
+                        Some(EntryKey::Version) => return Err(Error::duplicate_field(VERSION_KEY)),
+                        Some(EntryKey::Type(_)) if content.is_some() => {
+                            return Err(Error::custom("unexpected entry after the reflected value"));
+                        }
+                        Some(EntryKey::Type(type_traits))
+                            if version.is_some() || type_traits.type_info().version() == 0 =>
+                        {
+                            let value = map.next_value_seed(deserializer(type_traits, version))?;
+                            if map.next_key::<IgnoredAny>()?.is_some() {
+                                return Err(Error::custom(
+                                    "unexpected entry after the reflected value",
+                                ));
+                            }
+                            return Ok(value);
+                        }
+                        Some(EntryKey::Type(type_traits)) => {
+                            content = Some((type_traits, map.next_value::<Content>()?));
+                        }
+                        None => break,
+                    }
                 }
 
-                Ok(value)
+                let Some((type_traits, content)) = content else {
+                    return Err(Error::missing_field("type path"));
+                };
+                deserializer(type_traits, version).deserialize(ContentDeserializer::new(content))
             }
         }
 
-        deserializer.deserialize_map(ReflectDeserializerVisitor {
+        let visitor = ReflectDeserializerVisitor {
             registry: self.registry,
             processor: self.processor,
-        })
+            compact: self.compact,
+        };
+
+        if self.compact {
+            deserializer.deserialize_tuple(3, visitor)
+        } else {
+            deserializer.deserialize_map(visitor)
+        }
+    }
+}
+
+/// The key of an entry in the map written by [`ReflectSerializer`].
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+enum EntryKey<'a> {
+    Version,
+    Type(&'a TypeTraits),
+}
+
+struct EntryKeyDeserializer<'a>(&'a TypeRegistry);
+
+impl<'a, 'de> DeserializeSeed<'de> for EntryKeyDeserializer<'a> {
+    type Value = EntryKey<'a>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct EntryKeyVisitor<'a>(&'a TypeRegistry);
+
+        impl<'de, 'a> Visitor<'de> for EntryKeyVisitor<'a> {
+            type Value = EntryKey<'a>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("string containing `version` or `type` entry")
+            }
+
+            fn visit_str<E: Error>(self, key: &str) -> Result<Self::Value, E> {
+                if key == VERSION_KEY {
+                    return Ok(EntryKey::Version);
+                }
+                self.0
+                    .get_with_type_path(key)
+                    .map(EntryKey::Type)
+                    .ok_or_else(|| Error::custom(format!("no registration found for `{key}`")))
+            }
+        }
+
+        deserializer.deserialize_str(EntryKeyVisitor(self.0))
     }
 }
 
@@ -343,3 +764,144 @@ impl<'a, 'de> DeserializeSeed<'de> for TypePathDeserializer<'a> {
         deserializer.deserialize_str(TypePathVisitor(self.registry))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        borrow::Cow,
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use core::any::TypeId;
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect, Reflect,
+        info::TypePath,
+        ops::DynamicStruct,
+        registry::{TypeRegistry, TypeTraitMigrate},
+        serde::{InternalDeserializer, InternalSerializer, ReflectDeserializer, ReflectSerializer},
+    };
+
+    // version 1: `struct Save { hp: u32 }`
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(version = 2, partial_eq)]
+    struct Save {
+        health: u32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    struct World {
+        saves: Vec<Save>,
+    }
+
+    fn migrate_save(
+        version: u32,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<dyn Reflect>, Cow<'static, str>> {
+        let mut value = value.downcast::<DynamicStruct>().map_err(|_| "expected a struct")?;
+        if version < 2 {
+            let hp = value.remove("hp").ok_or("missing `hp`")?;
+            value.insert_boxed("health", hp);
+        }
+        Ok(value)
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Save>();
+        registry.register::<World>();
+        registry.register::<Vec<Save>>();
+        registry
+            .get_mut(TypeId::of::<Save>())
+            .unwrap()
+            .insert(TypeTraitMigrate::new(migrate_save).with_legacy_field::<u32>("hp"));
+        registry
+    }
+
+    #[test]
+    fn migrate_top_level() {
+        let registry = registry();
+        let save = Save { health: 5 };
+        let json = serde_json::to_value(ReflectSerializer::new(&save, &registry)).unwrap();
+        let path = Save::type_path();
+        assert_eq!(json, serde_json::json!({ "version": 2, path: { "health": 5 } }));
+
+        let old = serde_json::json!({ "version": 1, path: { "hp": 7 } });
+        let value = ReflectDeserializer::new(&registry).deserialize(&old).unwrap();
+        assert_eq!(Save::from_reflect(value.as_ref()), Some(Save { health: 7 }));
+
+        let new = serde_json::json!({ "version": 3, path: { "health": 7 } });
+        let err = ReflectDeserializer::new(&registry).deserialize(&new).err().unwrap();
+        assert!(err.to_string().contains("newer than version `2`"));
+    }
+
+    #[test]
+    fn failed_conversion_is_an_error() {
+        // A broken migration: the legacy `hp` is a string, but it's moved into `health: u32`.
+        let mut registry = TypeRegistry::new();
+        registry.register::<Save>();
+        registry
+            .get_mut(TypeId::of::<Save>())
+            .unwrap()
+            .insert(TypeTraitMigrate::new(migrate_save).with_legacy_field::<String>("hp"));
+
+        let path = Save::type_path();
+        let old = serde_json::json!({ "version": 1, path: { "hp": "7" } });
+        let err = ReflectDeserializer::new(&registry).deserialize(&old).err().unwrap();
+        let message = err.to_string();
+        assert!(message.contains(&format!("cannot convert the data to `{path}`")));
+        assert!(message.contains("expected `u32`"));
+        assert!(message.ends_with("at `.health`"));
+    }
+
+    #[test]
+    fn migrate_nested() {
+        let registry = registry();
+        let world = World { saves: vec![Save { health: 5 }] };
+        let json = serde_json::to_value(InternalSerializer::new(&world, &registry)).unwrap();
+        let expected = serde_json::json!({ "saves": [{ "version": 2, "value": { "health": 5 } }] });
+        assert_eq!(json, expected);
+
+        let value = InternalDeserializer::of::<World>(&registry).deserialize(&json).unwrap();
+        assert_eq!(World::from_reflect(value.as_ref()), Some(world));
+
+        let old = serde_json::json!({ "saves": [
+            { "version": 1, "value": { "hp": 3 } },
+            { "version": 2, "value": { "health": 4 } },
+        ] });
+        let value = InternalDeserializer::of::<World>(&registry).deserialize(&old).unwrap();
+        let expected = World { saves: vec![Save { health: 3 }, Save { health: 4 }] };
+        assert_eq!(World::from_reflect(value.as_ref()), Some(expected));
+
+        // Unwrapped values predate the versioning of the type, they are migrated as version 0.
+        let unversioned = serde_json::json!({ "saves": [
+            { "hp": 4 },
+            { "version": 2, "value": { "health": 6 } },
+        ] });
+        let value = InternalDeserializer::of::<World>(&registry).deserialize(&unversioned).unwrap();
+        let expected = World { saves: vec![Save { health: 4 }, Save { health: 6 }] };
+        assert_eq!(World::from_reflect(value.as_ref()), Some(expected));
+
+        let missing = serde_json::json!({ "saves": [{ "value": { "health": 4 } }] });
+        assert!(InternalDeserializer::of::<World>(&registry).deserialize(&missing).is_err());
+    }
+
+    #[test]
+    fn migrate_nested_compact() {
+        let registry = registry();
+        let world = World { saves: vec![Save { health: 5 }] };
+        let serializer = InternalSerializer::new(&world, &registry).with_compact(true);
+        let json = serde_json::to_value(serializer).unwrap();
+        assert_eq!(json, serde_json::json!([[[2, [5]]]]));
+
+        let deserializer = InternalDeserializer::of::<World>(&registry).with_compact(true);
+        let value = deserializer.deserialize(&json).unwrap();
+        assert_eq!(World::from_reflect(value.as_ref()), Some(world));
+    }
+}
@@ -0,0 +1,451 @@
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::{fmt, fmt::Formatter, marker::PhantomData};
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{DeserializeSeed, EnumAccess, Error, SeqAccess, VariantAccess, Visitor},
+};
+
+use crate::{
+    Reflect,
+    diff::{FieldDiff, ListDiff, MapDiff, ReflectDiff, SetDiff},
+    registry::TypeRegistry,
+    serde::ser::{
+        DIFF_NAME, DIFF_VARIANTS, FIELD_DIFF_NAME, FIELD_DIFF_VARIANTS, LIST_DIFF_NAME,
+        LIST_DIFF_VARIANTS, MAP_DIFF_NAME, MAP_DIFF_VARIANTS, SET_DIFF_NAME, SET_DIFF_VARIANTS,
+    },
+};
+
+use super::{SelfDescribingDeserializer, self_describing::VariantIndex};
+
+/// A deserializer for the data written by [`ReflectDiffSerializer`].
+///
+/// Recorded values are deserialized by [`SelfDescribingDeserializer`] as dynamic values,
+/// so only their opaque types must be registered. Use [`apply_diff_with_registry`] to apply
+/// diffs with non-opaque map keys or set values to dynamic maps and sets.
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+/// [`apply_diff_with_registry`]: crate::diff::apply_diff_with_registry
+#[derive(Clone, Copy)]
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    #[inline]
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'_> {
+    type Value = ReflectDiff;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        ReflectDiff::deserialize_with(self.registry, deserializer)
+    }
+}
+
+/// Like [`Deserialize`], with the registry used by recorded values.
+trait DeserializeWith: Sized {
+    fn deserialize_with<'de, D: Deserializer<'de>>(
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+struct With<'a, T>(&'a TypeRegistry, PhantomData<T>);
+
+impl<'a, T> With<'a, T> {
+    #[inline]
+    fn new(registry: &'a TypeRegistry) -> Self {
+        Self(registry, PhantomData)
+    }
+}
+
+impl<'de, T: DeserializeWith> DeserializeSeed<'de> for With<'_, T> {
+    type Value = T;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_with(self.0, deserializer)
+    }
+}
+
+impl DeserializeWith for usize {
+    #[inline]
+    fn deserialize_with<'de, D: Deserializer<'de>>(
+        _registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        usize::deserialize(deserializer)
+    }
+}
+
+impl DeserializeWith for Cow<'static, str> {
+    #[inline]
+    fn deserialize_with<'de, D: Deserializer<'de>>(
+        _registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        Ok(Cow::Owned(String::deserialize(deserializer)?))
+    }
+}
+
+impl DeserializeWith for Box<dyn Reflect> {
+    #[inline]
+    fn deserialize_with<'de, D: Deserializer<'de>>(
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        SelfDescribingDeserializer::new(registry).deserialize(deserializer)
+    }
+}
+
+impl<T: DeserializeWith> DeserializeWith for Vec<T> {
+    fn deserialize_with<'de, D: Deserializer<'de>>(
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct SeqVisitor<'a, T>(&'a TypeRegistry, PhantomData<T>);
+
+        impl<'de, T: DeserializeWith> Visitor<'de> for SeqVisitor<'_, T> {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut items = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(item) = seq.next_element_seed(With::new(self.0))? {
+                    items.push(item);
+                }
+                Ok(items)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor(registry, PhantomData))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($len:literal: $($name:ident),*) => {
+        impl<$($name: DeserializeWith),*> DeserializeWith for ($($name,)*) {
+            fn deserialize_with<'de, D: Deserializer<'de>>(
+                registry: &TypeRegistry,
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                struct TupleVisitor<'a, T>(&'a TypeRegistry, PhantomData<T>);
+
+                impl<'de, $($name: DeserializeWith),*> Visitor<'de>
+                    for TupleVisitor<'_, ($($name,)*)>
+                {
+                    type Value = ($($name,)*);
+
+                    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                        write!(formatter, "tuple of {} elements", $len)
+                    }
+
+                    #[expect(non_snake_case, reason = "reuse the type parameter names")]
+                    fn visit_seq<A: SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        let mut index = 0;
+                        $(
+                            let $name = seq
+                                .next_element_seed(With::<$name>::new(self.0))?
+                                .ok_or_else(|| Error::invalid_length(index, &self))?;
+                            index += 1;
+                        )*
+                        let _ = index;
+                        Ok(($($name,)*))
+                    }
+                }
+
+                deserializer.deserialize_tuple($len, TupleVisitor(registry, PhantomData))
+            }
+        }
+    };
+}
+
+impl_tuple!(2: T0, T1);
+impl_tuple!(3: T0, T1, T2);
+
+/// Deserializes an enum with variants known by index.
+trait DeserializeEnum: Sized {
+    const NAME: &'static str;
+    const VARIANTS: &'static [&'static str];
+
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        registry: &TypeRegistry,
+        index: usize,
+        variant: A,
+    ) -> Result<Self, A::Error>;
+}
+
+struct EnumVisitor<'a, T>(&'a TypeRegistry, PhantomData<T>);
+
+impl<'de, T: DeserializeEnum> Visitor<'de> for EnumVisitor<'_, T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "enum {}", T::NAME)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (index, variant) = data.variant_seed(VariantIndex {
+            variants: T::VARIANTS,
+        })?;
+        T::deserialize_variant(self.0, index, variant)
+    }
+}
+
+macro_rules! impl_deserialize_with_enum {
+    ($($ty:ty),*) => {
+        $(
+            impl DeserializeWith for $ty {
+                #[inline]
+                fn deserialize_with<'de, D: Deserializer<'de>>(
+                    registry: &TypeRegistry,
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    deserializer.deserialize_enum(
+                        <$ty as DeserializeEnum>::NAME,
+                        <$ty as DeserializeEnum>::VARIANTS,
+                        EnumVisitor::<$ty>(registry, PhantomData),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize_with_enum!(ReflectDiff, FieldDiff, ListDiff, MapDiff, SetDiff);
+
+impl DeserializeEnum for ReflectDiff {
+    const NAME: &'static str = DIFF_NAME;
+    const VARIANTS: &'static [&'static str] = DIFF_VARIANTS;
+
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        registry: &TypeRegistry,
+        index: usize,
+        variant: A,
+    ) -> Result<Self, A::Error> {
+        let seed = With::new(registry);
+        Ok(match index {
+            0 => {
+                variant.unit_variant()?;
+                Self::Unchanged
+            }
+            1 => Self::Replaced(variant.newtype_variant_seed(seed)?),
+            2 => Self::Fields(variant.newtype_variant_seed(With::new(registry))?),
+            3 => Self::Elements(variant.newtype_variant_seed(With::new(registry))?),
+            4 => Self::List(variant.newtype_variant_seed(With::new(registry))?),
+            5 => Self::Map(variant.newtype_variant_seed(With::new(registry))?),
+            6 => Self::Set(variant.newtype_variant_seed(With::new(registry))?),
+            _ => Self::VariantSwitched(variant.newtype_variant_seed(seed)?),
+        })
+    }
+}
+
+impl DeserializeEnum for FieldDiff {
+    const NAME: &'static str = FIELD_DIFF_NAME;
+    const VARIANTS: &'static [&'static str] = FIELD_DIFF_VARIANTS;
+
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        registry: &TypeRegistry,
+        index: usize,
+        variant: A,
+    ) -> Result<Self, A::Error> {
+        Ok(match index {
+            0 => Self::Changed(variant.newtype_variant_seed(With::new(registry))?),
+            1 => Self::Added(variant.newtype_variant_seed(With::new(registry))?),
+            _ => {
+                variant.unit_variant()?;
+                Self::Removed
+            }
+        })
+    }
+}
+
+impl DeserializeEnum for ListDiff {
+    const NAME: &'static str = LIST_DIFF_NAME;
+    const VARIANTS: &'static [&'static str] = LIST_DIFF_VARIANTS;
+
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        registry: &TypeRegistry,
+        index: usize,
+        variant: A,
+    ) -> Result<Self, A::Error> {
+        Ok(match index {
+            0 => {
+                let (index, diff) = variant.newtype_variant_seed(With::new(registry))?;
+                Self::Changed { index, diff }
+            }
+            _ => {
+                let (index, removed, inserted) =
+                    variant.newtype_variant_seed(With::new(registry))?;
+                Self::Spliced {
+                    index,
+                    removed,
+                    inserted,
+                }
+            }
+        })
+    }
+}
+
+impl DeserializeEnum for MapDiff {
+    const NAME: &'static str = MAP_DIFF_NAME;
+    const VARIANTS: &'static [&'static str] = MAP_DIFF_VARIANTS;
+
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        registry: &TypeRegistry,
+        index: usize,
+        variant: A,
+    ) -> Result<Self, A::Error> {
+        Ok(match index {
+            0 => {
+                let (key, diff) = variant.newtype_variant_seed(With::new(registry))?;
+                Self::Changed { key, diff }
+            }
+            1 => {
+                let (key, value) = variant.newtype_variant_seed(With::new(registry))?;
+                Self::Inserted { key, value }
+            }
+            _ => Self::Removed {
+                key: variant.newtype_variant_seed(With::new(registry))?,
+            },
+        })
+    }
+}
+
+impl DeserializeEnum for SetDiff {
+    const NAME: &'static str = SET_DIFF_NAME;
+    const VARIANTS: &'static [&'static str] = SET_DIFF_VARIANTS;
+
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        registry: &TypeRegistry,
+        index: usize,
+        variant: A,
+    ) -> Result<Self, A::Error> {
+        Ok(match index {
+            0 => Self::Inserted(variant.newtype_variant_seed(With::new(registry))?),
+            _ => Self::Removed(variant.newtype_variant_seed(With::new(registry))?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+    use serde::de::DeserializeSeed;
+    use std::collections::HashMap;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        diff::{apply_diff, apply_diff_with_registry, diff},
+        info::Typed,
+        ops::DynamicMap,
+        registry::TypeRegistry,
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+    };
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    enum Shape {
+        Dot(Point),
+        Line { from: Point, to: Point },
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq)]
+    struct Scene {
+        points: Vec<Point>,
+        names: BTreeMap<String, u32>,
+        shape: Shape,
+    }
+
+    #[test]
+    fn diff_round_trip() {
+        // Only opaque types are registered, recorded values are self-describing.
+        let mut registry = TypeRegistry::new();
+        registry.register::<u32>();
+        registry.register::<String>();
+
+        let old = Scene {
+            points: vec![Point { x: 1, y: 2 }],
+            names: BTreeMap::from([("a".into(), 1)]),
+            shape: Shape::Dot(Point { x: 0, y: 0 }),
+        };
+        let new = Scene {
+            points: vec![Point { x: 1, y: 3 }, Point { x: 4, y: 5 }],
+            names: BTreeMap::from([("b".into(), 2)]),
+            shape: Shape::Line {
+                from: Point { x: 0, y: 0 },
+                to: Point { x: 1, y: 1 },
+            },
+        };
+
+        let changes = diff(&old, &new).unwrap();
+        let json = serde_json::to_string(&ReflectDiffSerializer::new(&changes, &registry)).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let changes = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut target = old.clone();
+        apply_diff(&mut target, &changes).unwrap();
+        assert_eq!(target, new);
+    }
+
+    #[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash)]
+    #[reflect(hash, partial_eq)]
+    struct Cell {
+        x: u32,
+        y: u32,
+    }
+
+    #[test]
+    fn diff_round_trip_struct_keys() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<u32>();
+        registry.register::<Cell>();
+
+        let cell = |x, y| Cell { x, y };
+        let old = HashMap::from([(cell(0, 0), 1u32), (cell(1, 0), 2), (cell(2, 0), 3)]);
+        let mut new = old.clone();
+        new.insert(cell(0, 0), 5);
+        new.remove(&cell(1, 0));
+        new.insert(cell(3, 0), 4);
+
+        // Recorded keys are deserialized as `DynamicStruct`s.
+        let changes = diff(&old, &new).unwrap();
+        let json = serde_json::to_string(&ReflectDiffSerializer::new(&changes, &registry)).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let changes = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut target = old.clone();
+        apply_diff(&mut target, &changes).unwrap();
+        assert_eq!(target, new);
+
+        // Dynamic maps can only look up the converted keys.
+        let mut target = DynamicMap::new();
+        target.set_type_info(Some(<HashMap<Cell, u32> as Typed>::type_info()));
+        for (key, value) in &old {
+            target.insert(key.clone(), *value);
+        }
+        apply_diff_with_registry(&mut target, &changes, &registry).unwrap();
+        assert_eq!(HashMap::<Cell, u32>::from_reflect(&target), Some(new));
+    }
+}
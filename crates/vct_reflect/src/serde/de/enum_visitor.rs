@@ -5,25 +5,28 @@ use serde::de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, Varian
 use crate::{
     info::{EnumInfo, StructVariantInfo, TupleVariantInfo, VariantInfo},
     ops::{DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant},
-    registry::TypeRegistry,
+    registry::{TypeRegistry, TypeTraitMigrate},
 };
 
 use super::{
     DeserializerProcessor, InternalDeserializer,
-    struct_like_utils::{visit_struct, visit_struct_seq},
+    struct_like_utils::{StructLikeInfo, visit_struct, visit_struct_seq},
     tuple_like_utils::{TupleLikeInfo, visit_tuple},
 };
 
 /// A [`Visitor`] for deserializing [`Enum`] values.
 ///
 /// [`Enum`]: crate::Enum
-pub(super) struct EnumVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct EnumVisitor<'a, P> {
     pub enum_info: &'static EnumInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
+    /// Set when the data is outdated, see [`visit_struct`] and [`visit_tuple`].
+    pub legacy: Option<&'a TypeTraitMigrate>,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for EnumVisitor<'_, P> {
     type Value = DynamicEnum;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -42,11 +45,13 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
             VariantInfo::Unit(_) => variant.unit_variant()?.into(),
             VariantInfo::Struct(struct_info) => variant
                 .struct_variant(
-                    struct_info.field_names(),
+                    struct_info.serde_field_names(),
                     StructVariantVisitor {
                         struct_info,
                         registry: self.registry,
                         processor: self.processor,
+                        compact: self.compact,
+                        legacy: self.legacy,
                     },
                 )?
                 .into(),
@@ -62,6 +67,7 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
                     type_traits,
                     self.registry,
                     self.processor,
+                    self.compact,
                 ))?;
                 let mut dynamic_tuple = DynamicTuple::with_capacity(1);
                 dynamic_tuple.insert_boxed(value);
@@ -74,6 +80,8 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
                         tuple_info,
                         registry: self.registry,
                         processor: self.processor,
+                        compact: self.compact,
+                        legacy: self.legacy,
                     },
                 )?
                 .into(),
@@ -90,8 +98,8 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
     }
 }
 
-struct VariantDeserializer {
-    enum_info: &'static EnumInfo,
+pub(super) struct VariantDeserializer {
+    pub enum_info: &'static EnumInfo,
 }
 
 impl<'de> DeserializeSeed<'de> for VariantDeserializer {
@@ -141,13 +149,15 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
     }
 }
 
-struct StructVariantVisitor<'a, P: DeserializerProcessor> {
-    struct_info: &'static StructVariantInfo,
-    registry: &'a TypeRegistry,
-    processor: Option<&'a mut P>,
+pub(super) struct StructVariantVisitor<'a, P> {
+    pub struct_info: &'static StructVariantInfo,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+    pub compact: bool,
+    pub legacy: Option<&'a TypeTraitMigrate>,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for StructVariantVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for StructVariantVisitor<'_, P> {
     type Value = DynamicStruct;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -158,24 +168,40 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for StructVariantVisitor<'_, P>
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registry, self.processor)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registry,
+            self.processor,
+            self.compact,
+            self.legacy.is_some(),
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registry, self.processor)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registry,
+            self.processor,
+            self.compact,
+            self.legacy,
+        )
     }
 }
 
-struct TupleVariantVisitor<'a, P: DeserializerProcessor> {
-    tuple_info: &'static TupleVariantInfo,
-    registry: &'a TypeRegistry,
-    processor: Option<&'a mut P>,
+pub(super) struct TupleVariantVisitor<'a, P> {
+    pub tuple_info: &'static TupleVariantInfo,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+    pub compact: bool,
+    pub legacy: Option<&'a TypeTraitMigrate>,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleVariantVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for TupleVariantVisitor<'_, P> {
     type Value = DynamicTuple;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -186,6 +212,127 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleVariantVisitor<'_, P>
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registry, self.processor)
+        visit_tuple(
+            &mut seq,
+            self.tuple_info,
+            self.registry,
+            self.processor,
+            self.compact,
+            self.legacy.is_some(),
+        )
+    }
+}
+
+/// A [`Visitor`] for deserializing [`Enum`] values in the compact format,
+/// that is `(variant_index, (fields..))`.
+///
+/// [`Enum`]: crate::Enum
+pub(super) struct CompactEnumVisitor<'a, P> {
+    pub enum_info: &'static EnumInfo,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+    pub legacy: Option<&'a TypeTraitMigrate>,
+}
+
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for CompactEnumVisitor<'_, P> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected enum value in compact format")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let variant_index = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &"2"))?;
+        let Some(variant_info) = self.enum_info.variant_at(variant_index as usize) else {
+            return Err(Error::custom(format!(
+                "no variant found at index `{}` on enum `{}`",
+                variant_index,
+                self.enum_info.type_path()
+            )));
+        };
+
+        let value = seq
+            .next_element_seed(CompactVariantDeserializer {
+                variant_info,
+                registry: self.registry,
+                processor: self.processor,
+                legacy: self.legacy,
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &"2"))?;
+
+        Ok(DynamicEnum::new_with_index(
+            variant_index as usize,
+            variant_info.name(),
+            value,
+        ))
+    }
+}
+
+struct CompactVariantDeserializer<'a, P> {
+    variant_info: &'static VariantInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    legacy: Option<&'a TypeTraitMigrate>,
+}
+
+impl<'de, P> DeserializeSeed<'de> for CompactVariantDeserializer<'_, P>
+where
+    P: DeserializerProcessor<'de>,
+{
+    type Value = DynamicVariant;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct UnitVariantVisitor;
+
+        impl<'de> Visitor<'de> for UnitVariantVisitor {
+            type Value = DynamicVariant;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("reflected unit variant value")
+            }
+
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(DynamicVariant::Unit)
+            }
+        }
+
+        match self.variant_info {
+            VariantInfo::Unit(_) => deserializer.deserialize_tuple(0, UnitVariantVisitor),
+            VariantInfo::Struct(struct_info) => deserializer
+                .deserialize_tuple(
+                    struct_info.serialized_field_len(),
+                    StructVariantVisitor {
+                        struct_info,
+                        registry: self.registry,
+                        processor: self.processor,
+                        compact: true,
+                        legacy: self.legacy,
+                    },
+                )
+                .map(DynamicVariant::from),
+            VariantInfo::Tuple(tuple_info) => deserializer
+                .deserialize_tuple(
+                    tuple_info.serialized_field_len(),
+                    TupleVariantVisitor {
+                        tuple_info,
+                        registry: self.registry,
+                        processor: self.processor,
+                        compact: true,
+                        legacy: self.legacy,
+                    },
+                )
+                .map(DynamicVariant::from),
+        }
     }
 }
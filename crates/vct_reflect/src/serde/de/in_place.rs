@@ -0,0 +1,567 @@
+use alloc::{
+    boxed::Box,
+    format,
+    string::ToString,
+    vec::Vec,
+};
+use core::{fmt, fmt::Formatter};
+use serde::{
+    Deserializer,
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
+};
+
+use crate::{
+    Reflect,
+    info::{ListInfo, StructInfo, TypeInfo, UnnamedField},
+    ops::{Array, List, ReflectMut, Struct, Tuple, TupleStruct},
+    registry::{TypeRegistry, TypeTraitDeserialize, TypeTraits},
+    serde::SkipSerde,
+};
+
+use super::{
+    DeserializerProcessor, InternalDeserializer,
+    struct_like_utils::{Ident, StructLikeInfo},
+};
+
+/// A [`DeserializeSeed`] that deserializes data directly into an existing value.
+///
+/// Unlike [`InternalDeserializer`], struct, tuple struct, tuple, array and list values are
+/// written field by field through [`Reflect::reflect_mut`], no dynamic intermediate
+/// value is created for them. Other values (and types with [`TypeTraitDeserialize`])
+/// are deserialized as a whole and then replaced.
+///
+/// The data format is the same as [`InternalDeserializer`] (non-compact), but:
+/// - Missing struct fields keep their current values, default values are not used.
+/// - Fields with [`SkipSerde`] are left untouched.
+/// - Lists are resized to the length of the data.
+///
+/// This is useful for reloading large values, such as configs or scenes.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut deserializer = serde_json::Deserializer::from_str(r#"{ "hp": 10 }"#);
+/// ReflectDeserializeInPlace::new(&mut player, &registry).deserialize(&mut deserializer)?;
+/// ```
+pub struct ReflectDeserializeInPlace<'a, P = ()> {
+    value: &'a mut dyn Reflect,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    nested: bool,
+}
+
+impl<'a> ReflectDeserializeInPlace<'a, ()> {
+    #[inline]
+    pub fn new(value: &'a mut dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self {
+            value,
+            registry,
+            processor: None,
+            nested: false,
+        }
+    }
+}
+
+impl<'a, P> ReflectDeserializeInPlace<'a, P> {
+    #[inline]
+    pub fn with_processor(
+        value: &'a mut dyn Reflect,
+        registry: &'a TypeRegistry,
+        processor: &'a mut P,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            processor: Some(processor),
+            nested: false,
+        }
+    }
+
+    #[inline]
+    fn new_internal(
+        value: &'a mut dyn Reflect,
+        registry: &'a TypeRegistry,
+        processor: Option<&'a mut P>,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            processor,
+            nested: true,
+        }
+    }
+}
+
+impl<'de, P: DeserializerProcessor<'de>> DeserializeSeed<'de> for ReflectDeserializeInPlace<'_, P> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(
+        mut self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let Some(type_info) = self.value.represented_type_info() else {
+            return Err(Error::custom(format!(
+                "value `{}` does not represent any type",
+                self.value.reflect_type_path(),
+            )));
+        };
+        let Some(type_traits) = self.registry.get(type_info.type_id()) else {
+            return Err(Error::custom(format!(
+                "no type_traits found for type `{}`",
+                type_info.type_path(),
+            )));
+        };
+
+        // Nested values of versioned types may need migration, see `InternalSerializer`.
+        let in_place = !self.value.is_dynamic()
+            && (!self.nested || type_info.version() == 0)
+            && matches!(
+                type_info,
+                TypeInfo::Struct(_)
+                    | TypeInfo::TupleStruct(_)
+                    | TypeInfo::Tuple(_)
+                    | TypeInfo::List(_)
+                    | TypeInfo::Array(_)
+            );
+        if !in_place {
+            let value = InternalDeserializer::new_internal(
+                type_traits,
+                self.registry,
+                self.processor,
+                false,
+            )
+            .with_nested(self.nested)
+            .deserialize(deserializer)?;
+            return replace(self.value, value);
+        }
+
+        let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
+            match processor.try_deserialize(type_traits, self.registry, deserializer) {
+                Ok(Ok(value)) => return replace(self.value, value),
+                Ok(Err(err)) => return Err(err),
+                Err(deserializer) => deserializer,
+            }
+        } else {
+            deserializer
+        };
+
+        if let Some(deserialize_reflect) = type_traits.get::<TypeTraitDeserialize>() {
+            let value = deserialize_reflect.deserialize(deserializer)?;
+            return replace(self.value, value);
+        }
+
+        deserialize_in_place(
+            self.value.reflect_mut(),
+            type_traits,
+            self.registry,
+            self.processor,
+            deserializer,
+        )
+    }
+}
+
+/// Replaces the target with a deserialized value, applying it if the types are different.
+fn replace<E: Error>(target: &mut dyn Reflect, value: Box<dyn Reflect>) -> Result<(), E> {
+    if let Err(value) = target.set(value) {
+        target.try_apply(&*value).map_err(Error::custom)?;
+    }
+    Ok(())
+}
+
+fn deserialize_in_place<'de, D, P>(
+    target: ReflectMut<'_>,
+    type_traits: &TypeTraits,
+    registry: &TypeRegistry,
+    processor: Option<&mut P>,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+    P: DeserializerProcessor<'de>,
+{
+    match (type_traits.type_info(), target) {
+        (TypeInfo::Struct(struct_info), ReflectMut::Struct(dyn_struct)) => deserializer
+            .deserialize_struct(
+                struct_info.type_path_table().ident(),
+                struct_info.serde_field_names(),
+                StructInPlaceVisitor {
+                    dyn_struct,
+                    struct_info,
+                    registry,
+                    processor,
+                },
+            ),
+        (TypeInfo::TupleStruct(tuple_struct_info), ReflectMut::TupleStruct(dyn_tuple_struct)) => {
+            let len = tuple_struct_info.field_len();
+            let visitor = TupleInPlaceVisitor {
+                tuple: TupleLikeMut::TupleStruct(dyn_tuple_struct),
+                fields: tuple_struct_info.iter().as_slice(),
+                len,
+                registry,
+                processor,
+            };
+            let ident = tuple_struct_info.type_path_table().ident();
+            if len == 1 {
+                deserializer.deserialize_newtype_struct(ident, visitor)
+            } else {
+                deserializer.deserialize_tuple_struct(ident, len, visitor)
+            }
+        }
+        (TypeInfo::Tuple(tuple_info), ReflectMut::Tuple(dyn_tuple)) => deserializer
+            .deserialize_tuple(
+                tuple_info.field_len(),
+                TupleInPlaceVisitor {
+                    tuple: TupleLikeMut::Tuple(dyn_tuple),
+                    fields: tuple_info.iter().as_slice(),
+                    len: tuple_info.field_len(),
+                    registry,
+                    processor,
+                },
+            ),
+        (TypeInfo::Array(array_info), ReflectMut::Array(dyn_array)) => deserializer
+            .deserialize_tuple(
+                array_info.capacity(),
+                TupleInPlaceVisitor {
+                    tuple: TupleLikeMut::Array(dyn_array),
+                    fields: &[],
+                    len: array_info.capacity(),
+                    registry,
+                    processor,
+                },
+            ),
+        (TypeInfo::List(list_info), ReflectMut::List(dyn_list)) => {
+            deserializer.deserialize_seq(ListInPlaceVisitor {
+                dyn_list,
+                list_info,
+                registry,
+                processor,
+            })
+        }
+        (type_info, _) => Err(Error::custom(format!(
+            "cannot deserialize `{}` in place, the kind of the value does not match its type info",
+            type_info.type_path(),
+        ))),
+    }
+}
+
+/// A [`Visitor`] for deserializing fields into an existing [`Struct`].
+struct StructInPlaceVisitor<'a, P> {
+    dyn_struct: &'a mut dyn Struct,
+    struct_info: &'static StructInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for StructInPlaceVisitor<'_, P> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected struct value")
+    }
+
+    fn visit_map<V>(mut self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut seen = Vec::with_capacity(self.struct_info.field_len());
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            let field = match self.struct_info.serde_field(&key) {
+                Some(field) if seen.contains(&field.name()) => {
+                    return Err(Error::duplicate_field(field.serde_name()));
+                }
+                Some(field) if !field.has_attribute::<SkipSerde>() => field,
+                Some(_) => {
+                    map.next_value::<IgnoredAny>()?;
+                    continue;
+                }
+                None if self.struct_info.deny_unknown_fields() => {
+                    return Err(self.struct_info.unknown_field(&key));
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                    continue;
+                }
+            };
+
+            let Some(value) = self.dyn_struct.field_mut(field.name()) else {
+                return Err(self.struct_info.unknown_field(field.name()));
+            };
+            seen.push(field.name());
+            map.next_value_seed(ReflectDeserializeInPlace::new_internal(
+                value,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let len = self.struct_info.field_len();
+
+        for index in 0..len {
+            let field = StructLikeInfo::field_at::<V::Error>(self.struct_info, index)?;
+            if field.has_attribute::<SkipSerde>() {
+                continue;
+            }
+
+            let Some(value) = self.dyn_struct.field_at_mut(index) else {
+                return Err(self.struct_info.unknown_field(field.name()));
+            };
+            seq.next_element_seed(ReflectDeserializeInPlace::new_internal(
+                value,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ))?
+            .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A mutable reference to a tuple-like value.
+enum TupleLikeMut<'a> {
+    TupleStruct(&'a mut dyn TupleStruct),
+    Tuple(&'a mut dyn Tuple),
+    Array(&'a mut dyn Array),
+}
+
+impl TupleLikeMut<'_> {
+    #[inline]
+    fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match self {
+            TupleLikeMut::TupleStruct(value) => value.field_mut(index),
+            TupleLikeMut::Tuple(value) => value.field_mut(index),
+            TupleLikeMut::Array(value) => value.get_mut(index),
+        }
+    }
+}
+
+/// A [`Visitor`] for deserializing elements into an existing tuple-like value.
+struct TupleInPlaceVisitor<'a, P> {
+    tuple: TupleLikeMut<'a>,
+    /// Field infos, used to skip fields with [`SkipSerde`], empty for arrays.
+    fields: &'static [UnnamedField],
+    len: usize,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for TupleInPlaceVisitor<'_, P> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected tuple-like value")
+    }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let len = self.len;
+
+        for index in 0..len {
+            if self
+                .fields
+                .get(index)
+                .is_some_and(|field| field.has_attribute::<SkipSerde>())
+            {
+                continue;
+            }
+
+            let Some(value) = self.tuple.field_mut(index) else {
+                return Err(Error::custom(format!("no field at index `{index}`")));
+            };
+            seq.next_element_seed(ReflectDeserializeInPlace::new_internal(
+                value,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ))?
+            .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_newtype_struct<D>(mut self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self
+            .fields
+            .first()
+            .is_some_and(|field| field.has_attribute::<SkipSerde>())
+        {
+            return Ok(());
+        }
+
+        let Some(value) = self.tuple.field_mut(0) else {
+            return Err(Error::custom("Field at index 0 not found"));
+        };
+        ReflectDeserializeInPlace::new_internal(value, self.registry, self.processor)
+            .deserialize(deserializer)
+    }
+}
+
+/// A [`Visitor`] for deserializing elements into an existing [`List`].
+///
+/// Existing elements are overwritten in place, extra elements are deserialized
+/// and pushed, and remaining elements are removed.
+struct ListInPlaceVisitor<'a, P> {
+    dyn_list: &'a mut dyn List,
+    list_info: &'static ListInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for ListInPlaceVisitor<'_, P> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected list value")
+    }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut index = 0;
+
+        while let Some(value) = self.dyn_list.get_mut(index) {
+            let seed = ReflectDeserializeInPlace::new_internal(
+                value,
+                self.registry,
+                self.processor.as_deref_mut(),
+            );
+            if seq.next_element_seed(seed)?.is_none() {
+                while self.dyn_list.len() > index {
+                    self.dyn_list.pop();
+                }
+                return Ok(());
+            }
+            index += 1;
+        }
+
+        let item_ty = self.list_info.item_ty();
+        let Some(type_traits) = self.registry.get(item_ty.id()) else {
+            return Err(Error::custom(format!(
+                "no type_traits found for type `{item_ty:?}`"
+            )));
+        };
+
+        while let Some(value) = seq.next_element_seed(InternalDeserializer::new_internal(
+            type_traits,
+            self.registry,
+            self.processor.as_deref_mut(),
+            false,
+        ))? {
+            self.dyn_list.push(value);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{registry::TypeRegistry, serde::SkipSerde};
+
+    use super::ReflectDeserializeInPlace;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Stats {
+        hp: u32,
+        mp: u32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Config {
+        #[reflect(rename = "title", alias = "name")]
+        name: u32,
+        stats: Stats,
+        levels: Vec<u32>,
+        pair: (u32, u32),
+        slot: Option<u32>,
+        #[reflect(@SkipSerde::None)]
+        cache: u32,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Config>();
+        registry.register::<Stats>();
+        registry.register::<Vec<u32>>();
+        registry.register::<(u32, u32)>();
+        registry.register::<Option<u32>>();
+        registry
+    }
+
+    fn config() -> Config {
+        Config {
+            name: 1,
+            stats: Stats { hp: 10, mp: 20 },
+            levels: vec![1, 2, 3],
+            pair: (1, 2),
+            slot: None,
+            cache: 7,
+        }
+    }
+
+    #[test]
+    fn in_place() {
+        let registry = registry();
+        let mut value = config();
+
+        // missing fields keep their values, lists are resized, `SkipSerde` fields are untouched
+        let json = serde_json::json!({
+            "name": 2,
+            "stats": { "mp": 5 },
+            "levels": [4],
+            "pair": [3, 4],
+            "slot": 9,
+            "cache": 0,
+        });
+        ReflectDeserializeInPlace::new(&mut value, &registry).deserialize(&json).unwrap();
+
+        let expected = Config {
+            name: 2,
+            stats: Stats { hp: 10, mp: 5 },
+            levels: vec![4],
+            pair: (3, 4),
+            slot: Some(9),
+            cache: 7,
+        };
+        assert_eq!(value, expected);
+
+        let json = serde_json::json!({ "levels": [4, 5, 6] });
+        ReflectDeserializeInPlace::new(&mut value, &registry).deserialize(&json).unwrap();
+        assert_eq!(value.levels, vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn in_place_errors() {
+        let registry = registry();
+        let mut value = config();
+
+        let json = r#"{ "title": 2, "name": 3 }"#;
+        let mut de = serde_json::Deserializer::from_str(json);
+        let err = ReflectDeserializeInPlace::new(&mut value, &registry)
+            .deserialize(&mut de)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("duplicate field `title`"));
+
+        let json = serde_json::json!({ "stats": { "hp": "full" } });
+        let result = ReflectDeserializeInPlace::new(&mut value, &registry).deserialize(&json);
+        assert!(result.is_err());
+    }
+}
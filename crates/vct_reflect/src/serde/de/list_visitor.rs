@@ -9,13 +9,14 @@ use super::{DeserializerProcessor, InternalDeserializer};
 /// A [`Visitor`] for deserializing [`List`] values.
 ///
 /// [`List`]: crate::ops::List
-pub(super) struct ListVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct ListVisitor<'a, P> {
     pub list_info: &'static ListInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for ListVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for ListVisitor<'_, P> {
     type Value = DynamicList;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -39,6 +40,7 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for ListVisitor<'_, P> {
             type_traits,
             self.registry,
             self.processor.as_deref_mut(),
+            self.compact,
         ))? {
             list.push_box(value);
         }
@@ -13,13 +13,14 @@ use super::{DeserializerProcessor, InternalDeserializer};
 /// A [`Visitor`] for deserializing [`Map`] values.
 ///
 /// [`Map`]: crate::ops::Map
-pub(super) struct MapVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct MapVisitor<'a, P> {
     pub map_info: &'static MapInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for MapVisitor<'_, P> {
     type Value = DynamicMap;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -50,11 +51,13 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
             key_traits,
             self.registry,
             self.processor.as_deref_mut(),
+            self.compact,
         ))? {
             let value = map.next_value_seed(InternalDeserializer::new_internal(
                 value_traits,
                 self.registry,
                 self.processor.as_deref_mut(),
+                self.compact,
             ))?;
 
             dynamic_map.insert_boxed(key, value);
@@ -1,8 +1,14 @@
 mod deserializer;
+mod diff;
+mod in_place;
 mod processor;
+mod self_describing;
 
 pub use deserializer::*;
+pub use diff::ReflectDiffDeserializer;
+pub use in_place::*;
 pub use processor::*;
+pub use self_describing::SelfDescribingDeserializer;
 
 mod array_visitor;
 mod enum_visitor;
@@ -14,5 +20,8 @@ mod struct_visitor;
 mod tuple_struct_visitor;
 mod tuple_visitor;
 
+mod content;
+mod tagged_enum;
+
 mod struct_like_utils;
 mod tuple_like_utils;
@@ -11,13 +11,14 @@ use crate::{
 use super::{DeserializerProcessor, InternalDeserializer};
 
 /// A [`Visitor`] for deserializing [`Option`] values.
-pub(super) struct OptionVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct OptionVisitor<'a, P> {
     pub enum_info: &'static EnumInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for OptionVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for OptionVisitor<'_, P> {
     type Value = DynamicEnum;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -61,8 +62,12 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for OptionVisitor<'_, P> {
                     )));
                 };
 
-                let de =
-                    InternalDeserializer::new_internal(type_traits, self.registry, self.processor);
+                let de = InternalDeserializer::new_internal(
+                    type_traits,
+                    self.registry,
+                    self.processor,
+                    self.compact,
+                );
 
                 let mut value = DynamicTuple::with_capacity(1);
 
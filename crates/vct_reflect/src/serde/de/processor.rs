@@ -2,11 +2,14 @@ use alloc::boxed::Box;
 
 use crate::{
     Reflect,
-    registry::{TypeRegistry, TypeTraits},
+    registry::{TypeRegistry, TypeTraitDeserializeBorrowed, TypeTraits},
 };
 
-pub trait DeserializerProcessor {
-    fn try_deserialize<'de, D: serde::Deserializer<'de>>(
+/// Takes over the deserialization of some types, `'de` is the lifetime of the input.
+///
+/// Processors that don't borrow from the input should be implemented for all `'de`.
+pub trait DeserializerProcessor<'de> {
+    fn try_deserialize<D: serde::Deserializer<'de>>(
         &mut self,
         registration: &TypeTraits,
         registry: &TypeRegistry,
@@ -14,8 +17,8 @@ pub trait DeserializerProcessor {
     ) -> Result<Result<Box<dyn Reflect>, D::Error>, D>;
 }
 
-impl DeserializerProcessor for () {
-    fn try_deserialize<'de, D: serde::Deserializer<'de>>(
+impl<'de> DeserializerProcessor<'de> for () {
+    fn try_deserialize<D: serde::Deserializer<'de>>(
         &mut self,
         _registration: &TypeTraits,
         _registry: &TypeRegistry,
@@ -24,3 +27,105 @@ impl DeserializerProcessor for () {
         Err(deserializer)
     }
 }
+
+/// Wraps the user processor to deserialize types with [`TypeTraitDeserializeBorrowed`].
+///
+/// It only implements `DeserializerProcessor<'static>`, so it can only be used with a
+/// `Deserializer<'static>`, see the `deserialize_borrowed` methods.
+pub(super) struct BorrowedProcessor<'a, P> {
+    pub processor: Option<&'a mut P>,
+}
+
+impl<P> DeserializerProcessor<'static> for BorrowedProcessor<'_, P>
+where
+    P: DeserializerProcessor<'static>,
+{
+    fn try_deserialize<D: serde::Deserializer<'static>>(
+        &mut self,
+        registration: &TypeTraits,
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn Reflect>, D::Error>, D> {
+        let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
+            match processor.try_deserialize(registration, registry, deserializer) {
+                Ok(result) => return Ok(result),
+                Err(deserializer) => deserializer,
+            }
+        } else {
+            deserializer
+        };
+
+        let Some(deserialize_borrowed) = registration.get::<TypeTraitDeserializeBorrowed>() else {
+            return Err(deserializer);
+        };
+
+        Ok(deserialize_borrowed.deserialize(deserializer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::Cow, boxed::Box};
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect, Reflect,
+        registry::{TypeRegistry, TypeTraits},
+        serde::{DeserializerProcessor, InternalDeserializer},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    struct Label {
+        text: &'static str,
+        note: Cow<'static, str>,
+        size: u32,
+    }
+
+    /// Replaces every `u32` with `7`.
+    struct Sevens;
+
+    impl<'de> DeserializerProcessor<'de> for Sevens {
+        fn try_deserialize<D: serde::Deserializer<'de>>(
+            &mut self,
+            registration: &TypeTraits,
+            _registry: &TypeRegistry,
+            deserializer: D,
+        ) -> Result<Result<Box<dyn Reflect>, D::Error>, D> {
+            if registration.type_id() != core::any::TypeId::of::<u32>() {
+                return Err(deserializer);
+            }
+            let result = <serde::de::IgnoredAny as serde::Deserialize>::deserialize(deserializer);
+            Ok(result.map(|_| Box::new(7u32) as Box<dyn Reflect>))
+        }
+    }
+
+    static INPUT: &str = r#"{ "text": "hello", "note": "world", "size": 1 }"#;
+
+    #[test]
+    fn deserialize_borrowed() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Label>();
+        registry.register::<&'static str>();
+        registry.register::<Cow<'static, str>>();
+        registry.register::<u32>();
+
+        let mut de = serde_json::Deserializer::from_str(INPUT);
+        let value = InternalDeserializer::of::<Label>(&registry)
+            .deserialize_borrowed(&mut de)
+            .unwrap();
+        let label = Label::from_reflect(value.as_ref()).unwrap();
+        assert_eq!(label, Label { text: "hello", note: Cow::Borrowed("world"), size: 1 });
+        assert!(INPUT.as_bytes().as_ptr_range().contains(&label.text.as_ptr()));
+        assert!(matches!(label.note, Cow::Borrowed(_)));
+
+        // the user processor runs first
+        let mut processor = Sevens;
+        let mut de = serde_json::Deserializer::from_str(INPUT);
+        let type_traits = registry.get(core::any::TypeId::of::<Label>()).unwrap();
+        let value = InternalDeserializer::with_processor(type_traits, &registry, &mut processor)
+            .deserialize_borrowed(&mut de)
+            .unwrap();
+        assert_eq!(Label::from_reflect(value.as_ref()).unwrap().size, 7);
+    }
+}
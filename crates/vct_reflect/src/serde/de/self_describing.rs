@@ -0,0 +1,416 @@
+use alloc::{
+    boxed::Box,
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{fmt, fmt::Formatter};
+use serde::{
+    Deserializer,
+    de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor},
+};
+
+use crate::{
+    Reflect,
+    ops::{
+        DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+        DynamicTuple, DynamicTupleStruct, DynamicVariant, Map, Set,
+    },
+    registry::TypeRegistry,
+    serde::ser::{NODE_KINDS, NODE_NAME, VARIANT_KINDS, VARIANT_NAME},
+};
+
+use super::InternalDeserializer;
+
+/// A deserializer for the data written by [`SelfDescribingSerializer`].
+///
+/// Values are rebuilt as `Dynamic*` values without represented type info,
+/// only opaque types need to be registered.
+///
+/// [`SelfDescribingSerializer`]: crate::serde::SelfDescribingSerializer
+#[derive(Clone, Copy)]
+pub struct SelfDescribingDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> SelfDescribingDeserializer<'a> {
+    #[inline]
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for SelfDescribingDeserializer<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum(NODE_NAME, NODE_KINDS, NodeVisitor(self))
+    }
+}
+
+/// Deserializes a variant name or index of the given variants.
+pub(super) struct VariantIndex {
+    pub variants: &'static [&'static str],
+}
+
+impl<'de> DeserializeSeed<'de> for VariantIndex {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIndex {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("variant identifier")
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        match usize::try_from(v) {
+            Ok(index) if index < self.variants.len() => Ok(index),
+            _ => Err(Error::invalid_value(
+                serde::de::Unexpected::Unsigned(v),
+                &format!("variant index less than {}", self.variants.len()).as_str(),
+            )),
+        }
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.variants
+            .iter()
+            .position(|name| *name == v)
+            .ok_or_else(|| Error::unknown_variant(v, self.variants))
+    }
+}
+
+struct NodeVisitor<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> Visitor<'de> for NodeVisitor<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("self-describing reflected value")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let node = self.0;
+        let (kind, variant) = data.variant_seed(VariantIndex {
+            variants: NODE_KINDS,
+        })?;
+
+        Ok(match NODE_KINDS[kind] {
+            "Struct" => Box::new(variant.newtype_variant_seed(FieldsSeed(node))?),
+            "TupleStruct" => Box::new(DynamicTupleStruct::from(
+                variant.newtype_variant_seed(ItemsSeed(node))?.into_tuple(),
+            )),
+            "Tuple" => Box::new(variant.newtype_variant_seed(ItemsSeed(node))?.into_tuple()),
+            "List" => {
+                let mut dynamic_list = DynamicList::new();
+                for value in variant.newtype_variant_seed(ItemsSeed(node))?.0 {
+                    dynamic_list.push_box(value);
+                }
+                Box::new(dynamic_list)
+            }
+            "Array" => {
+                let items = variant.newtype_variant_seed(ItemsSeed(node))?;
+                Box::new(DynamicArray::new(items.0.into_boxed_slice()))
+            }
+            "Map" => Box::new(variant.newtype_variant_seed(EntriesSeed(node))?),
+            "Set" => {
+                let mut dynamic_set = DynamicSet::new();
+                for value in variant.newtype_variant_seed(ItemsSeed(node))?.0 {
+                    dynamic_set.insert_boxed(value);
+                }
+                Box::new(dynamic_set)
+            }
+            "Enum" => Box::new(variant.newtype_variant_seed(EnumSeed(node))?),
+            _ => variant.newtype_variant_seed(OpaqueSeed(node))?,
+        })
+    }
+}
+
+/// Deserializes a map of field names to values.
+struct FieldsSeed<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for FieldsSeed<'_> {
+    type Value = DynamicStruct;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldsSeed<'_> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map of field names to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut dynamic_struct = DynamicStruct::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(name) = map.next_key::<String>()? {
+            let value = map.next_value_seed(self.0)?;
+            dynamic_struct.insert_boxed(name, value);
+        }
+        Ok(dynamic_struct)
+    }
+}
+
+struct Items(Vec<Box<dyn Reflect>>);
+
+impl Items {
+    fn into_tuple(self) -> DynamicTuple {
+        let mut dynamic_tuple = DynamicTuple::with_capacity(self.0.len());
+        for value in self.0 {
+            dynamic_tuple.insert_boxed(value);
+        }
+        dynamic_tuple
+    }
+}
+
+/// Deserializes a sequence of values.
+struct ItemsSeed<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for ItemsSeed<'_> {
+    type Value = Items;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ItemsSeed<'_> {
+    type Value = Items;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of values")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(Items(values))
+    }
+}
+
+/// Deserializes a sequence of `(key, value)` tuples.
+struct EntriesSeed<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for EntriesSeed<'_> {
+    type Value = DynamicMap;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for EntriesSeed<'_> {
+    type Value = DynamicMap;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of key-value tuples")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut dynamic_map = DynamicMap::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some((key, value)) = seq.next_element_seed(PairSeed(self.0, self.0))? {
+            dynamic_map.insert_boxed(key, value);
+        }
+        Ok(dynamic_map)
+    }
+}
+
+/// Deserializes a 2-tuple with the given seeds.
+struct PairSeed<A, B>(A, B);
+
+impl<'de, A, B> DeserializeSeed<'de> for PairSeed<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A, B> Visitor<'de> for PairSeed<A, B>
+where
+    A: DeserializeSeed<'de>,
+    B: DeserializeSeed<'de>,
+{
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple of 2 elements")
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"tuple of 2 elements"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"tuple of 2 elements"))?;
+        Ok((first, second))
+    }
+}
+
+/// Deserializes a `(variant_name, variant_index, variant)` tuple.
+struct EnumSeed<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for EnumSeed<'_> {
+    type Value = DynamicEnum;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'de> Visitor<'de> for EnumSeed<'_> {
+    type Value = DynamicEnum;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple of variant name, variant index and variant")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let name = seq
+            .next_element::<String>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let index = seq
+            .next_element::<usize>()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let variant = seq
+            .next_element_seed(VariantSeed(self.0))?
+            .ok_or_else(|| Error::invalid_length(2, &"tuple of 3 elements"))?;
+        Ok(DynamicEnum::new_with_index(index, name, variant))
+    }
+}
+
+/// Deserializes a `Unit`, `Tuple(..)` or `Struct{..}` variant.
+struct VariantSeed<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for VariantSeed<'_> {
+    type Value = DynamicVariant;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum(VARIANT_NAME, VARIANT_KINDS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantSeed<'_> {
+    type Value = DynamicVariant;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("enum variant")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (kind, variant) = data.variant_seed(VariantIndex {
+            variants: VARIANT_KINDS,
+        })?;
+
+        match VARIANT_KINDS[kind] {
+            "Unit" => variant.unit_variant().map(|()| DynamicVariant::Unit),
+            "Tuple" => Ok(variant
+                .newtype_variant_seed(ItemsSeed(self.0))?
+                .into_tuple()
+                .into()),
+            _ => Ok(variant.newtype_variant_seed(FieldsSeed(self.0))?.into()),
+        }
+    }
+}
+
+/// Deserializes a `(type_path, value)` tuple of an opaque value.
+struct OpaqueSeed<'a>(SelfDescribingDeserializer<'a>);
+
+impl<'de> DeserializeSeed<'de> for OpaqueSeed<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for OpaqueSeed<'_> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple of type path and value")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let type_path = seq
+            .next_element::<String>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+
+        let registry = self.0.registry;
+        let Some(type_traits) = registry.get_with_type_path(&type_path) else {
+            return Err(Error::custom(format!(
+                "no type_traits found for type `{type_path}`"
+            )));
+        };
+
+        seq.next_element_seed(InternalDeserializer::new(type_traits, registry))?
+            .ok_or_else(|| Error::invalid_length(1, &"tuple of type path and value"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        ops::ReflectRef,
+        registry::TypeRegistry,
+        serde::{SelfDescribingDeserializer, SelfDescribingSerializer},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    enum Shape {
+        Empty,
+        Point(u32, u32),
+        Circle { radius: u32 },
+    }
+
+    #[test]
+    fn enum_variant_index() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<u32>();
+
+        let point = Shape::Point(1, 2);
+        let json = serde_json::to_value(SelfDescribingSerializer::new(&point, &registry)).unwrap();
+        let expected = serde_json::json!({
+            "Enum": ["Point", 1, { "Tuple": [{ "Opaque": ["u32", 1] }, { "Opaque": ["u32", 2] }] }]
+        });
+        assert_eq!(json, expected);
+
+        let value = SelfDescribingDeserializer::new(&registry).deserialize(&json).unwrap();
+        let ReflectRef::Enum(dyn_enum) = value.reflect_ref() else {
+            panic!("expected an enum");
+        };
+        assert_eq!(dyn_enum.variant_name(), "Point");
+        assert_eq!(dyn_enum.variant_index(), 1);
+        assert_eq!(Shape::from_reflect(value.as_ref()), Some(point));
+
+        let circle = Shape::Circle { radius: 3 };
+        let json = serde_json::to_value(SelfDescribingSerializer::new(&circle, &registry)).unwrap();
+        let value = SelfDescribingDeserializer::new(&registry).deserialize(&json).unwrap();
+        let ReflectRef::Enum(dyn_enum) = value.reflect_ref() else {
+            panic!("expected an enum");
+        };
+        assert_eq!(dyn_enum.variant_index(), 2);
+        assert_eq!(Shape::from_reflect(value.as_ref()), Some(circle));
+    }
+}
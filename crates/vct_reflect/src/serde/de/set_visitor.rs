@@ -13,13 +13,14 @@ use super::{DeserializerProcessor, InternalDeserializer};
 /// A [`Visitor`] for deserializing [`Set`] values.
 ///
 /// [`Set`]: crate::ops::Set
-pub(super) struct SetVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct SetVisitor<'a, P> {
     pub set_info: &'static SetInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for SetVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for SetVisitor<'_, P> {
     type Value = DynamicSet;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -43,6 +44,7 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for SetVisitor<'_, P> {
             type_traits,
             self.registry,
             self.processor.as_deref_mut(),
+            self.compact,
         ))? {
             dynamic_set.insert_boxed(value);
         }
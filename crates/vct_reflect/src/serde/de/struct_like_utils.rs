@@ -1,17 +1,18 @@
 use alloc::{
+    borrow::Cow,
     format,
     string::{String, ToString},
 };
 use core::{fmt, slice::Iter};
 use serde::{
     Deserialize,
-    de::{Error, MapAccess, SeqAccess, Visitor},
+    de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
 };
 
 use crate::{
     info::{NamedField, StructInfo, StructVariantInfo},
     ops::DynamicStruct,
-    registry::TypeRegistry,
+    registry::{TypeRegistry, TypeTraitMigrate},
     serde::SkipSerde,
 };
 
@@ -19,21 +20,36 @@ use super::{DeserializerProcessor, InternalDeserializer};
 
 /// A helper trait for accessing type information from struct-like types.
 pub(super) trait StructLikeInfo {
-    fn field<E: Error>(&self, name: &str) -> Result<&NamedField, E>;
+    fn unknown_field<E: Error>(&self, name: &str) -> E;
     fn field_at<E: Error>(&self, index: usize) -> Result<&NamedField, E>;
     fn field_len(&self) -> usize;
     fn iter_fields(&self) -> Iter<'_, NamedField>;
+
+    /// Returns `true` if unknown fields should be rejected instead of ignored.
+    fn deny_unknown_fields(&self) -> bool {
+        false
+    }
+
+    /// Returns the field by the serialized name or an alias.
+    fn serde_field(&self, name: &str) -> Option<&NamedField> {
+        self.iter_fields().find(|f| f.is_serde_name(name))
+    }
+
+    /// Returns the number of fields without [`SkipSerde`].
+    fn serialized_field_len(&self) -> usize {
+        self.iter_fields()
+            .filter(|f| !f.has_attribute::<SkipSerde>())
+            .count()
+    }
 }
 
 impl StructLikeInfo for StructInfo {
-    fn field<E: Error>(&self, name: &str) -> Result<&NamedField, E> {
-        Self::field(self, name).ok_or_else(|| {
-            Error::custom(format!(
-                "no field named `{}` on struct `{}`",
-                name,
-                self.type_path(),
-            ))
-        })
+    fn unknown_field<E: Error>(&self, name: &str) -> E {
+        Error::custom(format!(
+            "no field named `{}` on struct `{}`",
+            name,
+            self.type_path(),
+        ))
     }
 
     fn field_at<E: Error>(&self, index: usize) -> Result<&NamedField, E> {
@@ -55,17 +71,20 @@ impl StructLikeInfo for StructInfo {
     fn iter_fields(&self) -> Iter<'_, NamedField> {
         self.iter()
     }
+
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        Self::deny_unknown_fields(self)
+    }
 }
 
 impl StructLikeInfo for StructVariantInfo {
-    fn field<E: Error>(&self, name: &str) -> Result<&NamedField, E> {
-        Self::field(self, name).ok_or_else(|| {
-            Error::custom(format!(
-                "no field named `{}` on variant `{}`",
-                name,
-                self.name(),
-            ))
-        })
+    fn unknown_field<E: Error>(&self, name: &str) -> E {
+        Error::custom(format!(
+            "no field named `{}` on variant `{}`",
+            name,
+            self.name(),
+        ))
     }
 
     fn field_at<E: Error>(&self, index: usize) -> Result<&NamedField, E> {
@@ -87,10 +106,15 @@ impl StructLikeInfo for StructVariantInfo {
     fn iter_fields(&self) -> Iter<'_, NamedField> {
         self.iter()
     }
+
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        Self::deny_unknown_fields(self)
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Ident(pub String);
+pub(super) struct Ident(pub String);
 
 impl<'de> Deserialize<'de> for Ident {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -123,28 +147,48 @@ impl<'de> Deserialize<'de> for Ident {
 
 /// Deserializes a [struct-like] type from a mapping of fields, returning a [`DynamicStruct`].
 ///
+/// Fields are matched by the serialized name or an alias, a field given more than once is an
+/// error. Missing fields use the default value if provided, otherwise it's an error.
+///
+/// If `legacy` is provided (the data is outdated), unknown fields are deserialized with the
+/// declared legacy field types or ignored, and missing fields are left to the migration.
+///
 /// [struct-like]: StructLikeInfo
 pub(super) fn visit_struct<'de, T, V, P>(
     map: &mut V,
     info: &'static T,
     registry: &TypeRegistry,
     mut processor: Option<&mut P>,
+    compact: bool,
+    legacy: Option<&TypeTraitMigrate>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
     V: MapAccess<'de>,
-    P: DeserializerProcessor,
+    P: DeserializerProcessor<'de>,
 {
     let mut dynamic_struct = DynamicStruct::with_capacity(info.field_len());
 
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field_ty = info.field::<V::Error>(&key)?.ty();
+        let (name, field_id): (Cow<'static, str>, _) = if let Some(field) = info.serde_field(&key) {
+            if dynamic_struct.index_of(field.name()).is_some() {
+                return Err(Error::duplicate_field(field.serde_name()));
+            }
+            (Cow::Borrowed(field.name()), field.type_id())
+        } else if let Some(id) = legacy.and_then(|migrate| migrate.legacy_field(&key)) {
+            (Cow::Owned(key), id)
+        } else if legacy.is_none() && info.deny_unknown_fields() {
+            return Err(info.unknown_field(&key));
+        } else {
+            map.next_value::<IgnoredAny>()?;
+            continue;
+        };
 
         // cannot skip here, we need to call `next_value_seed`.
 
-        let Some(type_traits) = registry.get(field_ty.id()) else {
+        let Some(type_traits) = registry.get(field_id) else {
             return Err(Error::custom(format!(
-                "no type_traits found for type `{field_ty:?}`"
+                "no type_traits found for field `{name}`"
             )));
         };
 
@@ -152,15 +196,27 @@ where
             type_traits,
             registry,
             processor.as_deref_mut(),
+            compact,
         ))?;
-        dynamic_struct.insert_boxed(key, value);
+        dynamic_struct.insert_boxed(name, value);
     }
 
     for field in info.iter_fields() {
         if let Some(skip_serde) = field.get_attribute::<SkipSerde>() {
-             if let Some(val) = skip_serde.get(field.type_id(), registry)? {
+            if let Some(val) = skip_serde.get(field.type_id(), registry)? {
                 dynamic_struct.insert_boxed(field.name(), val);
             }
+            continue;
+        }
+
+        if dynamic_struct.index_of(field.name()).is_some() {
+            continue;
+        }
+
+        if let Some(val) = field.default_value() {
+            dynamic_struct.insert_boxed(field.name(), val);
+        } else if legacy.is_none() {
+            return Err(Error::missing_field(field.serde_name()));
         }
     }
 
@@ -169,17 +225,22 @@ where
 
 /// Deserializes a [struct-like] type from a sequence of fields, returning a [`DynamicStruct`].
 ///
+/// If `legacy` is `true` (the data is outdated), missing trailing fields are left to the
+/// migration and extra elements are ignored.
+///
 /// [struct-like]: StructLikeInfo
 pub(super) fn visit_struct_seq<'de, T, V, P>(
     seq: &mut V,
     info: &T,
     registry: &TypeRegistry,
     mut processor: Option<&mut P>,
+    compact: bool,
+    legacy: bool,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo,
     V: SeqAccess<'de>,
-    P: DeserializerProcessor,
+    P: DeserializerProcessor<'de>,
 {
 
     let len = info.field_len();
@@ -204,16 +265,130 @@ where
             )));
         };
 
-        let value = seq
-            .next_element_seed(InternalDeserializer::new_internal(
-                type_traits,
-                registry,
-                processor.as_deref_mut(),
-            ))?
-            .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
+        let value = seq.next_element_seed(InternalDeserializer::new_internal(
+            type_traits,
+            registry,
+            processor.as_deref_mut(),
+            compact,
+        ))?;
+        let Some(value) = value else {
+            if legacy {
+                break;
+            }
+            return Err(Error::invalid_length(index, &len.to_string().as_str()));
+        };
 
         dynamic_struct.insert_boxed(field.name(), value);
     }
 
+    if legacy {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+    }
+
     Ok(dynamic_struct)
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        registry::TypeRegistry,
+        serde::{InternalDeserializer, InternalSerializer},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    struct Item {
+        #[reflect(rename = "id", alias = "key")]
+        name: u32,
+        #[reflect(default)]
+        count: u32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq, deny_unknown_fields)]
+    struct Strict {
+        #[reflect(rename = "id")]
+        name: u32,
+    }
+
+    fn default_level() -> u32 {
+        5
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    struct Settings {
+        name: u32,
+        // The custom default also makes the field optional.
+        #[reflect(default = default_level)]
+        level: u32,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Item>();
+        registry.register::<Strict>();
+        registry.register::<Settings>();
+        registry
+    }
+
+    #[test]
+    fn serde_names() {
+        let registry = registry();
+        let item = Item { name: 3, count: 2 };
+        let json = serde_json::to_value(InternalSerializer::new(&item, &registry)).unwrap();
+        assert_eq!(json, serde_json::json!({ "id": 3, "count": 2 }));
+
+        let value = InternalDeserializer::of::<Item>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Item::from_reflect(value.as_ref()), Some(item));
+
+        // the alias is accepted and the missing field uses its default value
+        let json = serde_json::json!({ "key": 4 });
+        let value = InternalDeserializer::of::<Item>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Item::from_reflect(value.as_ref()), Some(Item { name: 4, count: 0 }));
+
+        // the rust name is not a serde name
+        let json = serde_json::json!({ "name": 4 });
+        let err = InternalDeserializer::of::<Item>(&registry).deserialize(&json).err().unwrap();
+        assert!(err.to_string().contains("missing field `id`"));
+    }
+
+    #[test]
+    fn custom_default() {
+        let registry = registry();
+        let json = serde_json::json!({ "name": 1 });
+        let value = InternalDeserializer::of::<Settings>(&registry).deserialize(&json).unwrap();
+        let level = value.reflect_ref().as_struct().unwrap().field("level").unwrap();
+        assert_eq!(level.downcast_ref::<u32>(), Some(&5));
+        assert_eq!(Settings::from_reflect(value.as_ref()), Some(Settings { name: 1, level: 5 }));
+
+        let json = serde_json::json!({ "level": 1 });
+        let err = InternalDeserializer::of::<Settings>(&registry).deserialize(&json).err().unwrap();
+        assert!(err.to_string().contains("missing field `name`"));
+    }
+
+    #[test]
+    fn duplicate_field() {
+        let registry = registry();
+        let json = r#"{ "id": 1, "key": 2 }"#;
+        let mut de = serde_json::Deserializer::from_str(json);
+        let err = InternalDeserializer::of::<Item>(&registry).deserialize(&mut de).err().unwrap();
+        assert!(err.to_string().contains("duplicate field `id`"));
+    }
+
+    #[test]
+    fn deny_unknown_fields() {
+        let registry = registry();
+        let json = serde_json::json!({ "id": 1, "extra": 2 });
+        let value = InternalDeserializer::of::<Item>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Item::from_reflect(value.as_ref()), Some(Item { name: 1, count: 0 }));
+
+        let err = InternalDeserializer::of::<Strict>(&registry).deserialize(&json).err().unwrap();
+        assert!(err.to_string().contains("no field named `extra`"));
+    }
+}
@@ -1,7 +1,11 @@
 use core::{fmt, fmt::Formatter};
 use serde::de::{MapAccess, SeqAccess, Visitor};
 
-use crate::{info::StructInfo, ops::DynamicStruct, registry::TypeRegistry};
+use crate::{
+    info::StructInfo,
+    ops::DynamicStruct,
+    registry::{TypeRegistry, TypeTraitMigrate},
+};
 
 use super::{
     DeserializerProcessor,
@@ -11,13 +15,16 @@ use super::{
 /// A [`Visitor`] for deserializing [`Struct`] values.
 ///
 /// [`Struct`]: crate::ops::Struct
-pub(super) struct StructVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct StructVisitor<'a, P> {
     pub struct_info: &'static StructInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
+    /// Set when the data is outdated, see [`visit_struct`].
+    pub legacy: Option<&'a TypeTraitMigrate>,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for StructVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for StructVisitor<'_, P> {
     type Value = DynamicStruct;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -28,13 +35,27 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for StructVisitor<'_, P> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registry, self.processor)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registry,
+            self.processor,
+            self.compact,
+            self.legacy.is_some(),
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
     where
         V: MapAccess<'de>,
     {
-        visit_struct(&mut map, self.struct_info, self.registry, self.processor)
+        visit_struct(
+            &mut map,
+            self.struct_info,
+            self.registry,
+            self.processor,
+            self.compact,
+            self.legacy,
+        )
     }
 }
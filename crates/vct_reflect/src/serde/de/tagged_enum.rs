@@ -0,0 +1,281 @@
+use alloc::{format, vec::Vec};
+use serde::{
+    Deserialize, Deserializer,
+    de::{DeserializeSeed, Error},
+};
+
+use crate::{
+    info::{EnumInfo, EnumTagging, TupleVariantInfo, VariantInfo},
+    ops::{DynamicEnum, DynamicTuple, DynamicVariant},
+    registry::{TypeRegistry, TypeTraitMigrate},
+};
+
+use super::{
+    DeserializerProcessor, InternalDeserializer,
+    content::{Content, ContentDeserializer},
+    enum_visitor::{StructVariantVisitor, TupleVariantVisitor, VariantDeserializer},
+};
+
+/// Deserializes an enum with [`EnumTagging`] other than `External`.
+///
+/// The data is buffered as [`Content`] first, so it requires a self-describing format.
+pub(super) fn deserialize_tagged_enum<'de, D, P>(
+    deserializer: D,
+    enum_info: &'static EnumInfo,
+    registry: &TypeRegistry,
+    mut processor: Option<&mut P>,
+    legacy: Option<&TypeTraitMigrate>,
+) -> Result<DynamicEnum, D::Error>
+where
+    D: Deserializer<'de>,
+    P: DeserializerProcessor<'de>,
+{
+    let content = Content::deserialize(deserializer)?;
+
+    let (variant_info, value) = match enum_info.tagging() {
+        EnumTagging::External => {
+            return Err(Error::custom("externally tagged enum is not handled here"));
+        }
+        EnumTagging::Internal { tag } => {
+            let mut entries = into_entries(content, "internally tagged enum")?;
+            let variant_info = take_variant(&mut entries, tag, enum_info)?;
+            let value = match variant_info {
+                VariantInfo::Unit(_) => DynamicVariant::Unit,
+                VariantInfo::Struct(struct_info) => ContentDeserializer::new(Content::Map(entries))
+                    .deserialize_map(StructVariantVisitor {
+                        struct_info,
+                        registry,
+                        processor,
+                        compact: false,
+                        legacy,
+                    })?
+                    .into(),
+                VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                    newtype_variant(tuple_info, Content::Map(entries), registry, processor)?
+                }
+                VariantInfo::Tuple(_) => {
+                    return Err(Error::custom(format!(
+                        "internally tagged variant `{}` cannot have multiple fields",
+                        enum_info.variant_path(variant_info.name()),
+                    )));
+                }
+            };
+            (variant_info, value)
+        }
+        EnumTagging::Adjacent { tag, content: content_key } => {
+            let mut entries = into_entries(content, "adjacently tagged enum")?;
+            let variant_info = take_variant(&mut entries, tag, enum_info)?;
+            let content = entries
+                .into_iter()
+                .find(|(key, _)| key.as_str() == Some(content_key))
+                .map_or(Content::Unit, |(_, value)| value);
+            let value = variant_value(variant_info, content, registry, processor, legacy)?;
+            (variant_info, value)
+        }
+        EnumTagging::Untagged => {
+            let matched = enum_info.iter().find_map(|variant_info| {
+                variant_value::<D::Error, P>(
+                    variant_info,
+                    content.clone(),
+                    registry,
+                    processor.as_deref_mut(),
+                    legacy,
+                )
+                .ok()
+                .map(|value| (variant_info, value))
+            });
+            matched.ok_or_else(|| {
+                Error::custom(format!(
+                    "data did not match any variant of untagged enum `{}`",
+                    enum_info.type_path(),
+                ))
+            })?
+        }
+    };
+
+    let variant_name = variant_info.name();
+    let variant_index = enum_info
+        .index_of(variant_name)
+        .expect("variant should exist");
+
+    Ok(DynamicEnum::new_with_index(variant_index, variant_name, value))
+}
+
+fn into_entries<E: Error>(content: Content, expected: &str) -> Result<Vec<(Content, Content)>, E> {
+    match content {
+        Content::Map(entries) => Ok(entries),
+        other => Err(Error::invalid_type(other.unexpected(), &expected)),
+    }
+}
+
+/// Removes the tag entry from the map, returning the variant it refers to.
+fn take_variant<E: Error>(
+    entries: &mut Vec<(Content, Content)>,
+    tag: &'static str,
+    enum_info: &'static EnumInfo,
+) -> Result<&'static VariantInfo, E> {
+    let Some(index) = entries.iter().position(|(key, _)| key.as_str() == Some(tag)) else {
+        return Err(Error::missing_field(tag));
+    };
+    let (_, variant) = entries.remove(index);
+    VariantDeserializer { enum_info }.deserialize(ContentDeserializer::new(variant))
+}
+
+/// Deserializes the content of the variant, the format is the same as [`EnumTagging::Untagged`].
+fn variant_value<'de, E: Error, P: DeserializerProcessor<'de>>(
+    variant_info: &'static VariantInfo,
+    content: Content,
+    registry: &TypeRegistry,
+    processor: Option<&mut P>,
+    legacy: Option<&TypeTraitMigrate>,
+) -> Result<DynamicVariant, E> {
+    match variant_info {
+        VariantInfo::Unit(_) => match content {
+            Content::Unit => Ok(DynamicVariant::Unit),
+            other => Err(Error::invalid_type(other.unexpected(), &"unit variant")),
+        },
+        VariantInfo::Struct(struct_info) => ContentDeserializer::new(content)
+            .deserialize_any(StructVariantVisitor {
+                struct_info,
+                registry,
+                processor,
+                compact: false,
+                legacy,
+            })
+            .map(DynamicVariant::from),
+        VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+            newtype_variant(tuple_info, content, registry, processor)
+        }
+        VariantInfo::Tuple(tuple_info) => ContentDeserializer::new(content)
+            .deserialize_tuple(
+                tuple_info.field_len(),
+                TupleVariantVisitor {
+                    tuple_info,
+                    registry,
+                    processor,
+                    compact: false,
+                    legacy,
+                },
+            )
+            .map(DynamicVariant::from),
+    }
+}
+
+fn newtype_variant<'de, E: Error, P: DeserializerProcessor<'de>>(
+    tuple_info: &'static TupleVariantInfo,
+    content: Content,
+    registry: &TypeRegistry,
+    processor: Option<&mut P>,
+) -> Result<DynamicVariant, E> {
+    let Some(field) = tuple_info.field_at(0) else {
+        return Err(Error::custom(format!(
+            "no field at index `0` on variant `{}`",
+            tuple_info.name(),
+        )));
+    };
+    let Some(type_traits) = registry.get(field.type_id()) else {
+        return Err(Error::custom(format!(
+            "no type_traits found for type `{:?}`",
+            field.ty()
+        )));
+    };
+
+    let value = InternalDeserializer::new_internal(type_traits, registry, processor, false)
+        .deserialize(ContentDeserializer::new(content))?;
+    let mut dynamic_tuple = DynamicTuple::with_capacity(1);
+    dynamic_tuple.insert_boxed(value);
+    Ok(dynamic_tuple.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        registry::TypeRegistry,
+        serde::{InternalDeserializer, InternalSerializer},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq, tag = "type")]
+    enum Internal {
+        Circle { radius: u32 },
+        Empty,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq, tag = "type", content = "data")]
+    enum Adjacent {
+        Circle { radius: u32 },
+        Point(u32, u32),
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq, untagged, deny_unknown_fields)]
+    enum Untagged {
+        Circle { radius: u32 },
+        Rect { w: u32, h: u32 },
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Internal>();
+        registry.register::<Adjacent>();
+        registry.register::<Untagged>();
+        registry.register::<u32>();
+        registry
+    }
+
+    #[test]
+    fn internally_tagged() {
+        let registry = registry();
+        let circle = Internal::Circle { radius: 2 };
+        let json = serde_json::to_value(InternalSerializer::new(&circle, &registry)).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "Circle", "radius": 2 }));
+
+        let value = InternalDeserializer::of::<Internal>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Internal::from_reflect(value.as_ref()), Some(circle));
+
+        let json = serde_json::json!({ "type": "Empty" });
+        let value = InternalDeserializer::of::<Internal>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Internal::from_reflect(value.as_ref()), Some(Internal::Empty));
+    }
+
+    #[test]
+    fn adjacently_tagged() {
+        let registry = registry();
+        let circle = Adjacent::Circle { radius: 2 };
+        let json = serde_json::to_value(InternalSerializer::new(&circle, &registry)).unwrap();
+        let expected = serde_json::json!({ "type": "Circle", "data": { "radius": 2 } });
+        assert_eq!(json, expected);
+
+        let value = InternalDeserializer::of::<Adjacent>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Adjacent::from_reflect(value.as_ref()), Some(circle));
+
+        let point = Adjacent::Point(1, 2);
+        let json = serde_json::to_value(InternalSerializer::new(&point, &registry)).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "Point", "data": [1, 2] }));
+
+        let value = InternalDeserializer::of::<Adjacent>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Adjacent::from_reflect(value.as_ref()), Some(point));
+    }
+
+    #[test]
+    fn untagged() {
+        let registry = registry();
+        let rect = Untagged::Rect { w: 1, h: 2 };
+        let json = serde_json::to_value(InternalSerializer::new(&rect, &registry)).unwrap();
+        assert_eq!(json, serde_json::json!({ "w": 1, "h": 2 }));
+
+        // `Circle` rejects the unknown fields, so the data falls through to `Rect`.
+        let value = InternalDeserializer::of::<Untagged>(&registry).deserialize(&json).unwrap();
+        assert_eq!(Untagged::from_reflect(value.as_ref()), Some(rect));
+
+        let json = serde_json::json!({ "radius": 2, "w": 1 });
+        let err = InternalDeserializer::of::<Untagged>(&registry).deserialize(&json).err().unwrap();
+        assert!(err.to_string().contains("did not match any variant"));
+    }
+}
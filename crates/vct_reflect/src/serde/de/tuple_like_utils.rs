@@ -1,5 +1,6 @@
 use alloc::{format, string::ToString};
-use serde::de::{Error, SeqAccess};
+use core::slice::Iter;
+use serde::de::{Error, IgnoredAny, SeqAccess};
 
 use crate::{
     info::{TupleInfo, TupleStructInfo, TupleVariantInfo, UnnamedField},
@@ -13,6 +14,14 @@ use super::{DeserializerProcessor, InternalDeserializer};
 pub(super) trait TupleLikeInfo {
     fn field_at<E: Error>(&self, index: usize) -> Result<&UnnamedField, E>;
     fn field_len(&self) -> usize;
+    fn iter_fields(&self) -> Iter<'_, UnnamedField>;
+
+    /// Returns the number of fields without [`SkipSerde`].
+    fn serialized_field_len(&self) -> usize {
+        self.iter_fields()
+            .filter(|f| !f.has_attribute::<SkipSerde>())
+            .count()
+    }
 }
 
 impl TupleLikeInfo for TupleInfo {
@@ -30,6 +39,11 @@ impl TupleLikeInfo for TupleInfo {
     fn field_len(&self) -> usize {
         Self::field_len(self)
     }
+
+    #[inline]
+    fn iter_fields(&self) -> Iter<'_, UnnamedField> {
+        self.iter()
+    }
 }
 
 impl TupleLikeInfo for TupleStructInfo {
@@ -47,6 +61,11 @@ impl TupleLikeInfo for TupleStructInfo {
     fn field_len(&self) -> usize {
         Self::field_len(self)
     }
+
+    #[inline]
+    fn iter_fields(&self) -> Iter<'_, UnnamedField> {
+        self.iter()
+    }
 }
 
 impl TupleLikeInfo for TupleVariantInfo {
@@ -64,21 +83,31 @@ impl TupleLikeInfo for TupleVariantInfo {
     fn field_len(&self) -> usize {
         Self::field_len(self)
     }
+
+    #[inline]
+    fn iter_fields(&self) -> Iter<'_, UnnamedField> {
+        self.iter()
+    }
 }
 
 /// Deserializes a [tuple-like] type from a sequence of elements, returning a [`DynamicTuple`].
 ///
+/// If `legacy` is `true` (the data is outdated), missing trailing elements are left to the
+/// migration and extra elements are ignored.
+///
 /// [tuple-like]: TupleLikeInfo
 pub(super) fn visit_tuple<'de, T, V, P>(
     seq: &mut V,
     info: &T,
     registry: &TypeRegistry,
     mut processor: Option<&mut P>,
+    compact: bool,
+    legacy: bool,
 ) -> Result<DynamicTuple, V::Error>
 where
     T: TupleLikeInfo,
     V: SeqAccess<'de>,
-    P: DeserializerProcessor,
+    P: DeserializerProcessor<'de>,
 {
     let len = info.field_len();
     let mut dynamic_tuple = DynamicTuple::with_capacity(len);
@@ -101,15 +130,24 @@ where
             continue;
         }
 
-        let value = seq
-            .next_element_seed(InternalDeserializer::new_internal(
-                type_traits,
-                registry,
-                processor.as_deref_mut(),
-            ))?
-            .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
+        let value = seq.next_element_seed(InternalDeserializer::new_internal(
+            type_traits,
+            registry,
+            processor.as_deref_mut(),
+            compact,
+        ))?;
+        let Some(value) = value else {
+            if legacy {
+                break;
+            }
+            return Err(Error::invalid_length(index, &len.to_string().as_str()));
+        };
         dynamic_tuple.insert_boxed(value);
     }
 
+    if legacy {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+    }
+
     Ok(dynamic_tuple)
 }
@@ -5,7 +5,7 @@ use serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
 use crate::{
     info::TupleStructInfo,
     ops::DynamicTupleStruct,
-    registry::TypeRegistry,
+    registry::{TypeRegistry, TypeTraitMigrate},
     serde::SkipSerde,
 };
 
@@ -14,13 +14,16 @@ use super::{DeserializerProcessor, InternalDeserializer, tuple_like_utils::visit
 /// A [`Visitor`] for deserializing [`TupleStruct`] values.
 ///
 /// [`TupleStruct`]: crate::TupleStruct
-pub(super) struct TupleStructVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct TupleStructVisitor<'a, P> {
     pub tuple_struct_info: &'static TupleStructInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
+    /// Set when the data is outdated, see [`visit_tuple`].
+    pub legacy: Option<&'a TypeTraitMigrate>,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleStructVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for TupleStructVisitor<'_, P> {
     type Value = DynamicTupleStruct;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -36,6 +39,8 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleStructVisitor<'_, P> {
             self.tuple_struct_info,
             self.registry,
             self.processor,
+            self.compact,
+            self.legacy.is_some(),
         )
         .map(DynamicTupleStruct::from)
     }
@@ -72,7 +77,12 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleStructVisitor<'_, P> {
             }
         }
 
-        let de = InternalDeserializer::new_internal(type_traits, self.registry, self.processor);
+        let de = InternalDeserializer::new_internal(
+            type_traits,
+            self.registry,
+            self.processor,
+            self.compact,
+        );
         let value = de.deserialize(deserializer)?;
 
         dynamic_tuple.insert_boxed(value);
@@ -8,13 +8,14 @@ use super::{DeserializerProcessor, tuple_like_utils::visit_tuple};
 /// A [`Visitor`] for deserializing [`Tuple`] values.
 ///
 /// [`Tuple`]: crate::Tuple
-pub(super) struct TupleVisitor<'a, P: DeserializerProcessor> {
+pub(super) struct TupleVisitor<'a, P> {
     pub tuple_info: &'static TupleInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub compact: bool,
 }
 
-impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleVisitor<'_, P> {
+impl<'de, P: DeserializerProcessor<'de>> Visitor<'de> for TupleVisitor<'_, P> {
     type Value = DynamicTuple;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
@@ -25,6 +26,6 @@ impl<'de, P: DeserializerProcessor> Visitor<'de> for TupleVisitor<'_, P> {
     where
         V: SeqAccess<'de>,
     {
-        visit_tuple(&mut seq, self.tuple_info, self.registry, self.processor)
+        visit_tuple(&mut seq, self.tuple_info, self.registry, self.processor, self.compact, false)
     }
 }
@@ -3,8 +3,16 @@ pub use skip_field::SkipSerde;
 
 mod de;
 mod ser;
+pub mod schema;
+pub mod text;
 
 pub use de::*;
 pub use ser::*;
 
 pub const NO_IDENT: &'static str = "_NoIdent";
+
+/// The key of the version entry written by [`ReflectSerializer`] for versioned types.
+pub const VERSION_KEY: &str = "version";
+
+/// The key of the value entry of nested versioned values, see [`InternalSerializer`].
+pub const VALUE_KEY: &str = "value";
@@ -0,0 +1,731 @@
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::{any::TypeId, fmt::Write, slice::Iter};
+use vct_utils::collections::{HashSet, TypeIdMap};
+
+use crate::{
+    Reflect,
+    info::{
+        CustomAttributes, EnumInfo, EnumTagging, NamedField, TypeInfo, UnnamedField, VariantInfo,
+    },
+    registry::TypeRegistry,
+    serde::{SkipSerde, VALUE_KEY, VERSION_KEY},
+};
+
+use super::{JsonMap, JsonValue};
+
+/// The dialect written to the `$schema` keyword of generated documents.
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+type AttributeHook = Box<dyn Fn(&dyn Reflect, &mut JsonMap)>;
+
+/// Generates JSON Schema (draft 2020-12) from [`TypeInfo`].
+///
+/// The schema describes the output of [`ReflectSerializer`] and [`InternalSerializer`]
+/// in the default (non-compact) mode:
+/// - Structs, tuple structs and enums are placed in `$defs` by type path and referenced
+///   with `$ref`, so recursive types are supported. Other types are inlined.
+/// - Field names are the serialized names, fields with [`SkipSerde`] are omitted and
+///   fields with a default value are not required.
+/// - Enums follow their [`EnumTagging`], `Option<T>` is `null` or `T`.
+/// - Opaque types are limited to primitives and strings, others accept any value unless
+///   a schema is provided by [`with_type_schema`](SchemaGenerator::with_type_schema).
+/// - Docs are written as `description` if the `reflect_docs` feature is enabled.
+///
+/// Custom attributes can add constraints with
+/// [`with_attribute_hook`](SchemaGenerator::with_attribute_hook).
+///
+/// # Example
+///
+/// ```ignore
+/// let generator = SchemaGenerator::new(&registry)
+///     .with_attribute_hook::<Range>(|range, schema| {
+///         schema.insert("minimum".into(), range.min.into());
+///         schema.insert("maximum".into(), range.max.into());
+///     });
+///
+/// let schema = generator.generate();
+/// let output = serde_json::to_string_pretty(&schema).unwrap();
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`InternalSerializer`]: crate::serde::InternalSerializer
+pub struct SchemaGenerator<'a> {
+    registry: &'a TypeRegistry,
+    type_schemas: TypeIdMap<JsonMap>,
+    attribute_hooks: TypeIdMap<AttributeHook>,
+}
+
+/// Definitions collected while generating a document.
+#[derive(Default)]
+struct Definitions {
+    defs: JsonMap,
+    queued: HashSet<TypeId>,
+    pending: Vec<&'static TypeInfo>,
+}
+
+impl<'a> SchemaGenerator<'a> {
+    /// Creates a generator with no custom schemas and hooks.
+    #[inline]
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            type_schemas: TypeIdMap::new(),
+            attribute_hooks: TypeIdMap::new(),
+        }
+    }
+
+    /// Uses the given schema for `T` instead of the generated one, it's always inlined.
+    ///
+    /// Mainly used for opaque types whose serialized form is unknown.
+    pub fn with_type_schema<T: 'static>(mut self, schema: JsonMap) -> Self {
+        self.type_schemas.insert(TypeId::of::<T>(), schema);
+        self
+    }
+
+    /// Adds a hook for the custom attribute `A`.
+    ///
+    /// The hook is called with the schema of each type, field and variant carrying the
+    /// attribute, after the schema is generated.
+    pub fn with_attribute_hook<A: Reflect>(
+        mut self,
+        hook: impl Fn(&A, &mut JsonMap) + 'static,
+    ) -> Self {
+        self.attribute_hooks.insert(
+            TypeId::of::<A>(),
+            Box::new(move |attribute, schema| {
+                if let Some(attribute) = attribute.downcast_ref::<A>() {
+                    hook(attribute, schema);
+                }
+            }),
+        );
+        self
+    }
+
+    /// Generates the schema of the [`ReflectSerializer`] output for all registered types.
+    ///
+    /// The root is a `oneOf` of `{ type_path: value }` objects, with the `version`
+    /// entry for versioned types.
+    ///
+    /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+    pub fn generate(&self) -> JsonValue {
+        let mut defs = Definitions::default();
+
+        let mut types: Vec<&'static TypeInfo> =
+            self.registry.iter().map(|traits| traits.type_info()).collect();
+        types.sort_unstable_by_key(|info| info.type_path());
+
+        let one_of = types
+            .into_iter()
+            .map(|info| {
+                let value = self.schema_of(info, &mut defs);
+                let mut schema = single_entry(info.type_path(), value);
+                if info.version() != 0 {
+                    let version = object([("const", info.version().into())]);
+                    insert_property(&mut schema, VERSION_KEY, version);
+                }
+                schema.into()
+            })
+            .collect::<Vec<_>>();
+
+        self.finish(object([("oneOf", one_of.into())]), defs)
+    }
+
+    /// Generates the schema of a single value of the type, without the type path,
+    /// i.e. the output of [`InternalSerializer`].
+    ///
+    /// [`InternalSerializer`]: crate::serde::InternalSerializer
+    pub fn generate_for(&self, info: &'static TypeInfo) -> JsonValue {
+        let mut defs = Definitions::default();
+        let root = self.schema_of(info, &mut defs);
+        self.finish(root, defs)
+    }
+
+    /// Resolves pending definitions and adds `$schema` and `$defs` to the root.
+    fn finish(&self, mut root: JsonMap, mut defs: Definitions) -> JsonValue {
+        while let Some(info) = defs.pending.pop() {
+            let schema = self.definition(info, &mut defs);
+            defs.defs.insert(info.type_path().into(), schema.into());
+        }
+
+        root.insert("$schema".into(), SCHEMA_DIALECT.into());
+        if !defs.defs.is_empty() {
+            root.insert("$defs".into(), defs.defs.into());
+        }
+        root.into()
+    }
+
+    /// Returns the schema of a nested value, versioned types are wrapped with their version,
+    /// see [`InternalSerializer`].
+    ///
+    /// [`InternalSerializer`]: crate::serde::InternalSerializer
+    fn nested_schema_of(&self, info: &'static TypeInfo, defs: &mut Definitions) -> JsonMap {
+        let schema = self.schema_of(info, defs);
+        if info.version() == 0 {
+            return schema;
+        }
+        let version = object([("const", info.version().into())]);
+        let properties = object([(VERSION_KEY, version.into()), (VALUE_KEY, schema.into())]);
+        object([
+            ("type", "object".into()),
+            ("properties", properties.into()),
+            ("required", vec![VERSION_KEY.into(), VALUE_KEY.into()].into()),
+            ("additionalProperties", false.into()),
+        ])
+    }
+
+    /// Returns the schema of a value, named types are referenced.
+    fn schema_of(&self, info: &'static TypeInfo, defs: &mut Definitions) -> JsonMap {
+        if let Some(schema) = self.type_schemas.get(&info.type_id()) {
+            return schema.clone();
+        }
+
+        let mut schema = match info {
+            TypeInfo::Enum(enum_info) if is_option(info) => {
+                let some = enum_info
+                    .variant("Some")
+                    .and_then(|variant| variant.as_tuple_variant().ok())
+                    .and_then(|variant| variant.field_at(0));
+                match some {
+                    Some(field) => {
+                        let null = object([("type", "null".into())]);
+                        let value = self.nested_schema_of(field.type_info(), defs);
+                        object([("anyOf", vec![null.into(), value.into()].into())])
+                    }
+                    None => JsonMap::new(),
+                }
+            }
+            TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) | TypeInfo::Enum(_) => {
+                if defs.queued.insert(info.type_id()) {
+                    defs.pending.push(info);
+                }
+                return object([("$ref", def_ref(info.type_path()).into())]);
+            }
+            TypeInfo::Tuple(tuple_info) => self.tuple_schema(tuple_info.iter(), defs),
+            TypeInfo::List(list_info) => object([
+                ("type", "array".into()),
+                ("items", self.nested_schema_of(list_info.item_info(), defs).into()),
+            ]),
+            TypeInfo::Array(array_info) => object([
+                ("type", "array".into()),
+                ("items", self.nested_schema_of(array_info.item_info(), defs).into()),
+                ("minItems", array_info.capacity().into()),
+                ("maxItems", array_info.capacity().into()),
+            ]),
+            TypeInfo::Set(set_info) => object([
+                ("type", "array".into()),
+                ("items", self.nested_schema_of(set_info.value_info(), defs).into()),
+                ("uniqueItems", true.into()),
+            ]),
+            TypeInfo::Map(map_info) => {
+                let value = self.nested_schema_of(map_info.value_info(), defs);
+                object([("type", "object".into()), ("additionalProperties", value.into())])
+            }
+            TypeInfo::Opaque(_) => opaque_schema(info),
+        };
+
+        self.apply_attributes(info.custom_attributes(), &mut schema);
+        schema
+    }
+
+    /// Returns the schema placed in `$defs` for a struct, tuple struct or enum.
+    fn definition(&self, info: &'static TypeInfo, defs: &mut Definitions) -> JsonMap {
+        let mut schema = match info {
+            TypeInfo::Struct(struct_info) => {
+                self.struct_schema(struct_info.iter(), struct_info.deny_unknown_fields(), defs)
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                self.tuple_schema(tuple_struct_info.iter(), defs)
+            }
+            TypeInfo::Enum(enum_info) => self.enum_schema(enum_info, defs),
+            _ => unreachable!("only structs and enums are placed in `$defs`"),
+        };
+
+        #[cfg(feature = "reflect_docs")]
+        describe(&mut schema, info.docs());
+        self.apply_attributes(info.custom_attributes(), &mut schema);
+        schema
+    }
+
+    /// Returns the schema of a map of named fields.
+    fn struct_schema(
+        &self,
+        fields: Iter<'static, NamedField>,
+        deny_unknown_fields: bool,
+        defs: &mut Definitions,
+    ) -> JsonMap {
+        let mut properties = JsonMap::new();
+        let mut required = Vec::new();
+
+        for field in fields {
+            if field.has_attribute::<SkipSerde>() {
+                continue;
+            }
+            let mut schema = self.nested_schema_of(field.type_info(), defs);
+            #[cfg(feature = "reflect_docs")]
+            describe(&mut schema, field.docs());
+            self.apply_attributes(field.custom_attributes(), &mut schema);
+
+            if field.default_value().is_none() {
+                required.push(field.serde_name().into());
+            }
+            properties.insert(field.serde_name().into(), schema.into());
+        }
+
+        let mut schema = object([("type", "object".into()), ("properties", properties.into())]);
+        if !required.is_empty() {
+            schema.insert("required".into(), required.into());
+        }
+        if deny_unknown_fields {
+            schema.insert("additionalProperties".into(), false.into());
+        }
+        schema
+    }
+
+    /// Returns the schema of a fixed-length array of unnamed fields.
+    fn tuple_schema(&self, fields: Iter<'static, UnnamedField>, defs: &mut Definitions) -> JsonMap {
+        let items = fields
+            .filter(|field| !field.has_attribute::<SkipSerde>())
+            .map(|field| self.unnamed_field_schema(field, defs).into())
+            .collect::<Vec<JsonValue>>();
+
+        let len = items.len();
+        let mut schema = object([("type", "array".into())]);
+        // `prefixItems` must not be empty.
+        if len > 0 {
+            schema.insert("prefixItems".into(), items.into());
+        }
+        schema.insert("minItems".into(), len.into());
+        schema.insert("maxItems".into(), len.into());
+        schema
+    }
+
+    fn unnamed_field_schema(&self, field: &UnnamedField, defs: &mut Definitions) -> JsonMap {
+        let mut schema = self.nested_schema_of(field.type_info(), defs);
+        #[cfg(feature = "reflect_docs")]
+        describe(&mut schema, field.docs());
+        self.apply_attributes(field.custom_attributes(), &mut schema);
+        schema
+    }
+
+    /// Returns the schema of an enum, one entry per variant.
+    ///
+    /// Variants of untagged enums may overlap, so `anyOf` is used instead of `oneOf`.
+    fn enum_schema(&self, info: &'static EnumInfo, defs: &mut Definitions) -> JsonMap {
+        let tagging = info.tagging();
+        let variants = info
+            .iter()
+            .filter_map(|variant| {
+                let mut schema = self.variant_schema(tagging, variant, defs)?;
+                #[cfg(feature = "reflect_docs")]
+                describe(&mut schema, variant.docs());
+                self.apply_attributes(variant.custom_attributes(), &mut schema);
+                Some(schema.into())
+            })
+            .collect::<Vec<JsonValue>>();
+
+        match tagging {
+            EnumTagging::Untagged => object([("anyOf", variants.into())]),
+            _ => object([("oneOf", variants.into())]),
+        }
+    }
+
+    /// Returns the schema of a variant with its tag, or `None` if the variant
+    /// cannot be serialized with the tagging.
+    fn variant_schema(
+        &self,
+        tagging: EnumTagging,
+        variant: &'static VariantInfo,
+        defs: &mut Definitions,
+    ) -> Option<JsonMap> {
+        let name = variant.name();
+        let is_unit = matches!(variant, VariantInfo::Unit(_));
+
+        let schema = match tagging {
+            EnumTagging::External if is_unit => object([("const", name.into())]),
+            EnumTagging::External => single_entry(name, self.variant_content(variant, defs)),
+            EnumTagging::Untagged => self.variant_content(variant, defs),
+            EnumTagging::Adjacent { tag, content } => {
+                let mut schema = object([("type", "object".into())]);
+                if !is_unit {
+                    insert_property(&mut schema, content, self.variant_content(variant, defs));
+                }
+                insert_property(&mut schema, tag, object([("const", name.into())]));
+                schema
+            }
+            EnumTagging::Internal { tag } => {
+                let mut schema = match variant {
+                    VariantInfo::Unit(_) => object([("type", "object".into())]),
+                    VariantInfo::Struct(struct_info) => self.struct_schema(
+                        struct_info.iter(),
+                        struct_info.deny_unknown_fields(),
+                        defs,
+                    ),
+                    VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                        // The fields of the inner struct are flattened.
+                        let TypeInfo::Struct(struct_info) = tuple_info.field_at(0)?.type_info()
+                        else {
+                            return None;
+                        };
+                        self.struct_schema(
+                            struct_info.iter(),
+                            struct_info.deny_unknown_fields(),
+                            defs,
+                        )
+                    }
+                    VariantInfo::Tuple(_) => return None,
+                };
+                insert_property(&mut schema, tag, object([("const", name.into())]));
+                schema
+            }
+        };
+        Some(schema)
+    }
+
+    /// Returns the schema of the variant content, without the variant name.
+    fn variant_content(&self, variant: &'static VariantInfo, defs: &mut Definitions) -> JsonMap {
+        match variant {
+            VariantInfo::Unit(_) => object([("type", "null".into())]),
+            VariantInfo::Struct(struct_info) => {
+                self.struct_schema(struct_info.iter(), struct_info.deny_unknown_fields(), defs)
+            }
+            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                // Newtype variants are written as the inner value.
+                match tuple_info.field_at(0) {
+                    Some(field) => self.unnamed_field_schema(field, defs),
+                    None => JsonMap::new(),
+                }
+            }
+            VariantInfo::Tuple(tuple_info) => self.tuple_schema(tuple_info.iter(), defs),
+        }
+    }
+
+    /// Calls the hooks of the attributes on the schema.
+    fn apply_attributes(&self, attributes: Option<&CustomAttributes>, schema: &mut JsonMap) {
+        let Some(attributes) = attributes else {
+            return;
+        };
+        for (id, attribute) in attributes.iter() {
+            if let Some(hook) = self.attribute_hooks.get(id) {
+                hook(attribute, schema);
+            }
+        }
+    }
+}
+
+/// Builds an object from entries.
+fn object<const N: usize>(entries: [(&str, JsonValue); N]) -> JsonMap {
+    entries
+        .into_iter()
+        .map(|(key, value)| (String::from(key), value))
+        .collect()
+}
+
+/// Adds a required property to an object schema.
+fn insert_property(schema: &mut JsonMap, name: &str, value: JsonMap) {
+    let properties = schema
+        .entry("properties".into())
+        .or_insert_with(|| JsonMap::new().into());
+    if let Some(properties) = properties.as_object_mut() {
+        properties.insert(name.into(), value.into());
+    }
+
+    let required = schema
+        .entry("required".into())
+        .or_insert_with(|| Vec::new().into());
+    if let JsonValue::Array(required) = required {
+        required.push(name.into());
+    }
+}
+
+/// Returns the schema of an object with exactly one entry.
+fn single_entry(name: &str, value: JsonMap) -> JsonMap {
+    let mut schema = object([("type", "object".into())]);
+    insert_property(&mut schema, name, value);
+    schema.insert("additionalProperties".into(), false.into());
+    schema
+}
+
+#[cfg(feature = "reflect_docs")]
+fn describe(schema: &mut JsonMap, docs: Option<&str>) {
+    if let Some(docs) = docs {
+        schema.insert("description".into(), docs.trim().into());
+    }
+}
+
+/// Returns `#/$defs/<type_path>`, escaped as a JSON pointer in a URI fragment.
+fn def_ref(type_path: &str) -> String {
+    let mut output = String::from("#/$defs/");
+    for byte in type_path.bytes() {
+        match byte {
+            b'~' => output.push_str("~0"),
+            b'/' => output.push_str("~1"),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => output.push(byte as char),
+            b'-' | b'.' | b'_' | b':' | b'@' | b'!' | b'$' | b'&' | b'\'' => {
+                output.push(byte as char);
+            }
+            b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' => output.push(byte as char),
+            _ => {
+                let _ = write!(output, "%{byte:02X}");
+            }
+        }
+    }
+    output
+}
+
+fn is_option(info: &TypeInfo) -> bool {
+    info.type_path_table().module_path() == Some("core::option")
+        && info.type_path_table().ident() == "Option"
+}
+
+/// Returns the schema of primitives and strings, or an empty schema (any value).
+fn opaque_schema(info: &TypeInfo) -> JsonMap {
+    macro_rules! bounded_integer {
+        ($($ty:ty),*) => {
+            $(
+                if info.type_is::<$ty>() {
+                    return object([
+                        ("type", "integer".into()),
+                        ("minimum", <$ty>::MIN.into()),
+                        ("maximum", <$ty>::MAX.into()),
+                    ]);
+                }
+            )*
+        };
+    }
+
+    bounded_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+    if info.type_is::<u128>() {
+        object([("type", "integer".into()), ("minimum", 0.into())])
+    } else if info.type_is::<i128>() {
+        object([("type", "integer".into())])
+    } else if info.type_is::<f32>() || info.type_is::<f64>() {
+        object([("type", "number".into())])
+    } else if info.type_is::<bool>() {
+        object([("type", "boolean".into())])
+    } else if info.type_is::<char>() {
+        object([
+            ("type", "string".into()),
+            ("minLength", 1.into()),
+            ("maxLength", 1.into()),
+        ])
+    } else if info.type_is::<String>()
+        || info.type_is::<&'static str>()
+        || info.type_is::<alloc::borrow::Cow<'static, str>>()
+    {
+        object([("type", "string".into())])
+    } else {
+        JsonMap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String, vec::Vec};
+    use serde_json::json;
+    use vct_reflect_derive::Reflect;
+
+    use super::{SCHEMA_DIALECT, SchemaGenerator};
+    use crate::{
+        info::{TypePath, Typed},
+        registry::TypeRegistry,
+        serde::{InternalSerializer, SkipSerde},
+    };
+
+    #[derive(Reflect)]
+    struct Range {
+        min: u8,
+        max: u8,
+    }
+
+    #[derive(Reflect)]
+    #[reflect(version = 2)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    #[derive(Reflect)]
+    #[reflect(deny_unknown_fields)]
+    struct Spawn {
+        #[reflect(rename = "pos")]
+        position: Point,
+        #[reflect(default)]
+        count: u8,
+        label: Option<String>,
+        tags: Vec<bool>,
+        #[reflect(@SkipSerde::None)]
+        cache: u8,
+        #[reflect(@Range { min: 1, max: 10 })]
+        level: u8,
+    }
+
+    #[derive(Reflect)]
+    enum Shape {
+        Empty,
+        Circle { r: u8 },
+        Line(u8, u8),
+    }
+
+    #[derive(Reflect)]
+    #[reflect(tag = "type")]
+    enum Event {
+        Quit,
+        Spawn { count: u8 },
+    }
+
+    fn def_ref<T: TypePath>() -> String {
+        format!("#/$defs/{}", T::type_path())
+    }
+
+    fn to_json(schema: super::JsonValue) -> serde_json::Value {
+        serde_json::to_value(schema).unwrap()
+    }
+
+    #[test]
+    fn struct_schema() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Spawn>();
+        registry.register::<Point>();
+        registry.register::<Option<String>>();
+        registry.register::<Vec<bool>>();
+        let generator = SchemaGenerator::new(&registry).with_attribute_hook::<Range>(
+            |range, schema| {
+                schema.insert("minimum".into(), range.min.into());
+                schema.insert("maximum".into(), range.max.into());
+            },
+        );
+        let u8_schema = json!({ "type": "integer", "minimum": 0, "maximum": 255 });
+
+        let schema = to_json(generator.generate_for(Spawn::type_info()));
+        let expected = json!({
+            "$schema": SCHEMA_DIALECT,
+            "$ref": def_ref::<Spawn>(),
+            "$defs": {
+                Spawn::type_path(): {
+                    "type": "object",
+                    "properties": {
+                        "pos": {
+                            "type": "object",
+                            "properties": {
+                                "version": { "const": 2 },
+                                "value": { "$ref": def_ref::<Point>() },
+                            },
+                            "required": ["version", "value"],
+                            "additionalProperties": false,
+                        },
+                        "count": u8_schema,
+                        "label": { "anyOf": [{ "type": "null" }, { "type": "string" }] },
+                        "tags": { "type": "array", "items": { "type": "boolean" } },
+                        "level": { "type": "integer", "minimum": 1, "maximum": 10 },
+                    },
+                    "required": ["pos", "label", "tags", "level"],
+                    "additionalProperties": false,
+                },
+                Point::type_path(): {
+                    "type": "object",
+                    "properties": { "x": u8_schema, "y": u8_schema },
+                    "required": ["x", "y"],
+                },
+            },
+        });
+        assert_eq!(schema, expected);
+
+        // The serialized fields match the properties.
+        let spawn = Spawn {
+            position: Point { x: 1, y: 2 },
+            count: 3,
+            label: None,
+            tags: Vec::new(),
+            cache: 4,
+            level: 5,
+        };
+        let value = serde_json::to_value(InternalSerializer::new(&spawn, &registry)).unwrap();
+        let properties = &expected["$defs"][Spawn::type_path()]["properties"];
+        let keys = |value: &serde_json::Value| {
+            value.as_object().unwrap().keys().cloned().collect::<Vec<_>>()
+        };
+        assert_eq!(keys(&value), keys(properties));
+        assert_eq!(value["pos"], json!({ "version": 2, "value": { "x": 1, "y": 2 } }));
+    }
+
+    #[test]
+    fn enum_schema() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Shape>();
+        registry.register::<Event>();
+        registry.register::<Point>();
+        let u8_schema = json!({ "type": "integer", "minimum": 0, "maximum": 255 });
+
+        let schema = to_json(SchemaGenerator::new(&registry).generate());
+        let entry = |type_path: &str, value: serde_json::Value| {
+            json!({
+                "type": "object",
+                "properties": { type_path: value },
+                "required": [type_path],
+                "additionalProperties": false,
+            })
+        };
+        // The registry also contains the primitive types.
+        let one_of = schema["oneOf"].as_array().unwrap();
+        assert!(one_of.contains(&entry(Shape::type_path(), json!({ "$ref": def_ref::<Shape>() }))));
+        assert!(one_of.contains(&entry(Event::type_path(), json!({ "$ref": def_ref::<Event>() }))));
+        assert!(one_of.contains(&entry("u8", u8_schema.clone())));
+
+        // Versioned types are written with their version.
+        let mut point = entry(Point::type_path(), json!({ "$ref": def_ref::<Point>() }));
+        point["properties"]["version"] = json!({ "const": 2 });
+        point["required"] = json!([Point::type_path(), "version"]);
+        assert!(one_of.contains(&point));
+
+        let expected = json!({
+            Shape::type_path(): {
+                "oneOf": [
+                    { "const": "Empty" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Circle": {
+                                "type": "object",
+                                "properties": { "r": u8_schema },
+                                "required": ["r"],
+                            },
+                        },
+                        "required": ["Circle"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Line": {
+                                "type": "array",
+                                "prefixItems": [u8_schema, u8_schema],
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                        },
+                        "required": ["Line"],
+                        "additionalProperties": false,
+                    },
+                ],
+            },
+            Event::type_path(): {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "type": { "const": "Quit" } },
+                        "required": ["type"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "count": u8_schema, "type": { "const": "Spawn" } },
+                        "required": ["count", "type"],
+                    },
+                ],
+            },
+        });
+        assert_eq!(schema["$schema"], SCHEMA_DIALECT);
+        assert_eq!(schema["$defs"][Shape::type_path()], expected[Shape::type_path()]);
+        assert_eq!(schema["$defs"][Event::type_path()], expected[Event::type_path()]);
+    }
+}
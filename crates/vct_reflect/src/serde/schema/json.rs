@@ -0,0 +1,141 @@
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
+use serde::{
+    Serialize, Serializer,
+    ser::{SerializeMap, SerializeSeq},
+};
+
+/// A JSON object, keys are kept in sorted order so the output is deterministic.
+pub type JsonMap = BTreeMap<String, JsonValue>;
+
+/// A JSON value, used to build schemas without depending on a JSON library.
+///
+/// It implements [`Serialize`], write it with any serde format (usually `serde_json`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    /// Integers within `i64` or `u64` are written as such, others as `i128`.
+    Integer(i128),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(JsonMap),
+}
+
+impl JsonValue {
+    /// Returns the object, if this value is an object.
+    #[inline]
+    pub fn as_object(&self) -> Option<&JsonMap> {
+        match self {
+            Self::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Returns the mutable object, if this value is an object.
+    #[inline]
+    pub fn as_object_mut(&mut self) -> Option<&mut JsonMap> {
+        match self {
+            Self::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for JsonValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(val) => serializer.serialize_bool(*val),
+            Self::Integer(val) => {
+                if let Ok(val) = i64::try_from(*val) {
+                    serializer.serialize_i64(val)
+                } else if let Ok(val) = u64::try_from(*val) {
+                    serializer.serialize_u64(val)
+                } else {
+                    serializer.serialize_i128(*val)
+                }
+            }
+            Self::Number(val) => serializer.serialize_f64(*val),
+            Self::String(val) => serializer.serialize_str(val),
+            Self::Array(values) => {
+                let mut state = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    state.serialize_element(value)?;
+                }
+                state.end()
+            }
+            Self::Object(map) => {
+                let mut state = serializer.serialize_map(Some(map.len()))?;
+                for (key, value) in map {
+                    state.serialize_entry(key, value)?;
+                }
+                state.end()
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for JsonValue {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<bool> for JsonValue {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for JsonValue {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for JsonValue {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::String(String::from(value))
+    }
+}
+
+impl From<String> for JsonValue {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Cow<'_, str>> for JsonValue {
+    #[inline]
+    fn from(value: Cow<'_, str>) -> Self {
+        Self::String(value.into_owned())
+    }
+}
+
+impl From<Vec<JsonValue>> for JsonValue {
+    #[inline]
+    fn from(value: Vec<JsonValue>) -> Self {
+        Self::Array(value)
+    }
+}
+
+impl From<JsonMap> for JsonValue {
+    #[inline]
+    fn from(value: JsonMap) -> Self {
+        Self::Object(value)
+    }
+}
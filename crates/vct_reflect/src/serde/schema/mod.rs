@@ -0,0 +1,22 @@
+//! JSON Schema export for registered types.
+//!
+//! [`SchemaGenerator`] walks the [`TypeInfo`] of registered types and emits a
+//! JSON Schema (draft 2020-12) document describing the output of [`ReflectSerializer`].
+//! Named types are placed in `$defs` and referenced with `$ref`, custom attributes can
+//! add constraints by hooks.
+//!
+//! The schema is built as a [`JsonValue`] tree, write it with any serde format:
+//!
+//! ```ignore
+//! let schema = SchemaGenerator::new(&registry).generate();
+//! std::fs::write("types.schema.json", serde_json::to_string_pretty(&schema)?)?;
+//! ```
+//!
+//! [`TypeInfo`]: crate::info::TypeInfo
+//! [`ReflectSerializer`]: crate::serde::ReflectSerializer
+
+mod generator;
+mod json;
+
+pub use generator::{SCHEMA_DIALECT, SchemaGenerator};
+pub use json::{JsonMap, JsonValue};
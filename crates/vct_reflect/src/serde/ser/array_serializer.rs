@@ -8,6 +8,7 @@ pub(super) struct ArraySerializer<'a, P: SerializerProcessor> {
     pub array: &'a dyn Array,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for ArraySerializer<'_, P> {
@@ -18,6 +19,7 @@ impl<P: SerializerProcessor> Serialize for ArraySerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.compact,
             ))?;
         }
         state.end()
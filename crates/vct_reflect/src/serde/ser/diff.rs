@@ -0,0 +1,271 @@
+use alloc::boxed::Box;
+use serde::{Serialize, Serializer, ser::SerializeSeq};
+
+use crate::{
+    Reflect,
+    diff::{FieldDiff, ListDiff, MapDiff, ReflectDiff, SetDiff},
+    registry::TypeRegistry,
+};
+
+use super::SelfDescribingSerializer;
+
+/// The enum names and variants written by [`ReflectDiffSerializer`].
+pub(crate) const DIFF_NAME: &str = "ReflectDiff";
+pub(crate) const DIFF_VARIANTS: &[&str] = &[
+    "Unchanged",
+    "Replaced",
+    "Fields",
+    "Elements",
+    "List",
+    "Map",
+    "Set",
+    "VariantSwitched",
+];
+pub(crate) const FIELD_DIFF_NAME: &str = "FieldDiff";
+pub(crate) const FIELD_DIFF_VARIANTS: &[&str] = &["Changed", "Added", "Removed"];
+pub(crate) const LIST_DIFF_NAME: &str = "ListDiff";
+pub(crate) const LIST_DIFF_VARIANTS: &[&str] = &["Changed", "Spliced"];
+pub(crate) const MAP_DIFF_NAME: &str = "MapDiff";
+pub(crate) const MAP_DIFF_VARIANTS: &[&str] = &["Changed", "Inserted", "Removed"];
+pub(crate) const SET_DIFF_NAME: &str = "SetDiff";
+pub(crate) const SET_DIFF_VARIANTS: &[&str] = &["Inserted", "Removed"];
+
+/// A serializer for [`ReflectDiff`].
+///
+/// The diff is written as nested enums, e.g. `ListDiff::Spliced` is a
+/// `(index, removed, inserted)` tuple. Recorded values are serialized by
+/// [`SelfDescribingSerializer`], as they are often dynamic values without represented types,
+/// so only their opaque types must be registered.
+///
+/// See [`ReflectDiffDeserializer`] for deserialization.
+///
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+pub struct ReflectDiffSerializer<'a> {
+    diff: &'a ReflectDiff,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    #[inline]
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl Serialize for ReflectDiffSerializer<'_> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.diff.serialize_with(self.registry, serializer)
+    }
+}
+
+/// Like [`Serialize`], with the registry used by recorded values.
+trait SerializeWith {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+struct With<'a, T: ?Sized>(&'a T, &'a TypeRegistry);
+
+impl<T: SerializeWith + ?Sized> Serialize for With<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_with(self.1, serializer)
+    }
+}
+
+impl<T: SerializeWith> SerializeWith for [T] {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(self.len()))?;
+        for item in self {
+            state.serialize_element(&With(item, registry))?;
+        }
+        state.end()
+    }
+}
+
+impl SerializeWith for Box<dyn Reflect> {
+    #[inline]
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        SelfDescribingSerializer::new(self.as_ref(), registry).serialize(serializer)
+    }
+}
+
+impl<K: Serialize, T: SerializeWith> SerializeWith for (K, T) {
+    #[inline]
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (&self.0, With(&self.1, registry)).serialize(serializer)
+    }
+}
+
+impl SerializeWith for ReflectDiff {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Unchanged => serializer.serialize_unit_variant(DIFF_NAME, 0, "Unchanged"),
+            Self::Replaced(value) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                1,
+                "Replaced",
+                &With(value, registry),
+            ),
+            Self::Fields(fields) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                2,
+                "Fields",
+                &With(fields.as_slice(), registry),
+            ),
+            Self::Elements(elements) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                3,
+                "Elements",
+                &With(elements.as_slice(), registry),
+            ),
+            Self::List(changes) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                4,
+                "List",
+                &With(changes.as_slice(), registry),
+            ),
+            Self::Map(changes) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                5,
+                "Map",
+                &With(changes.as_slice(), registry),
+            ),
+            Self::Set(changes) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                6,
+                "Set",
+                &With(changes.as_slice(), registry),
+            ),
+            Self::VariantSwitched(value) => serializer.serialize_newtype_variant(
+                DIFF_NAME,
+                7,
+                "VariantSwitched",
+                &With(value, registry),
+            ),
+        }
+    }
+}
+
+impl SerializeWith for FieldDiff {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Changed(diff) => serializer.serialize_newtype_variant(
+                FIELD_DIFF_NAME,
+                0,
+                "Changed",
+                &With(diff, registry),
+            ),
+            Self::Added(value) => serializer.serialize_newtype_variant(
+                FIELD_DIFF_NAME,
+                1,
+                "Added",
+                &With(value, registry),
+            ),
+            Self::Removed => serializer.serialize_unit_variant(FIELD_DIFF_NAME, 2, "Removed"),
+        }
+    }
+}
+
+impl SerializeWith for ListDiff {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Changed { index, diff } => serializer.serialize_newtype_variant(
+                LIST_DIFF_NAME,
+                0,
+                "Changed",
+                &(index, With(diff, registry)),
+            ),
+            Self::Spliced {
+                index,
+                removed,
+                inserted,
+            } => serializer.serialize_newtype_variant(
+                LIST_DIFF_NAME,
+                1,
+                "Spliced",
+                &(index, removed, With(inserted.as_slice(), registry)),
+            ),
+        }
+    }
+}
+
+impl SerializeWith for MapDiff {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Changed { key, diff } => serializer.serialize_newtype_variant(
+                MAP_DIFF_NAME,
+                0,
+                "Changed",
+                &(With(key, registry), With(diff, registry)),
+            ),
+            Self::Inserted { key, value } => serializer.serialize_newtype_variant(
+                MAP_DIFF_NAME,
+                1,
+                "Inserted",
+                &(With(key, registry), With(value, registry)),
+            ),
+            Self::Removed { key } => serializer.serialize_newtype_variant(
+                MAP_DIFF_NAME,
+                2,
+                "Removed",
+                &With(key, registry),
+            ),
+        }
+    }
+}
+
+impl SerializeWith for SetDiff {
+    fn serialize_with<S: Serializer>(
+        &self,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Inserted(value) => serializer.serialize_newtype_variant(
+                SET_DIFF_NAME,
+                0,
+                "Inserted",
+                &With(value, registry),
+            ),
+            Self::Removed(value) => serializer.serialize_newtype_variant(
+                SET_DIFF_NAME,
+                1,
+                "Removed",
+                &With(value, registry),
+            ),
+        }
+    }
+}
@@ -1,14 +1,20 @@
 use alloc::format;
+use core::slice::Iter;
 use serde::{
-    Serialize,
-    ser::{Error, SerializeStructVariant, SerializeTupleVariant},
+    Serialize, Serializer,
+    ser::{
+        Error, SerializeMap, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleVariant,
+    },
 };
 
 use super::{InternalSerializer, SerializerProcessor};
 use crate::{
-    info::{TypeInfo, VariantInfo, VariantKind},
-    ops::Enum,
+    Reflect,
+    info::{EnumInfo, EnumTagging, NamedField, TypeInfo, VariantInfo, VariantKind},
+    ops::{Enum, ReflectRef},
     registry::TypeRegistry,
+    serde::SkipSerde,
 };
 
 /// A serializer for [`Enum`] values.
@@ -16,10 +22,11 @@ pub(super) struct EnumSerializer<'a, P: SerializerProcessor> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
-    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let type_info = match self.enum_value.represented_type_info() {
             Some(info) => info,
             None => {
@@ -52,11 +59,28 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
         let variant_kind = self.enum_value.variant_kind();
         let field_len = self.enum_value.field_len();
 
+        let is_option = type_info.type_path_table().module_path() == Some("core::option")
+            && type_info.type_path_table().ident() == "Option";
+
+        if self.compact && !is_option {
+            let mut state = serializer.serialize_tuple(2)?;
+            state.serialize_element(&variant_index)?;
+            state.serialize_element(&CompactFieldsSerializer {
+                enum_value: self.enum_value,
+                variant_info,
+                registry: self.registry,
+                processor: self.processor,
+            })?;
+            return state.end();
+        }
+
+        if !is_option && enum_info.tagging() != EnumTagging::External {
+            return self.serialize_tagged(serializer, enum_info, variant_info);
+        }
+
         match variant_kind {
             VariantKind::Unit => {
-                if type_info.type_path_table().module_path() == Some("core::option")
-                    && type_info.type_path_table().ident() == "Option"
-                {
+                if is_option {
                     serializer.serialize_none()
                 } else {
                     serializer.serialize_unit_variant(enum_name, variant_index, variant_name)
@@ -82,8 +106,13 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
                     let name = field_info.name();
                     if let Some(value) = self.enum_value.field(name) {
                         state.serialize_field(
-                            name,
-                            &InternalSerializer::new_internal(value, self.registry, self.processor),
+                            field_info.serde_name(),
+                            &InternalSerializer::new_internal(
+                                value,
+                                self.registry,
+                                self.processor,
+                                self.compact,
+                            ),
                         )?;
                     } else {
                         return Err(Error::custom(format!(
@@ -98,20 +127,24 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
             VariantKind::Tuple if field_len == 1 => {
                 let field = self.enum_value.field_at(0).unwrap();
 
-                if type_info.type_path_table().module_path() == Some("core::option")
-                    && type_info.type_path_table().ident() == "Option"
-                {
+                if is_option {
                     serializer.serialize_some(&InternalSerializer::new_internal(
                         field,
                         self.registry,
                         self.processor,
+                        self.compact,
                     ))
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &InternalSerializer::new_internal(field, self.registry, self.processor),
+                        &InternalSerializer::new_internal(
+                            field,
+                            self.registry,
+                            self.processor,
+                            self.compact,
+                        ),
                     )
                 }
             }
@@ -138,6 +171,7 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
                             value,
                             self.registry,
                             self.processor,
+                            self.compact,
                         ))?;
                     } else {
                         return Err(Error::custom(format!(
@@ -152,3 +186,256 @@ impl<P: SerializerProcessor> Serialize for EnumSerializer<'_, P> {
         }
     }
 }
+
+impl<P: SerializerProcessor> EnumSerializer<'_, P> {
+    /// Serializes the value with [`EnumTagging`] other than `External`.
+    fn serialize_tagged<S: Serializer>(
+        &self,
+        serializer: S,
+        enum_info: &'static EnumInfo,
+        variant_info: &'static VariantInfo,
+    ) -> Result<S::Ok, S::Error> {
+        let variant_name = variant_info.name();
+        let content = VariantContentSerializer {
+            enum_value: self.enum_value,
+            variant_info,
+            registry: self.registry,
+            processor: self.processor,
+        };
+
+        match enum_info.tagging() {
+            EnumTagging::External => unreachable!("externally tagged enum is not handled here"),
+            EnumTagging::Untagged => content.serialize(serializer),
+            EnumTagging::Adjacent { tag, content: content_key } => {
+                let is_unit = matches!(variant_info, VariantInfo::Unit(_));
+                let mut state = serializer.serialize_struct(
+                    enum_info.type_path_table().ident(),
+                    if is_unit { 1 } else { 2 },
+                )?;
+                state.serialize_field(tag, variant_name)?;
+                if !is_unit {
+                    state.serialize_field(content_key, &content)?;
+                }
+                state.end()
+            }
+            EnumTagging::Internal { tag } => match variant_info {
+                VariantInfo::Unit(_) => {
+                    let mut state = serializer.serialize_map(Some(1))?;
+                    state.serialize_entry(tag, variant_name)?;
+                    state.end()
+                }
+                VariantInfo::Struct(struct_info) => serialize_tagged_fields(
+                    serializer,
+                    (tag, variant_name),
+                    struct_info.iter(),
+                    |name| self.enum_value.field(name),
+                    self.registry,
+                    self.processor,
+                ),
+                VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                    let Some(field) = self.enum_value.field_at(0) else {
+                        return Err(Error::custom(format!(
+                            "field `0` was missing while serializing type {}",
+                            enum_info.type_path()
+                        )));
+                    };
+                    let (ReflectRef::Struct(struct_value), Some(TypeInfo::Struct(struct_info))) =
+                        (field.reflect_ref(), field.represented_type_info())
+                    else {
+                        return Err(Error::custom(format!(
+                            "internally tagged variant `{}` must contain a struct, received `{}`",
+                            enum_info.variant_path(variant_name),
+                            field.reflect_type_path(),
+                        )));
+                    };
+                    serialize_tagged_fields(
+                        serializer,
+                        (tag, variant_name),
+                        struct_info.iter(),
+                        |name| struct_value.field(name),
+                        self.registry,
+                        self.processor,
+                    )
+                }
+                VariantInfo::Tuple(_) => Err(Error::custom(format!(
+                    "internally tagged variant `{}` cannot have multiple fields",
+                    enum_info.variant_path(variant_name),
+                ))),
+            },
+        }
+    }
+}
+
+/// Serializes the tag entry followed by the fields as a map, fields with [`SkipSerde`] are skipped.
+fn serialize_tagged_fields<'a, S, P>(
+    serializer: S,
+    (tag, variant_name): (&'static str, &'static str),
+    fields: Iter<'static, NamedField>,
+    get_field: impl Fn(&str) -> Option<&'a dyn Reflect>,
+    registry: &TypeRegistry,
+    processor: Option<&P>,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    P: SerializerProcessor,
+{
+    let len = fields.clone().filter(|f| !f.has_attribute::<SkipSerde>()).count();
+    let mut state = serializer.serialize_map(Some(len + 1))?;
+    state.serialize_entry(tag, variant_name)?;
+    for field_info in fields {
+        if field_info.has_attribute::<SkipSerde>() {
+            continue;
+        }
+        let name = field_info.name();
+        let Some(value) = get_field(name) else {
+            return Err(Error::custom(format!(
+                "field `{name}` was missing while serializing variant `{variant_name}`"
+            )));
+        };
+        state.serialize_entry(
+            field_info.serde_name(),
+            &InternalSerializer::new_internal(value, registry, processor, false),
+        )?;
+    }
+    state.end()
+}
+
+/// Serializes the content of the current variant without the variant name,
+/// used by adjacently tagged and untagged enums.
+struct VariantContentSerializer<'a, P: SerializerProcessor> {
+    enum_value: &'a dyn Enum,
+    variant_info: &'static VariantInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<P: SerializerProcessor> Serialize for VariantContentSerializer<'_, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let missing_field = |field: &dyn core::fmt::Display| {
+            Error::custom(format!(
+                "field `{field}` was missing while serializing type {}",
+                self.enum_value.reflect_type_path()
+            ))
+        };
+
+        match self.variant_info {
+            VariantInfo::Unit(_) => serializer.serialize_unit(),
+            VariantInfo::Struct(struct_info) => {
+                let len = struct_info
+                    .iter()
+                    .filter(|f| !f.has_attribute::<SkipSerde>())
+                    .count();
+                let mut state = serializer.serialize_map(Some(len))?;
+                for field_info in struct_info.iter() {
+                    if field_info.has_attribute::<SkipSerde>() {
+                        continue;
+                    }
+                    let name = field_info.name();
+                    let value = self.enum_value.field(name).ok_or_else(|| missing_field(&name))?;
+                    state.serialize_entry(
+                        field_info.serde_name(),
+                        &InternalSerializer::new_internal(
+                            value,
+                            self.registry,
+                            self.processor,
+                            false,
+                        ),
+                    )?;
+                }
+                state.end()
+            }
+            VariantInfo::Tuple(tuple_info) if tuple_info.field_len() == 1 => {
+                let value = self.enum_value.field_at(0).ok_or_else(|| missing_field(&0))?;
+                InternalSerializer::new_internal(value, self.registry, self.processor, false)
+                    .serialize(serializer)
+            }
+            VariantInfo::Tuple(tuple_info) => {
+                let mut state = serializer.serialize_tuple(tuple_info.field_len())?;
+                for field_info in tuple_info.iter() {
+                    let index = field_info.index();
+                    let value = self
+                        .enum_value
+                        .field_at(index)
+                        .ok_or_else(|| missing_field(&index))?;
+                    state.serialize_element(&InternalSerializer::new_internal(
+                        value,
+                        self.registry,
+                        self.processor,
+                        false,
+                    ))?;
+                }
+                state.end()
+            }
+        }
+    }
+}
+
+/// Serializes the fields of the current variant as a tuple, used by the compact format.
+///
+/// Fields with [`SkipSerde`] are skipped, consistent with the deserializer.
+struct CompactFieldsSerializer<'a, P: SerializerProcessor> {
+    enum_value: &'a dyn Enum,
+    variant_info: &'static VariantInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<P: SerializerProcessor> Serialize for CompactFieldsSerializer<'_, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let missing_field = |field: &dyn core::fmt::Display| {
+            Error::custom(format!(
+                "field `{field}` was missing while serializing type {}",
+                self.enum_value.reflect_type_path()
+            ))
+        };
+
+        match self.variant_info {
+            VariantInfo::Unit(_) => serializer.serialize_tuple(0)?.end(),
+            VariantInfo::Struct(struct_info) => {
+                let field_len = struct_info
+                    .iter()
+                    .map(|f| !f.has_attribute::<SkipSerde>() as usize)
+                    .sum::<usize>();
+                let mut state = serializer.serialize_tuple(field_len)?;
+                for field_info in struct_info.iter() {
+                    if field_info.has_attribute::<SkipSerde>() {
+                        continue;
+                    }
+                    let name = field_info.name();
+                    let value = self.enum_value.field(name).ok_or_else(|| missing_field(&name))?;
+                    state.serialize_element(&InternalSerializer::new_internal(
+                        value,
+                        self.registry,
+                        self.processor,
+                        true,
+                    ))?;
+                }
+                state.end()
+            }
+            VariantInfo::Tuple(tuple_info) => {
+                let field_len = tuple_info
+                    .iter()
+                    .map(|f| !f.has_attribute::<SkipSerde>() as usize)
+                    .sum::<usize>();
+                let mut state = serializer.serialize_tuple(field_len)?;
+                for field_info in tuple_info.iter() {
+                    if field_info.has_attribute::<SkipSerde>() {
+                        continue;
+                    }
+                    let index = field_info.index();
+                    let value = self
+                        .enum_value
+                        .field_at(index)
+                        .ok_or_else(|| missing_field(&index))?;
+                    state.serialize_element(&InternalSerializer::new_internal(
+                        value,
+                        self.registry,
+                        self.processor,
+                        true,
+                    ))?;
+                }
+                state.end()
+            }
+        }
+    }
+}
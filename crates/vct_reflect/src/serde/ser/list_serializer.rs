@@ -8,6 +8,7 @@ pub(super) struct ListSerializer<'a, P: SerializerProcessor> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for ListSerializer<'_, P> {
@@ -18,6 +19,7 @@ impl<P: SerializerProcessor> Serialize for ListSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.compact,
             ))?;
         }
         state.end()
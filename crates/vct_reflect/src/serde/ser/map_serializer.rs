@@ -8,6 +8,7 @@ pub(super) struct MapSerializer<'a, P: SerializerProcessor> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for MapSerializer<'_, P> {
@@ -18,8 +19,18 @@ impl<P: SerializerProcessor> Serialize for MapSerializer<'_, P> {
         let mut state = serializer.serialize_map(Some(self.map.len()))?;
         for (key, value) in self.map.iter() {
             state.serialize_entry(
-                &InternalSerializer::new_internal(key, self.registry, self.processor),
-                &InternalSerializer::new_internal(value, self.registry, self.processor),
+                &InternalSerializer::new_internal(
+                    key,
+                    self.registry,
+                    self.processor,
+                    self.compact,
+                ),
+                &InternalSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.compact,
+                ),
             )?;
         }
         state.end()
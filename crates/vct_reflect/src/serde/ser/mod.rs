@@ -1,7 +1,16 @@
+mod diff;
 mod processor;
+mod self_describing;
 mod serializer;
 
+pub use diff::ReflectDiffSerializer;
+pub(crate) use diff::{
+    DIFF_NAME, DIFF_VARIANTS, FIELD_DIFF_NAME, FIELD_DIFF_VARIANTS, LIST_DIFF_NAME,
+    LIST_DIFF_VARIANTS, MAP_DIFF_NAME, MAP_DIFF_VARIANTS, SET_DIFF_NAME, SET_DIFF_VARIANTS,
+};
 pub use processor::*;
+pub use self_describing::SelfDescribingSerializer;
+pub(crate) use self_describing::{NODE_KINDS, NODE_NAME, VARIANT_KINDS, VARIANT_NAME};
 pub use serializer::*;
 
 mod array_serializer;
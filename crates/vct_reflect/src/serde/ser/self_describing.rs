@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+use serde::{
+    Serialize, Serializer,
+    ser::{SerializeMap, SerializeSeq},
+};
+
+use crate::{Reflect, info::VariantKind, ops::ReflectRef, registry::TypeRegistry};
+
+use super::InternalSerializer;
+
+/// The enum name of the nodes written by [`SelfDescribingSerializer`].
+pub(crate) const NODE_NAME: &str = "ReflectNode";
+/// The variants (kinds) of the nodes written by [`SelfDescribingSerializer`].
+pub(crate) const NODE_KINDS: &[&str] = &[
+    "Struct",
+    "TupleStruct",
+    "Tuple",
+    "List",
+    "Array",
+    "Map",
+    "Set",
+    "Enum",
+    "Opaque",
+];
+/// The enum name of the enum variants written by [`SelfDescribingSerializer`].
+pub(crate) const VARIANT_NAME: &str = "ReflectVariant";
+/// The variants (kinds) of the enum variants written by [`SelfDescribingSerializer`].
+pub(crate) const VARIANT_KINDS: &[&str] = &["Unit", "Tuple", "Struct"];
+
+/// A serializer that describes the structure of the value, no type info is required.
+///
+/// Each value is written as an enum variant named by its [kind]:
+/// - `Struct`: a map of field names to values.
+/// - `TupleStruct`, `Tuple`, `List`, `Array` and `Set`: a sequence of values.
+/// - `Map`: a sequence of `(key, value)` tuples.
+/// - `Enum`: a `(variant_name, variant_index, variant)` tuple,
+///   the variant is `Unit`, `Tuple(..)` or `Struct{..}`.
+/// - `Opaque`: a `(type_path, value)` tuple, the value is serialized by [`InternalSerializer`].
+///
+/// So dynamic values without represented type info, such as patches of unknown types,
+/// can be serialized, and [`SelfDescribingDeserializer`] rebuilds them as `Dynamic*` values.
+/// Only opaque types need to be registered.
+///
+/// [kind]: crate::info::ReflectKind
+/// [`SelfDescribingDeserializer`]: crate::serde::SelfDescribingDeserializer
+pub struct SelfDescribingSerializer<'a> {
+    value: &'a dyn Reflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> SelfDescribingSerializer<'a> {
+    #[inline]
+    pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+
+    #[inline]
+    fn node(&self, value: &'a dyn Reflect) -> Self {
+        Self::new(value, self.registry)
+    }
+}
+
+impl Serialize for SelfDescribingSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(dyn_struct) => {
+                let fields = dyn_struct
+                    .iter_fields()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let name = dyn_struct.name_at(index).expect("field should exist");
+                        (name, self.node(value))
+                    })
+                    .collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 0, "Struct", &Fields(fields))
+            }
+            ReflectRef::TupleStruct(dyn_tuple_struct) => {
+                let items = dyn_tuple_struct.iter_fields().map(|v| self.node(v)).collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 1, "TupleStruct", &Items(items))
+            }
+            ReflectRef::Tuple(dyn_tuple) => {
+                let items = dyn_tuple.iter_fields().map(|v| self.node(v)).collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 2, "Tuple", &Items(items))
+            }
+            ReflectRef::List(dyn_list) => {
+                let items = dyn_list.iter().map(|v| self.node(v)).collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 3, "List", &Items(items))
+            }
+            ReflectRef::Array(dyn_array) => {
+                let items = dyn_array.iter().map(|v| self.node(v)).collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 4, "Array", &Items(items))
+            }
+            ReflectRef::Map(dyn_map) => {
+                let entries = dyn_map
+                    .iter()
+                    .map(|(key, value)| (self.node(key), self.node(value)))
+                    .collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 5, "Map", &Entries(entries))
+            }
+            ReflectRef::Set(dyn_set) => {
+                let items = dyn_set.iter().map(|v| self.node(v)).collect();
+                serializer.serialize_newtype_variant(NODE_NAME, 6, "Set", &Items(items))
+            }
+            ReflectRef::Enum(dyn_enum) => {
+                let variant = match dyn_enum.variant_kind() {
+                    VariantKind::Unit => Variant::Unit,
+                    VariantKind::Tuple => Variant::Tuple(Items(
+                        dyn_enum
+                            .iter_fields()
+                            .map(|field| self.node(field.value()))
+                            .collect(),
+                    )),
+                    VariantKind::Struct => Variant::Struct(Fields(
+                        dyn_enum
+                            .iter_fields()
+                            .map(|field| {
+                                let name = field.name().expect("field should exist");
+                                (name, self.node(field.value()))
+                            })
+                            .collect(),
+                    )),
+                };
+                serializer.serialize_newtype_variant(
+                    NODE_NAME,
+                    7,
+                    "Enum",
+                    &(dyn_enum.variant_name(), dyn_enum.variant_index(), variant),
+                )
+            }
+            ReflectRef::Opaque(value) => serializer.serialize_newtype_variant(
+                NODE_NAME,
+                8,
+                "Opaque",
+                &(value.reflect_type_path(), InternalSerializer::new(value, self.registry)),
+            ),
+        }
+    }
+}
+
+struct Fields<'a>(Vec<(&'a str, SelfDescribingSerializer<'a>)>);
+
+impl Serialize for Fields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in &self.0 {
+            state.serialize_entry(name, value)?;
+        }
+        state.end()
+    }
+}
+
+struct Items<'a>(Vec<SelfDescribingSerializer<'a>>);
+
+impl Serialize for Items<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in &self.0 {
+            state.serialize_element(value)?;
+        }
+        state.end()
+    }
+}
+
+struct Entries<'a>(Vec<(SelfDescribingSerializer<'a>, SelfDescribingSerializer<'a>)>);
+
+impl Serialize for Entries<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for entry in &self.0 {
+            state.serialize_element(entry)?;
+        }
+        state.end()
+    }
+}
+
+enum Variant<'a> {
+    Unit,
+    Tuple(Items<'a>),
+    Struct(Fields<'a>),
+}
+
+impl Serialize for Variant<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Variant::Unit => serializer.serialize_unit_variant(VARIANT_NAME, 0, "Unit"),
+            Variant::Tuple(items) => {
+                serializer.serialize_newtype_variant(VARIANT_NAME, 1, "Tuple", items)
+            }
+            Variant::Struct(fields) => {
+                serializer.serialize_newtype_variant(VARIANT_NAME, 2, "Struct", fields)
+            }
+        }
+    }
+}
@@ -1,24 +1,35 @@
 use alloc::format;
-use serde::{Serialize, Serializer, ser::SerializeMap};
+use serde::{
+    Serialize, Serializer,
+    ser::{SerializeMap, SerializeTuple},
+};
 
 use crate::{
     Reflect,
+    info::TypeInfo,
     ops::ReflectRef,
-    registry::{TypeRegistry, TypeTraitSerialize},
+    registry::{TypeRegistry, TypeTraitSerialize, type_path_hash},
 };
 
 use super::{
-    SerializerProcessor, array_serializer::ArraySerializer, enum_serializer::EnumSerializer,
-    list_serializer::ListSerializer, map_serializer::MapSerializer, set_serializer::SetSerializer,
+    super::{VALUE_KEY, VERSION_KEY}, SerializerProcessor, array_serializer::ArraySerializer,
+    enum_serializer::EnumSerializer, list_serializer::ListSerializer,
+    map_serializer::MapSerializer, set_serializer::SetSerializer,
     struct_serializer::StructSerializer, tuple_serializer::TupleSerializer,
     tuple_struct_serializer::TupleStructSerializer,
 };
 
 /// A serializer without type path attached
+///
+/// The version of the value itself is not written, but nested values of versioned types
+/// are written as `{ "version": version, "value": value }` (`(version, value)` in the
+/// compact format), so that they can be migrated separately.
 pub struct InternalSerializer<'a, P: SerializerProcessor = ()> {
     value: &'a dyn Reflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    compact: bool,
+    nested: bool,
 }
 
 impl<'a> InternalSerializer<'a, ()> {
@@ -32,6 +43,8 @@ impl<'a> InternalSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            compact: false,
+            nested: false,
         }
     }
 }
@@ -48,25 +61,62 @@ impl<'a, P: SerializerProcessor> InternalSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            compact: false,
+            nested: false,
         }
     }
 
+    /// An internal constructor for serializing nested values.
     #[inline]
     pub(super) fn new_internal(
         value: &'a dyn Reflect,
         registry: &'a TypeRegistry,
         processor: Option<&'a P>,
+        compact: bool,
     ) -> Self {
         Self {
             value,
             registry,
             processor,
+            compact,
+            nested: true,
         }
     }
 }
 
+impl<P: SerializerProcessor> InternalSerializer<'_, P> {
+    /// Sets whether to use the compact format, see [`ReflectSerializer::with_compact`].
+    #[inline]
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+}
+
 impl<'a, P: SerializerProcessor> Serialize for InternalSerializer<'a, P> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let version = self.value.represented_type_info().map_or(0, TypeInfo::version);
+        if self.nested && version != 0 {
+            let value = InternalSerializer {
+                value: self.value,
+                registry: self.registry,
+                processor: self.processor,
+                compact: self.compact,
+                nested: false,
+            };
+            return if self.compact {
+                let mut state = serializer.serialize_tuple(2)?;
+                state.serialize_element(&version)?;
+                state.serialize_element(&value)?;
+                state.end()
+            } else {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry(VERSION_KEY, &version)?;
+                state.serialize_entry(VALUE_KEY, &value)?;
+                state.end()
+            };
+        }
+
         let serializer = if let Some(processor) = self.processor {
             match processor.try_serialize(self.value, self.registry, serializer) {
                 Ok(result) => return result,
@@ -76,58 +126,68 @@ impl<'a, P: SerializerProcessor> Serialize for InternalSerializer<'a, P> {
             serializer
         };
 
-        // Try to get the Serializ impl of the type itself
-        if let Some(p) = self.registry.get_type_trait::<TypeTraitSerialize>(self.value.type_id()) {
-            return p.serialize(self.value, serializer);
+        // Try to get the Serializ impl of the type itself,
+        // `as_reflect` unwraps pass-through wrappers such as `Box<T>`.
+        let value = self.value.as_reflect();
+        if let Some(p) = self.registry.get_type_trait::<TypeTraitSerialize>(value.type_id()) {
+            return p.serialize(value, serializer);
         }
 
-        match self.value.reflect_ref() {
+        match value.reflect_ref() {
             ReflectRef::Struct(struct_value) => StructSerializer {
                 struct_value,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(tuple_struct) => TupleStructSerializer {
                 tuple_struct,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Tuple(tuple) => TupleSerializer {
                 tuple,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::List(list) => ListSerializer {
                 list,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Array(array) => ArraySerializer {
                 array,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Map(map) => MapSerializer {
                 map,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Set(set) => SetSerializer {
                 set,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Enum(enum_value) => EnumSerializer {
                 enum_value,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Opaque(_) => Err(serde::ser::Error::custom(
@@ -137,11 +197,17 @@ impl<'a, P: SerializerProcessor> Serialize for InternalSerializer<'a, P> {
     }
 }
 
-/// A serializer with type path attached
+/// A serializer with type path attached.
+///
+/// The output is `{ type_path: value }`, if the [version] of the type is not `0`,
+/// it's `{ "version": version, type_path: value }`.
+///
+/// [version]: crate::info::TypeInfo::version
 pub struct ReflectSerializer<'a, P: SerializerProcessor = ()> {
     value: &'a dyn Reflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    compact: bool,
 }
 
 impl<'a> ReflectSerializer<'a, ()> {
@@ -155,6 +221,7 @@ impl<'a> ReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            compact: false,
         }
     }
 }
@@ -174,32 +241,123 @@ impl<'a, P: SerializerProcessor> ReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            compact: false,
         }
     }
 }
 
+impl<P: SerializerProcessor> ReflectSerializer<'_, P> {
+    /// Sets whether to use the compact format, the default is `false`.
+    ///
+    /// The compact format is designed for binary formats such as `bincode` and `postcard`:
+    /// - The type path is replaced by the [type path hash], the output is `(hash, version, value)`.
+    /// - Structs are serialized as tuples, without field names.
+    /// - Enums are serialized as `(variant_index, (fields..))`, without variant names.
+    ///   `Option` is still serialized by `serialize_none` and `serialize_some`.
+    ///
+    /// The data can only be deserialized by [`ReflectDeserializer`] in compact mode.
+    ///
+    /// [type path hash]: crate::registry::type_path_hash
+    /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+    #[inline]
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+}
+
 impl<P: SerializerProcessor> Serialize for ReflectSerializer<'_, P> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut state = serializer.serialize_map(Some(1))?;
-        state.serialize_entry(
-            self.value
-                .represented_type_info()
-                .ok_or_else(|| {
-                    if self.value.is_dynamic() {
-                        serde::ser::Error::custom(format!(
-                            "cannot get represented type from dynamic type: `{}`.",
-                            self.value.reflect_type_path(),
-                        ))
-                    } else {
-                        serde::ser::Error::custom(format!(
-                            "cannot get type info for `{}`.",
-                            self.value.reflect_type_path(),
-                        ))
-                    }
-                })?
-                .type_path(),
-            &InternalSerializer::new_internal(self.value, self.registry, self.processor),
-        )?;
-        state.end()
+        let type_info = self.value.represented_type_info().ok_or_else(|| {
+            if self.value.is_dynamic() {
+                serde::ser::Error::custom(format!(
+                    "cannot get represented type from dynamic type: `{}`.",
+                    self.value.reflect_type_path(),
+                ))
+            } else {
+                serde::ser::Error::custom(format!(
+                    "cannot get type info for `{}`.",
+                    self.value.reflect_type_path(),
+                ))
+            }
+        })?;
+        let type_path = type_info.type_path();
+        let version = type_info.version();
+
+        let value = InternalSerializer {
+            value: self.value,
+            registry: self.registry,
+            processor: self.processor,
+            compact: self.compact,
+            nested: false,
+        };
+
+        if self.compact {
+            let mut state = serializer.serialize_tuple(3)?;
+            state.serialize_element(&type_path_hash(type_path))?;
+            state.serialize_element(&version)?;
+            state.serialize_element(&value)?;
+            state.end()
+        } else if version == 0 {
+            let mut state = serializer.serialize_map(Some(1))?;
+            state.serialize_entry(type_path, &value)?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_map(Some(2))?;
+            state.serialize_entry(VERSION_KEY, &version)?;
+            state.serialize_entry(type_path, &value)?;
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use serde::de::DeserializeSeed;
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        info::TypePath,
+        registry::{TypeRegistry, type_path_hash},
+        serde::{ReflectDeserializer, ReflectSerializer},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    struct Player {
+        name: String,
+        health: Option<u32>,
+        result: Result<u8, String>,
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<Option<u32>>();
+        registry.register::<Result<u8, String>>();
+
+        let player = Player {
+            name: "vct".into(),
+            health: Some(10),
+            result: Err("lost".into()),
+        };
+        let serializer = ReflectSerializer::new(&player, &registry).with_compact(true);
+        let json = serde_json::to_value(&serializer).unwrap();
+        let hash = type_path_hash(Player::type_path());
+        let expected = serde_json::json!([hash, 0, ["vct", 10, [1, ["lost"]]]]);
+        assert_eq!(json, expected);
+
+        let deserializer = ReflectDeserializer::new(&registry).with_compact(true);
+        let value = deserializer.deserialize(&json).unwrap();
+        assert_eq!(Player::from_reflect(value.as_ref()), Some(player));
+
+        // The normal deserializer can not read the compact format.
+        assert!(ReflectDeserializer::new(&registry).deserialize(&json).is_err());
+        let unknown = serde_json::json!([0, 0, json]);
+        let deserializer = ReflectDeserializer::new(&registry).with_compact(true);
+        assert!(deserializer.deserialize(&unknown).is_err());
     }
 }
@@ -8,6 +8,7 @@ pub(super) struct SetSerializer<'a, P: SerializerProcessor> {
     pub set: &'a dyn Set,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for SetSerializer<'_, P> {
@@ -18,6 +19,7 @@ impl<P: SerializerProcessor> Serialize for SetSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.compact,
             ))?;
         }
         state.end()
@@ -1,7 +1,7 @@
 use alloc::format;
 use serde::{
     Serialize,
-    ser::{Error, SerializeStruct},
+    ser::{Error, SerializeStruct, SerializeTuple},
 };
 
 use crate::{info::TypeInfo, ops::Struct, registry::TypeRegistry, serde::SkipSerde};
@@ -13,6 +13,7 @@ pub(super) struct StructSerializer<'a, P: SerializerProcessor> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for StructSerializer<'_, P> {
@@ -41,6 +42,29 @@ impl<P: SerializerProcessor> Serialize for StructSerializer<'_, P> {
             .map(|f| !f.has_attribute::<SkipSerde>() as usize)
             .sum::<usize>();
 
+        if self.compact {
+            let mut state = serializer.serialize_tuple(field_len)?;
+            for field_info in struct_info.iter() {
+                if field_info.has_attribute::<SkipSerde>() {
+                    continue;
+                }
+                let name = field_info.name();
+                let Some(value) = self.struct_value.field(name) else {
+                    return Err(Error::custom(format!(
+                        "field `{name}` was missing while serializing type {}",
+                        struct_info.type_path()
+                    )));
+                };
+                state.serialize_element(&InternalSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.compact,
+                ))?;
+            }
+            return state.end();
+        }
+
         let mut state = serializer.serialize_struct(
             struct_info
                 .type_path_table()
@@ -55,8 +79,13 @@ impl<P: SerializerProcessor> Serialize for StructSerializer<'_, P> {
             let name = field_info.name();
             if let Some(value) = self.struct_value.field(name) {
                 state.serialize_field(
-                    name,
-                    &InternalSerializer::new_internal(value, self.registry, self.processor),
+                    field_info.serde_name(),
+                    &InternalSerializer::new_internal(
+                        value,
+                        self.registry,
+                        self.processor,
+                        self.compact,
+                    ),
                 )?;
             } else {
                 return Err(Error::custom(format!(
@@ -8,6 +8,7 @@ pub(super) struct TupleSerializer<'a, P: SerializerProcessor> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for TupleSerializer<'_, P> {
@@ -19,6 +20,7 @@ impl<P: SerializerProcessor> Serialize for TupleSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.compact,
             ))?;
         }
         state.end()
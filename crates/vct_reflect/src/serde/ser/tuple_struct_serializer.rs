@@ -12,6 +12,7 @@ pub(super) struct TupleStructSerializer<'a, P: SerializerProcessor> {
     pub tuple_struct: &'a dyn TupleStruct,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: SerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
@@ -57,6 +58,7 @@ impl<P: SerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
                     value,
                     self.registry,
                     self.processor,
+                    self.compact,
                 ))?;
             } else {
                 return Err(Error::custom(format!(
@@ -0,0 +1,54 @@
+use alloc::borrow::Cow;
+use core::{error, fmt};
+
+/// A enumeration of all error outcomes that might happen when printing or
+/// parsing the [text format](super).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextError {
+    /// The value does not represent any type, e.g. a dynamic value without type info.
+    MissingTypeInfo { type_path: Cow<'static, str> },
+    /// An opaque type that has no text representation,
+    /// only primitives, `String` and `Cow<'static, str>` are supported.
+    UnsupportedType { type_path: Cow<'static, str> },
+    /// The type path was not found in the registry.
+    UnknownType { type_path: Cow<'static, str> },
+    /// Invalid input at the given position (1-based).
+    Syntax {
+        line: usize,
+        column: usize,
+        message: Cow<'static, str>,
+    },
+    /// An error reported by the underlying writer.
+    Fmt,
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTypeInfo { type_path } => {
+                write!(f, "value `{type_path}` does not represent any type")
+            }
+            Self::UnsupportedType { type_path } => {
+                write!(f, "type `{type_path}` has no text representation")
+            }
+            Self::UnknownType { type_path } => {
+                write!(f, "no type_traits found for type `{type_path}`")
+            }
+            Self::Syntax {
+                line,
+                column,
+                message,
+            } => write!(f, "{message} at line {line} column {column}"),
+            Self::Fmt => f.write_str("an error occurred when writing text"),
+        }
+    }
+}
+
+impl error::Error for TextError {}
+
+impl From<fmt::Error> for TextError {
+    #[inline]
+    fn from(_: fmt::Error) -> Self {
+        Self::Fmt
+    }
+}
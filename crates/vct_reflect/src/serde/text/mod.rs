@@ -0,0 +1,41 @@
+//! A human-readable text format for reflected values.
+//!
+//! The format is similar to Rust syntax (and RON), it's self-contained and
+//! only requires `alloc`:
+//!
+//! ```text
+//! my_crate::Player {
+//!     name: "Alice",
+//!     position: (1.0, 2.0),          // tuple and tuple struct
+//!     items: [Sword, Potion(3)],     // list, array and set; unit and tuple variants
+//!     stats: { "hp": 100 },          // map
+//!     state: Moving { speed: 2.5 },  // struct variant
+//! }
+//! ```
+//!
+//! - The type path of the root value is written first and resolved with the
+//!   [`TypeRegistry`], nested values are guided by their [`TypeInfo`].
+//! - Opaque values are limited to primitives, `String` and `Cow<'static, str>`,
+//!   other opaque types are [`TextError::UnsupportedType`], the registered
+//!   [`TypeTraitSerialize`] and [`TypeTraitDeserialize`] are not used.
+//! - Numbers may have `_` separators and a type suffix, as in Rust (e.g. `1_000u32`),
+//!   the suffix must match the expected type.
+//! - Missing struct fields use their default value (`#[reflect(default)]`),
+//!   otherwise it's an error.
+//! - Trailing commas and `//` comments are allowed.
+//!
+//! Use [`to_string`] or [`to_string_pretty`] for printing, and [`from_str`]
+//! for parsing, which produces `Dynamic*` values.
+//!
+//! [`TypeRegistry`]: crate::registry::TypeRegistry
+//! [`TypeInfo`]: crate::info::TypeInfo
+//! [`TypeTraitSerialize`]: crate::registry::TypeTraitSerialize
+//! [`TypeTraitDeserialize`]: crate::registry::TypeTraitDeserialize
+
+mod error;
+mod parser;
+mod printer;
+
+pub use error::TextError;
+pub use parser::{from_str, from_str_with_info};
+pub use printer::{to_string, to_string_pretty, write_text};
@@ -0,0 +1,635 @@
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::TypeId;
+
+use crate::{
+    Reflect,
+    info::{NamedField, TypeInfo, UnnamedField, VariantInfo},
+    ops::{
+        DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct,
+        DynamicTuple, DynamicTupleStruct, DynamicVariant, Map, Set, Tuple,
+    },
+    registry::TypeRegistry,
+};
+
+use super::TextError;
+
+/// Parses a reflected value from the [text format](super).
+///
+/// The input starts with the type path (or the type name, if not ambiguous) of the value,
+/// which is resolved with the registry.
+///
+/// Returns `Dynamic*` values for non-opaque types, use [`FromReflect`] to get the concrete value.
+///
+/// [`FromReflect`]: crate::FromReflect
+pub fn from_str(input: &str, registry: &TypeRegistry) -> Result<Box<dyn Reflect>, TextError> {
+    let mut parser = Parser::new(input);
+
+    let type_path = parser.type_path()?;
+    let type_traits = registry
+        .get_with_type_path(type_path)
+        .or_else(|| registry.get_with_type_name(type_path))
+        .ok_or_else(|| TextError::UnknownType {
+            type_path: Cow::Owned(type_path.to_string()),
+        })?;
+
+    let value = parser.value(type_traits.type_info())?;
+    parser.end()?;
+    Ok(value)
+}
+
+/// Parses a reflected value of a known type from the [text format](super).
+///
+/// Unlike [`from_str`], the input does not start with the type path.
+pub fn from_str_with_info(
+    input: &str,
+    type_info: &'static TypeInfo,
+) -> Result<Box<dyn Reflect>, TextError> {
+    let mut parser = Parser::new(input);
+    let value = parser.value(type_info)?;
+    parser.end()?;
+    Ok(value)
+}
+
+const NUMBER_SUFFIXES: [&str; 14] = [
+    "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize", "f32",
+    "f64",
+];
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn error_at(&self, pos: usize, message: impl Into<Cow<'static, str>>) -> TextError {
+        let consumed = &self.input[..pos];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed
+            .rfind('\n')
+            .map_or(consumed, |index| &consumed[index + 1..])
+            .chars()
+            .count()
+            + 1;
+
+        TextError::Syntax {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    #[inline]
+    fn error(&self, message: impl Into<Cow<'static, str>>) -> TextError {
+        self.error_at(self.pos, message)
+    }
+
+    #[inline]
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    /// Skips whitespace and `//` comments.
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), TextError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.unexpected(format!("`{expected}`")))
+        }
+    }
+
+    fn unexpected(&self, expected: impl core::fmt::Display) -> TextError {
+        match self.rest().chars().next() {
+            Some(found) => self.error(format!("expected {expected}, found `{found}`")),
+            None => self.error(format!("expected {expected}, found end of input")),
+        }
+    }
+
+    fn end(&mut self) -> Result<(), TextError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error("trailing characters")),
+        }
+    }
+
+    /// Takes the longest prefix matching the predicate.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn ident(&mut self) -> Result<&'a str, TextError> {
+        self.skip_whitespace();
+        if !self.rest().starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            return Err(self.unexpected("identifier"));
+        }
+        Ok(self.take_while(|c| c.is_alphanumeric() || c == '_'))
+    }
+
+    /// Reads the type path, brackets are balanced so generic arguments may contain spaces.
+    fn type_path(&mut self) -> Result<&'a str, TextError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut depth = 0usize;
+
+        for c in self.rest().chars() {
+            match c {
+                '<' => depth += 1,
+                '(' | '[' if depth > 0 || self.pos == start => depth += 1,
+                '>' | ')' | ']' if depth > 0 => depth -= 1,
+                '{' | '(' | '[' => break,
+                c if c.is_whitespace() && depth == 0 => break,
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+            if depth == 0 && matches!(c, ')' | ']') {
+                break;
+            }
+        }
+
+        if self.pos == start {
+            return Err(self.unexpected("type path"));
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    /// Parses comma separated items in delimiters, a trailing comma is allowed.
+    fn seq(
+        &mut self,
+        open: char,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<(), TextError>,
+    ) -> Result<(), TextError> {
+        self.expect(open)?;
+        loop {
+            if self.eat(close) {
+                return Ok(());
+            }
+            item(self)?;
+            if !self.eat(',') {
+                return self.expect(close);
+            }
+        }
+    }
+
+    fn value(&mut self, type_info: &'static TypeInfo) -> Result<Box<dyn Reflect>, TextError> {
+        match type_info {
+            TypeInfo::Struct(struct_info) => {
+                let mut dynamic_struct = self.named_fields(struct_info.iter().as_slice())?;
+                dynamic_struct.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let dynamic_tuple = self.unnamed_fields(tuple_struct_info.iter().as_slice())?;
+                let mut dynamic_tuple_struct = DynamicTupleStruct::from(dynamic_tuple);
+                dynamic_tuple_struct.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::Tuple(tuple_info) => {
+                let mut dynamic_tuple = self.unnamed_fields(tuple_info.iter().as_slice())?;
+                dynamic_tuple.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::List(list_info) => {
+                let mut dynamic_list = DynamicList::new();
+                self.seq('[', ']', |this| {
+                    dynamic_list.push_box(this.value(list_info.item_info())?);
+                    Ok(())
+                })?;
+                dynamic_list.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Array(array_info) => {
+                let start = self.pos;
+                let mut values = Vec::with_capacity(array_info.capacity());
+                self.seq('[', ']', |this| {
+                    values.push(this.value(array_info.item_info())?);
+                    Ok(())
+                })?;
+                if values.len() != array_info.capacity() {
+                    return Err(self.error_at(
+                        start,
+                        format!(
+                            "expected an array of length {}, found length {}",
+                            array_info.capacity(),
+                            values.len(),
+                        ),
+                    ));
+                }
+                let mut dynamic_array = DynamicArray::new(values.into_boxed_slice());
+                dynamic_array.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::Map(map_info) => {
+                let mut dynamic_map = DynamicMap::new();
+                self.seq('{', '}', |this| {
+                    let key = this.value(map_info.key_info())?;
+                    this.expect(':')?;
+                    let value = this.value(map_info.value_info())?;
+                    dynamic_map.insert_boxed(key, value);
+                    Ok(())
+                })?;
+                dynamic_map.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = DynamicSet::new();
+                self.seq('[', ']', |this| {
+                    dynamic_set.insert_boxed(this.value(set_info.value_info())?);
+                    Ok(())
+                })?;
+                dynamic_set.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_set))
+            }
+            TypeInfo::Enum(enum_info) => {
+                let start = self.pos;
+                let name = self.ident()?;
+                let (Some(index), Some(variant_info)) =
+                    (enum_info.index_of(name), enum_info.variant(name))
+                else {
+                    return Err(self.error_at(
+                        start,
+                        format!("no variant named `{name}` on enum `{}`", enum_info.type_path()),
+                    ));
+                };
+
+                let variant = match variant_info {
+                    VariantInfo::Unit(_) => DynamicVariant::Unit,
+                    VariantInfo::Tuple(tuple_info) => {
+                        self.unnamed_fields(tuple_info.iter().as_slice())?.into()
+                    }
+                    VariantInfo::Struct(struct_info) => {
+                        self.named_fields(struct_info.iter().as_slice())?.into()
+                    }
+                };
+
+                let mut dynamic_enum =
+                    DynamicEnum::new_with_index(index, name.to_string(), variant);
+                dynamic_enum.set_type_info(Some(type_info));
+                Ok(Box::new(dynamic_enum))
+            }
+            TypeInfo::Opaque(_) => self.opaque(type_info),
+        }
+    }
+
+    /// Parses `{ name: value, ... }`, missing fields use the default value if provided.
+    fn named_fields(&mut self, fields: &'static [NamedField]) -> Result<DynamicStruct, TextError> {
+        let start = self.pos;
+        let mut dynamic_struct = DynamicStruct::with_capacity(fields.len());
+        self.seq('{', '}', |this| {
+            let start = this.pos;
+            let name = this.ident()?;
+            let Some(field) = fields.iter().find(|field| field.name() == name) else {
+                return Err(this.error_at(start, format!("unknown field `{name}`")));
+            };
+            if dynamic_struct.index_of(name).is_some() {
+                return Err(this.error_at(start, format!("duplicate field `{name}`")));
+            }
+            this.expect(':')?;
+            dynamic_struct.insert_boxed(field.name(), this.value(field.type_info())?);
+            Ok(())
+        })?;
+
+        for field in fields {
+            if dynamic_struct.index_of(field.name()).is_some() {
+                continue;
+            }
+            let Some(value) = field.default_value() else {
+                return Err(self.error_at(start, format!("missing field `{}`", field.name())));
+            };
+            dynamic_struct.insert_boxed(field.name(), value);
+        }
+        Ok(dynamic_struct)
+    }
+
+    fn unnamed_fields(
+        &mut self,
+        fields: &'static [UnnamedField],
+    ) -> Result<DynamicTuple, TextError> {
+        let start = self.pos;
+        let mut dynamic_tuple = DynamicTuple::with_capacity(fields.len());
+        self.seq('(', ')', |this| {
+            let Some(field) = fields.get(dynamic_tuple.field_len()) else {
+                return Err(this.error(format!("expected {} fields", fields.len())));
+            };
+            dynamic_tuple.insert_boxed(this.value(field.type_info())?);
+            Ok(())
+        })?;
+        if dynamic_tuple.field_len() != fields.len() {
+            return Err(self.error_at(
+                start,
+                format!(
+                    "expected {} fields, found {}",
+                    fields.len(),
+                    dynamic_tuple.field_len()
+                ),
+            ));
+        }
+        Ok(dynamic_tuple)
+    }
+
+    fn opaque(&mut self, type_info: &'static TypeInfo) -> Result<Box<dyn Reflect>, TextError> {
+        let type_id = type_info.type_id();
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if type_id == TypeId::of::<bool>() {
+            let text = self.take_while(|c| c.is_alphanumeric() || c == '_');
+            return match text {
+                "true" => Ok(Box::new(true)),
+                "false" => Ok(Box::new(false)),
+                _ => Err(self.error_at(start, format!("invalid `bool` literal `{text}`"))),
+            };
+        }
+
+        macro_rules! parse_number {
+            ($($ty:ident),* $(,)?) => {
+                $(
+                    if type_id == TypeId::of::<$ty>() {
+                        let text = self.take_while(|c| c.is_alphanumeric() || "_.+-".contains(c));
+                        let number = self.number_literal(start, text, stringify!($ty))?;
+                        return match number.parse::<$ty>() {
+                            Ok(value) => Ok(Box::new(value)),
+                            Err(_) => Err(self.error_at(
+                                start,
+                                format!("invalid `{}` literal `{text}`", stringify!($ty)),
+                            )),
+                        };
+                    }
+                )*
+            };
+        }
+
+        parse_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+
+        if type_id == TypeId::of::<char>() {
+            return Ok(Box::new(self.char_literal()?));
+        }
+        if type_id == TypeId::of::<String>() {
+            return Ok(Box::new(self.string_literal()?));
+        }
+        if type_id == TypeId::of::<Cow<'static, str>>() {
+            return Ok(Box::new(Cow::<'static, str>::Owned(self.string_literal()?)));
+        }
+
+        Err(TextError::UnsupportedType {
+            type_path: Cow::Borrowed(type_info.type_path()),
+        })
+    }
+
+    /// Removes the type suffix (e.g. `42u32`) and `_` separators (e.g. `1_000`) of a number,
+    /// as in Rust literals, the suffix must be the expected type.
+    fn number_literal(
+        &self,
+        start: usize,
+        text: &'a str,
+        expected: &str,
+    ) -> Result<Cow<'a, str>, TextError> {
+        let mut number = text;
+        for suffix in NUMBER_SUFFIXES {
+            if let Some(rest) = text.strip_suffix(suffix)
+                && rest.ends_with(|c: char| c.is_ascii_digit() || c == '_')
+            {
+                if suffix != expected {
+                    return Err(self.error_at(
+                        start,
+                        format!("`{text}` has the suffix `{suffix}`, expected `{expected}`"),
+                    ));
+                }
+                number = rest;
+                break;
+            }
+        }
+
+        // A leading `_` is not a number, as in Rust.
+        if number.contains('_') && !number.starts_with('_') {
+            Ok(Cow::Owned(number.replace('_', "")))
+        } else {
+            Ok(Cow::Borrowed(number))
+        }
+    }
+
+    fn string_literal(&mut self) -> Result<String, TextError> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.next_char()? {
+                '"' => return Ok(value),
+                '\\' => value.push(self.escape()?),
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn char_literal(&mut self) -> Result<char, TextError> {
+        self.expect('\'')?;
+        let value = match self.next_char()? {
+            '\\' => self.escape()?,
+            c => c,
+        };
+        if self.next_char()? != '\'' {
+            return Err(self.error("expected `'`"));
+        }
+        Ok(value)
+    }
+
+    fn next_char(&mut self) -> Result<char, TextError> {
+        let Some(c) = self.rest().chars().next() else {
+            return Err(self.error("unexpected end of input"));
+        };
+        self.pos += c.len_utf8();
+        Ok(c)
+    }
+
+    /// Parses an escape sequence after `\`, the same as in Rust literals.
+    fn escape(&mut self) -> Result<char, TextError> {
+        let start = self.pos - 1;
+        let c = match self.next_char()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            c @ ('\\' | '"' | '\'') => c,
+            'u' => {
+                if self.next_char()? != '{' {
+                    return Err(self.error_at(start, "invalid unicode escape"));
+                }
+                let digits = self.take_while(|c| c.is_ascii_hexdigit());
+                if self.next_char()? != '}' {
+                    return Err(self.error_at(start, "invalid unicode escape"));
+                }
+                u32::from_str_radix(digits, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error_at(start, "invalid unicode escape"))?
+            }
+            c => return Err(self.error_at(start, format!("unknown escape `\\{c}`"))),
+        };
+        Ok(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        borrow::Cow,
+        collections::BTreeMap,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        FromReflect,
+        registry::TypeRegistry,
+        info::Typed,
+        serde::text::{TextError, from_str, from_str_with_info, to_string, to_string_pretty},
+    };
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    enum State {
+        Idle,
+        Moving { speed: f32 },
+        Hit(u32),
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(partial_eq)]
+    struct Player {
+        name: String,
+        title: Cow<'static, str>,
+        initial: char,
+        position: (f32, f32),
+        items: Vec<State>,
+        stats: BTreeMap<String, u32>,
+        pet: Option<u32>,
+        #[reflect(default)]
+        level: u32,
+    }
+
+    #[derive(Reflect)]
+    struct Label {
+        text: &'static str,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<Label>();
+        registry
+    }
+
+    fn new_player() -> Player {
+        Player {
+            name: "Alice \"A\"\n".to_string(),
+            title: Cow::Borrowed("hero"),
+            initial: '\'',
+            position: (1.5, -2.0),
+            items: vec![State::Idle, State::Moving { speed: 2.5 }, State::Hit(3)],
+            stats: BTreeMap::from([("hp".to_string(), 100), ("mp".to_string(), 5)]),
+            pet: None,
+            level: 3,
+        }
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let registry = registry();
+        let player = new_player();
+
+        for text in [to_string(&player).unwrap(), to_string_pretty(&player).unwrap()] {
+            let value = from_str(&text, &registry).unwrap();
+            assert_eq!(Player::from_reflect(value.as_ref()), Some(new_player()), "{text}");
+        }
+
+        let text = to_string(&(State::Hit(2), Some(1u8))).unwrap();
+        assert!(text.ends_with(" (Hit(2), Some(1))"), "{text}");
+    }
+
+    #[test]
+    fn text_missing_fields() {
+        let registry = registry();
+        let text = r#"Player {
+            name: "Bob", title: "", initial: 'b', position: (0, 0), // comment
+            items: [], stats: {}, pet: Some(1),
+        }"#;
+        let value = from_str(text, &registry).unwrap();
+        let player = Player::from_reflect(value.as_ref()).unwrap();
+        assert_eq!((player.level, player.pet), (0, Some(1)));
+
+        let text = r#"Player { name: "Bob", level: 2 }"#;
+        let err = from_str(text, &registry).err().unwrap();
+        assert!(err.to_string().contains("missing field `title`"), "{err}");
+    }
+
+    #[test]
+    fn text_numbers() {
+        let info = <u32 as Typed>::type_info();
+        for (text, expected) in [("1_000", 1000), ("42u32", 42), ("7_u32", 7), ("1_0_u32", 10)] {
+            let value = from_str_with_info(text, info).unwrap();
+            assert_eq!(value.downcast_ref::<u32>(), Some(&expected), "{text}");
+        }
+
+        let err = from_str_with_info("42u8", info).err().unwrap();
+        assert!(err.to_string().contains("`42u8` has the suffix `u8`, expected `u32`"), "{err}");
+        assert!(from_str_with_info("_1", info).is_err());
+
+        let info = <f64 as Typed>::type_info();
+        let value = from_str_with_info("1_000.5f64", info).unwrap();
+        assert_eq!(value.downcast_ref::<f64>(), Some(&1000.5));
+        assert!(from_str_with_info("1.5f32", info).is_err());
+        assert!(from_str_with_info("inf", info).is_ok());
+    }
+
+    #[test]
+    fn text_static_str() {
+        let err = to_string(&Label { text: "x" }).err().unwrap();
+        assert!(matches!(err, TextError::UnsupportedType { .. }));
+
+        let err = from_str(r#"Label { text: "x" }"#, &registry()).err().unwrap();
+        assert!(matches!(err, TextError::UnsupportedType { .. }));
+    }
+}
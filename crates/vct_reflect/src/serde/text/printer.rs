@@ -0,0 +1,181 @@
+use alloc::{borrow::Cow, string::String};
+use core::fmt::Write;
+
+use crate::{
+    Reflect,
+    info::VariantKind,
+    ops::ReflectRef,
+};
+
+use super::TextError;
+
+/// Prints a reflected value in the [text format](super) on a single line.
+///
+/// The output starts with the type path of the value, see [`from_str`](super::from_str).
+pub fn to_string(value: &dyn Reflect) -> Result<String, TextError> {
+    let mut output = String::new();
+    write_text(&mut output, value, false)?;
+    Ok(output)
+}
+
+/// Prints a reflected value in the [text format](super) with one entry per line.
+pub fn to_string_pretty(value: &dyn Reflect) -> Result<String, TextError> {
+    let mut output = String::new();
+    write_text(&mut output, value, true)?;
+    Ok(output)
+}
+
+/// Writes a reflected value in the [text format](super) to the writer.
+pub fn write_text<W: Write>(
+    writer: &mut W,
+    value: &dyn Reflect,
+    pretty: bool,
+) -> Result<(), TextError> {
+    let Some(type_info) = value.represented_type_info() else {
+        return Err(TextError::MissingTypeInfo {
+            type_path: Cow::Owned(value.reflect_type_path().into()),
+        });
+    };
+
+    writer.write_str(type_info.type_path())?;
+    writer.write_char(' ')?;
+
+    Printer {
+        writer,
+        pretty,
+        depth: 0,
+    }
+    .value(value)
+}
+
+struct Printer<'a, W: Write> {
+    writer: &'a mut W,
+    pretty: bool,
+    depth: usize,
+}
+
+impl<W: Write> Printer<'_, W> {
+    fn value(&mut self, value: &dyn Reflect) -> Result<(), TextError> {
+        match value.reflect_ref() {
+            ReflectRef::Struct(dyn_struct) => {
+                let fields = dyn_struct.iter_fields().enumerate();
+                self.seq("{", "}", true, fields, |this, (index, value)| {
+                    let name = dyn_struct.name_at(index).expect("field should exist");
+                    this.field(name, value)
+                })
+            }
+            ReflectRef::TupleStruct(dyn_tuple_struct) => {
+                self.seq("(", ")", false, dyn_tuple_struct.iter_fields(), Self::value)
+            }
+            ReflectRef::Tuple(dyn_tuple) => {
+                self.seq("(", ")", false, dyn_tuple.iter_fields(), Self::value)
+            }
+            ReflectRef::List(dyn_list) => self.seq("[", "]", false, dyn_list.iter(), Self::value),
+            ReflectRef::Array(dyn_array) => {
+                self.seq("[", "]", false, dyn_array.iter(), Self::value)
+            }
+            ReflectRef::Map(dyn_map) => {
+                self.seq("{", "}", true, dyn_map.iter(), |this, (key, value)| {
+                    this.value(key)?;
+                    this.writer.write_str(": ")?;
+                    this.value(value)
+                })
+            }
+            ReflectRef::Set(dyn_set) => self.seq("[", "]", false, dyn_set.iter(), Self::value),
+            ReflectRef::Enum(dyn_enum) => {
+                self.writer.write_str(dyn_enum.variant_name())?;
+                match dyn_enum.variant_kind() {
+                    VariantKind::Unit => Ok(()),
+                    VariantKind::Tuple => {
+                        self.seq("(", ")", false, dyn_enum.iter_fields(), |this, field| {
+                            this.value(field.value())
+                        })
+                    }
+                    VariantKind::Struct => {
+                        self.writer.write_char(' ')?;
+                        self.seq("{", "}", true, dyn_enum.iter_fields(), |this, field| {
+                            let name = field.name().expect("field should exist");
+                            this.field(name, field.value())
+                        })
+                    }
+                }
+            }
+            ReflectRef::Opaque(value) => self.opaque(value),
+        }
+    }
+
+    fn field(&mut self, name: &str, value: &dyn Reflect) -> Result<(), TextError> {
+        self.writer.write_str(name)?;
+        self.writer.write_str(": ")?;
+        self.value(value)
+    }
+
+    /// Prints a delimited sequence, `padded` adds spaces inside the delimiters on a single line.
+    fn seq<T>(
+        &mut self,
+        open: &str,
+        close: &str,
+        padded: bool,
+        items: impl Iterator<Item = T>,
+        mut print: impl FnMut(&mut Self, T) -> Result<(), TextError>,
+    ) -> Result<(), TextError> {
+        self.writer.write_str(open)?;
+        self.depth += 1;
+
+        let mut empty = true;
+        for item in items {
+            if self.pretty {
+                self.new_line()?;
+            } else if !empty {
+                self.writer.write_str(", ")?;
+            } else if padded {
+                self.writer.write_char(' ')?;
+            }
+            print(self, item)?;
+            if self.pretty {
+                self.writer.write_char(',')?;
+            }
+            empty = false;
+        }
+
+        self.depth -= 1;
+        if !empty {
+            if self.pretty {
+                self.new_line()?;
+            } else if padded {
+                self.writer.write_char(' ')?;
+            }
+        }
+        self.writer.write_str(close)?;
+        Ok(())
+    }
+
+    fn new_line(&mut self) -> Result<(), TextError> {
+        self.writer.write_char('\n')?;
+        for _ in 0..self.depth {
+            self.writer.write_str("    ")?;
+        }
+        Ok(())
+    }
+
+    fn opaque(&mut self, value: &dyn Reflect) -> Result<(), TextError> {
+        macro_rules! print_debug {
+            ($($ty:ty),* $(,)?) => {
+                $(
+                    if let Some(value) = value.downcast_ref::<$ty>() {
+                        return Ok(write!(self.writer, "{value:?}")?);
+                    }
+                )*
+            };
+        }
+
+        print_debug!(
+            bool, char, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64,
+            String, Cow<'static, str>,
+        );
+
+        Err(TextError::UnsupportedType {
+            type_path: Cow::Owned(value.reflect_type_path().into()),
+        })
+    }
+}
@@ -0,0 +1,16 @@
+//! Generic traversal of reflected values.
+//!
+//! Implement [`ReflectVisitor`] (or [`ReflectVisitorMut`]) with the callbacks of interest,
+//! then run it over a value with [`walk`] (or [`walk_mut`]). Each callback receives the path
+//! from the root as a list of [`Accessor`]s, and returns a [`VisitControl`] to continue,
+//! skip the children or stop the walk.
+//!
+//! [`Accessor`]: crate::access::Accessor
+
+// Visitor traits.
+mod visitor;
+pub use visitor::{ReflectVisitor, ReflectVisitorMut, VisitControl};
+
+// Walk drivers.
+mod walk;
+pub use walk::{walk, walk_mut};
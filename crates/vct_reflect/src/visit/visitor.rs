@@ -0,0 +1,188 @@
+use crate::{
+    Reflect,
+    access::Accessor,
+    ops::{Array, Enum, List, Map, ReflectMut, ReflectRef, Set, Struct, Tuple, TupleStruct},
+};
+
+/// Controls how the walk continues after visiting a value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VisitControl {
+    /// Visits the children of the value.
+    #[default]
+    Continue,
+    /// Doesn't visit the children of the value, the walk continues with its siblings.
+    SkipChildren,
+    /// Stops the walk.
+    Break,
+}
+
+/// A visitor of reflected values, see [`walk`](super::walk).
+///
+/// [`visit`](ReflectVisitor::visit) is called for every value before its children,
+/// and dispatches to the `visit_*` callback of its [kind] by default.
+/// [`leave`](ReflectVisitor::leave) is called after the children are visited or skipped.
+///
+/// `path` is the list of accessors from the root to the value,
+/// e.g. `[FieldName("items"), ListIndex(0)]`. Map values and set elements are accessed by
+/// [`Accessor::MapKey`], and enum fields by [`Accessor::FieldName`] or [`Accessor::TupleIndex`].
+///
+/// All callbacks do nothing and return [`VisitControl::Continue`] by default.
+///
+/// [kind]: crate::info::ReflectKind
+pub trait ReflectVisitor {
+    /// Visits a value before its children.
+    fn visit(&mut self, path: &[Accessor<'_>], value: &dyn Reflect) -> VisitControl {
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => self.visit_struct(path, value),
+            ReflectRef::TupleStruct(value) => self.visit_tuple_struct(path, value),
+            ReflectRef::Tuple(value) => self.visit_tuple(path, value),
+            ReflectRef::List(value) => self.visit_list(path, value),
+            ReflectRef::Array(value) => self.visit_array(path, value),
+            ReflectRef::Map(value) => self.visit_map(path, value),
+            ReflectRef::Set(value) => self.visit_set(path, value),
+            ReflectRef::Enum(value) => self.visit_enum(path, value),
+            ReflectRef::Opaque(value) => self.visit_opaque(path, value),
+        }
+    }
+
+    /// Called after the children of a value are visited or skipped.
+    ///
+    /// Not called if the walk is stopped by the value or its children.
+    #[inline]
+    fn leave(&mut self, _path: &[Accessor<'_>], _value: &dyn Reflect) {}
+
+    #[inline]
+    fn visit_struct(&mut self, _path: &[Accessor<'_>], _value: &dyn Struct) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_tuple_struct(
+        &mut self,
+        _path: &[Accessor<'_>],
+        _value: &dyn TupleStruct,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_tuple(&mut self, _path: &[Accessor<'_>], _value: &dyn Tuple) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_list(&mut self, _path: &[Accessor<'_>], _value: &dyn List) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_array(&mut self, _path: &[Accessor<'_>], _value: &dyn Array) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_map(&mut self, _path: &[Accessor<'_>], _value: &dyn Map) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_set(&mut self, _path: &[Accessor<'_>], _value: &dyn Set) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_enum(&mut self, _path: &[Accessor<'_>], _value: &dyn Enum) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_opaque(&mut self, _path: &[Accessor<'_>], _value: &dyn Reflect) -> VisitControl {
+        VisitControl::Continue
+    }
+}
+
+/// A visitor of mutable reflected values, see [`walk_mut`](super::walk_mut).
+///
+/// The same as [`ReflectVisitor`], except that set elements are passed to
+/// [`visit_set_element`](ReflectVisitorMut::visit_set_element),
+/// since they cannot be mutated in place.
+pub trait ReflectVisitorMut {
+    /// Visits a value before its children.
+    fn visit(&mut self, path: &[Accessor<'_>], value: &mut dyn Reflect) -> VisitControl {
+        match value.reflect_mut() {
+            ReflectMut::Struct(value) => self.visit_struct(path, value),
+            ReflectMut::TupleStruct(value) => self.visit_tuple_struct(path, value),
+            ReflectMut::Tuple(value) => self.visit_tuple(path, value),
+            ReflectMut::List(value) => self.visit_list(path, value),
+            ReflectMut::Array(value) => self.visit_array(path, value),
+            ReflectMut::Map(value) => self.visit_map(path, value),
+            ReflectMut::Set(value) => self.visit_set(path, value),
+            ReflectMut::Enum(value) => self.visit_enum(path, value),
+            ReflectMut::Opaque(value) => self.visit_opaque(path, value),
+        }
+    }
+
+    /// Called after the children of a value are visited or skipped.
+    ///
+    /// Not called if the walk is stopped by the value or its children.
+    #[inline]
+    fn leave(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Reflect) {}
+
+    #[inline]
+    fn visit_struct(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Struct) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_tuple_struct(
+        &mut self,
+        _path: &[Accessor<'_>],
+        _value: &mut dyn TupleStruct,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_tuple(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Tuple) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_list(&mut self, _path: &[Accessor<'_>], _value: &mut dyn List) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_array(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Array) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_map(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Map) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_set(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Set) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_enum(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Enum) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    #[inline]
+    fn visit_opaque(&mut self, _path: &[Accessor<'_>], _value: &mut dyn Reflect) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Visits a set element instead of [`visit`](ReflectVisitorMut::visit),
+    /// as mutating it may change its hash. Its children are not visited.
+    ///
+    /// Only [`VisitControl::Break`] has an effect.
+    #[inline]
+    fn visit_set_element(&mut self, _path: &[Accessor<'_>], _value: &dyn Reflect) -> VisitControl {
+        VisitControl::Continue
+    }
+}
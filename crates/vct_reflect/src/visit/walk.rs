@@ -0,0 +1,320 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{
+    Reflect,
+    access::Accessor,
+    ops::{ReflectMut, ReflectRef},
+};
+
+use super::{ReflectVisitor, ReflectVisitorMut, VisitControl};
+
+/// Walks `value` and its children depth-first with the visitor.
+///
+/// Returns `false` if the walk was stopped by [`VisitControl::Break`].
+pub fn walk<V: ReflectVisitor + ?Sized>(value: &dyn Reflect, visitor: &mut V) -> bool {
+    walk_value(value, &mut Vec::new(), visitor)
+}
+
+/// Walks `value` and its children depth-first with the mutable visitor.
+///
+/// Set elements can't be mutated in place, as it may change their hash, they are passed to
+/// [`ReflectVisitorMut::visit_set_element`] instead.
+///
+/// Returns `false` if the walk was stopped by [`VisitControl::Break`].
+pub fn walk_mut<V: ReflectVisitorMut + ?Sized>(value: &mut dyn Reflect, visitor: &mut V) -> bool {
+    walk_value_mut(value, &mut Vec::new(), visitor)
+}
+
+fn walk_value<'a, V: ReflectVisitor + ?Sized>(
+    value: &'a dyn Reflect,
+    path: &mut Vec<Accessor<'a>>,
+    visitor: &mut V,
+) -> bool {
+    match visitor.visit(path, value) {
+        VisitControl::Break => return false,
+        VisitControl::SkipChildren => {}
+        VisitControl::Continue => {
+            if !walk_children(value, path, visitor) {
+                return false;
+            }
+        }
+    }
+    visitor.leave(path, value);
+    true
+}
+
+fn walk_children<'a, V: ReflectVisitor + ?Sized>(
+    value: &'a dyn Reflect,
+    path: &mut Vec<Accessor<'a>>,
+    visitor: &mut V,
+) -> bool {
+    let mut child = |accessor: Accessor<'a>, value: &'a dyn Reflect| {
+        path.push(accessor);
+        let keep_going = walk_value(value, path, visitor);
+        path.pop();
+        keep_going
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(dyn_struct) => {
+            dyn_struct.iter_fields().enumerate().all(|(index, field)| {
+                let name = dyn_struct.name_at(index).expect("field should exist");
+                child(Accessor::FieldName(Cow::Borrowed(name)), field)
+            })
+        }
+        ReflectRef::TupleStruct(dyn_tuple_struct) => dyn_tuple_struct
+            .iter_fields()
+            .enumerate()
+            .all(|(index, field)| child(Accessor::TupleIndex(index), field)),
+        ReflectRef::Tuple(dyn_tuple) => dyn_tuple
+            .iter_fields()
+            .enumerate()
+            .all(|(index, field)| child(Accessor::TupleIndex(index), field)),
+        ReflectRef::List(dyn_list) => dyn_list
+            .iter()
+            .enumerate()
+            .all(|(index, item)| child(Accessor::ListIndex(index), item)),
+        ReflectRef::Array(dyn_array) => dyn_array
+            .iter()
+            .enumerate()
+            .all(|(index, item)| child(Accessor::ListIndex(index), item)),
+        ReflectRef::Map(dyn_map) => dyn_map
+            .iter()
+            .all(|(key, value)| child(Accessor::MapKey(Cow::Borrowed(key)), value)),
+        ReflectRef::Set(dyn_set) => dyn_set
+            .iter()
+            .all(|item| child(Accessor::MapKey(Cow::Borrowed(item)), item)),
+        ReflectRef::Enum(dyn_enum) => dyn_enum.iter_fields().enumerate().all(|(index, field)| {
+            let accessor = match field.name() {
+                Some(name) => Accessor::FieldName(Cow::Borrowed(name)),
+                None => Accessor::TupleIndex(index),
+            };
+            child(accessor, field.value())
+        }),
+        ReflectRef::Opaque(_) => true,
+    }
+}
+
+fn walk_value_mut<V: ReflectVisitorMut + ?Sized>(
+    value: &mut dyn Reflect,
+    path: &mut Vec<Accessor<'_>>,
+    visitor: &mut V,
+) -> bool {
+    match visitor.visit(path, value) {
+        VisitControl::Break => return false,
+        VisitControl::SkipChildren => {}
+        VisitControl::Continue => {
+            if !walk_children_mut(value, path, visitor) {
+                return false;
+            }
+        }
+    }
+    visitor.leave(path, value);
+    true
+}
+
+fn walk_children_mut<'p, V: ReflectVisitorMut + ?Sized>(
+    value: &mut dyn Reflect,
+    path: &mut Vec<Accessor<'p>>,
+    visitor: &mut V,
+) -> bool {
+    let mut child = |accessor: Accessor<'p>, value: &mut dyn Reflect| {
+        path.push(accessor);
+        let keep_going = walk_value_mut(value, path, visitor);
+        path.pop();
+        keep_going
+    };
+
+    match value.reflect_mut() {
+        ReflectMut::Struct(dyn_struct) => (0..dyn_struct.field_len()).all(|index| {
+            let name = dyn_struct.name_at(index).expect("field should exist").into();
+            let field = dyn_struct.field_at_mut(index).expect("field should exist");
+            child(Accessor::FieldName(Cow::Owned(name)), field)
+        }),
+        ReflectMut::TupleStruct(dyn_tuple_struct) => {
+            (0..dyn_tuple_struct.field_len()).all(|index| {
+                let field = dyn_tuple_struct.field_mut(index).expect("field should exist");
+                child(Accessor::TupleIndex(index), field)
+            })
+        }
+        ReflectMut::Tuple(dyn_tuple) => (0..dyn_tuple.field_len()).all(|index| {
+            let field = dyn_tuple.field_mut(index).expect("field should exist");
+            child(Accessor::TupleIndex(index), field)
+        }),
+        ReflectMut::List(dyn_list) => (0..dyn_list.len()).all(|index| {
+            let item = dyn_list.get_mut(index).expect("index should be in bounds");
+            child(Accessor::ListIndex(index), item)
+        }),
+        ReflectMut::Array(dyn_array) => (0..dyn_array.len()).all(|index| {
+            let item = dyn_array.get_mut(index).expect("index should be in bounds");
+            child(Accessor::ListIndex(index), item)
+        }),
+        ReflectMut::Map(dyn_map) => {
+            // Maps cannot be iterated mutably, so entries are visited by `retain`,
+            // with a copy of the path that borrows the key.
+            let mut keep_going = true;
+            dyn_map.retain(&mut |key, value| {
+                if keep_going {
+                    let mut path = key_path(path, key);
+                    keep_going = walk_value_mut(value, &mut path, visitor);
+                }
+                true
+            });
+            keep_going
+        }
+        ReflectMut::Set(dyn_set) => dyn_set.iter().all(|item| {
+            let path = key_path(path, item);
+            visitor.visit_set_element(&path, item) != VisitControl::Break
+        }),
+        ReflectMut::Opaque(_) => true,
+        ReflectMut::Enum(dyn_enum) => (0..dyn_enum.field_len()).all(|index| {
+            let accessor = match dyn_enum.name_at(index) {
+                Some(name) => Accessor::FieldName(Cow::Owned(name.into())),
+                None => Accessor::TupleIndex(index),
+            };
+            let field = dyn_enum.field_at_mut(index).expect("field should exist");
+            child(accessor, field)
+        }),
+    }
+}
+
+/// Returns a copy of `path` followed by the map key or set element `key`.
+fn key_path<'a>(path: &'a [Accessor<'_>], key: &'a dyn Reflect) -> Vec<Accessor<'a>> {
+    let mut key_path = Vec::with_capacity(path.len() + 1);
+    key_path.extend(path.iter().map(Accessor::reborrow));
+    key_path.push(Accessor::MapKey(Cow::Borrowed(key)));
+    key_path
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        collections::{BTreeMap, BTreeSet},
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use vct_reflect_derive::Reflect;
+
+    use crate::{
+        Reflect,
+        access::Accessor,
+        ops::List,
+        visit::{ReflectVisitor, ReflectVisitorMut, VisitControl, walk, walk_mut},
+    };
+
+    #[derive(Reflect)]
+    enum Asset {
+        Handle(u32),
+        Inline { data: Vec<u32> },
+    }
+
+    #[derive(Reflect)]
+    struct Scene {
+        assets: Vec<Asset>,
+        slots: BTreeMap<String, u32>,
+        tags: BTreeSet<String>,
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            assets: vec![Asset::Handle(1), Asset::Inline { data: vec![2, 3] }],
+            slots: BTreeMap::from([("main".into(), 4)]),
+            tags: BTreeSet::from(["a".into(), "b".into()]),
+        }
+    }
+
+    fn path_string(path: &[Accessor<'_>]) -> String {
+        path.iter().map(ToString::to_string).collect()
+    }
+
+    /// Records the path of every `u32`, and stops at `stop`.
+    struct Numbers {
+        paths: Vec<String>,
+        stop: Option<u32>,
+    }
+
+    impl ReflectVisitor for Numbers {
+        fn visit_opaque(&mut self, path: &[Accessor<'_>], value: &dyn Reflect) -> VisitControl {
+            let Some(&number) = value.downcast_ref::<u32>() else {
+                return VisitControl::Continue;
+            };
+            self.paths.push(path_string(path));
+            match self.stop {
+                Some(stop) if stop == number => VisitControl::Break,
+                _ => VisitControl::Continue,
+            }
+        }
+
+        fn visit_list(&mut self, path: &[Accessor<'_>], _value: &dyn List) -> VisitControl {
+            // Skip inline data.
+            match path.last() {
+                Some(Accessor::FieldName(name)) if name == "data" => VisitControl::SkipChildren,
+                _ => VisitControl::Continue,
+            }
+        }
+    }
+
+    #[test]
+    fn walk_paths() {
+        let mut numbers = Numbers {
+            paths: Vec::new(),
+            stop: None,
+        };
+        assert!(walk(&scene(), &mut numbers));
+        assert_eq!(numbers.paths, [".assets[0].0", r#".slots["main"]"#]);
+
+        let mut numbers = Numbers {
+            paths: Vec::new(),
+            stop: Some(1),
+        };
+        assert!(!walk(&scene(), &mut numbers));
+        assert_eq!(numbers.paths, [".assets[0].0"]);
+    }
+
+    /// Doubles every `u32`.
+    struct Double;
+
+    impl ReflectVisitorMut for Double {
+        fn visit_opaque(
+            &mut self,
+            _path: &[Accessor<'_>],
+            value: &mut dyn Reflect,
+        ) -> VisitControl {
+            if let Some(number) = value.downcast_mut::<u32>() {
+                *number *= 2;
+            }
+            VisitControl::Continue
+        }
+    }
+
+    #[test]
+    fn walk_mut_values() {
+        let mut scene = scene();
+        assert!(walk_mut(&mut scene, &mut Double));
+        assert!(matches!(scene.assets[0], Asset::Handle(2)));
+        assert!(matches!(&scene.assets[1], Asset::Inline { data } if data == &[4, 6]));
+        assert_eq!(scene.slots["main"], 8);
+    }
+
+    /// Records the path of every set element, and stops after the first.
+    struct Elements(Vec<String>);
+
+    impl ReflectVisitorMut for Elements {
+        fn visit_set_element(
+            &mut self,
+            path: &[Accessor<'_>],
+            _value: &dyn Reflect,
+        ) -> VisitControl {
+            self.0.push(path_string(path));
+            VisitControl::Break
+        }
+    }
+
+    #[test]
+    fn walk_mut_set_elements() {
+        let mut elements = Elements(Vec::new());
+        assert!(!walk_mut(&mut scene(), &mut elements));
+        assert_eq!(elements.0, [r#".tags["a"]"#]);
+    }
+}